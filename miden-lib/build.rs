@@ -197,6 +197,9 @@ fn compile_note_scripts(source_dir: &Path, target_dir: &Path, assembler: Assembl
         println!("Failed to create note_scripts directory: {}", e);
     }
 
+    // the roots of the note scripts are written as constants, so that they do not have to be
+    // computed from the MASB at runtime
+    let mut roots = String::new();
     for masm_file_path in get_masm_files(source_dir).unwrap() {
         // read the MASM file, parse it, and serialize the parsed AST to bytes
         let code = assembler.clone().assemble_program(masm_file_path.clone())?;
@@ -207,10 +210,20 @@ fn compile_note_scripts(source_dir: &Path, target_dir: &Path, assembler: Assembl
         let masb_file_name = masm_file_path.file_name().unwrap().to_str().unwrap();
         let mut masb_file_path = target_dir.join(masb_file_name);
 
+        let root: [u64; 4] = code.hash().into();
+        let script_name = masm_file_path.file_stem().unwrap().to_str().unwrap();
+        roots.push_str(&format!(
+            "pub const {script_name}: Digest = Digest::new([Felt::new({}), Felt::new({}), \
+             Felt::new({}), Felt::new({})]);\n",
+            root[0], root[1], root[2], root[3]
+        ));
+
         // write the binary MASB to the output dir
         masb_file_path.set_extension("masb");
         fs::write(masb_file_path, bytes).unwrap();
     }
+    fs::write(target_dir.join("note_script_roots.rs"), roots).unwrap();
+
     Ok(())
 }
 
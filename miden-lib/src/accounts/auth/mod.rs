@@ -0,0 +1,49 @@
+use miden_objects::{
+    accounts::{AccountComponent, StorageSlot},
+    crypto::dsa::rpo_falcon512,
+};
+
+use super::TransactionKernel;
+
+// RPO FALCON 512
+// ================================================================================================
+
+/// An [AccountComponent] implementing the RpoFalcon512 signature scheme for authentication of
+/// transactions.
+///
+/// The component exposes two procedures:
+/// - `auth_tx_rpo_falcon512`, which authenticates the transaction with the public key of the
+///   account.
+/// - `rotate_key_rpo_falcon512`, which can be used to replace the public key of the account. A
+///   transaction script invoking it can be built with
+///   [build_rotate_key_tx_script()](crate::accounts::wallets::build_rotate_key_tx_script).
+///
+/// The public key is stored in the only storage slot of the component. This component supports all
+/// account types.
+pub struct RpoFalcon512 {
+    public_key: rpo_falcon512::PublicKey,
+}
+
+impl RpoFalcon512 {
+    /// Returns a new [RpoFalcon512] component with the provided public key.
+    pub fn new(public_key: rpo_falcon512::PublicKey) -> Self {
+        Self { public_key }
+    }
+}
+
+impl From<RpoFalcon512> for AccountComponent {
+    fn from(auth: RpoFalcon512) -> Self {
+        let source_code = "
+            export.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+            export.::miden::contracts::auth::basic::rotate_key_rpo_falcon512
+        ";
+
+        AccountComponent::compile(
+            source_code,
+            TransactionKernel::assembler(),
+            vec![StorageSlot::new_value(auth.public_key.into())],
+        )
+        .expect("rpo falcon 512 component should satisfy the requirements of a valid component")
+        .with_supports_all_types()
+    }
+}
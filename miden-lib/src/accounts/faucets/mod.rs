@@ -1,14 +1,16 @@
-use alloc::{collections::BTreeMap, string::ToString};
+use alloc::string::ToString;
 
 use miden_objects::{
     accounts::{
-        Account, AccountCode, AccountId, AccountStorage, AccountStorageType, AccountType, SlotItem,
+        Account, AccountBuilder, AccountComponent, AccountId, AccountStorageType, AccountType,
+        StorageSlot,
     },
     assets::TokenSymbol,
-    AccountError, Felt, Word, ZERO,
+    transaction::TransactionScript,
+    AccountError, Felt, Hasher, TransactionScriptError, Word, ZERO,
 };
 
-use super::{AuthScheme, TransactionKernel};
+use super::{word_to_masm_push_string, AuthScheme, TransactionKernel};
 
 // FUNGIBLE FAUCET
 // ================================================================================================
@@ -16,16 +18,111 @@ use super::{AuthScheme, TransactionKernel};
 const MAX_MAX_SUPPLY: u64 = (1 << 63) - 1;
 const MAX_DECIMALS: u8 = 12;
 
+/// An [AccountComponent] implementing a basic fungible faucet.
+///
+/// The component exposes the following procedures:
+/// - `distribute`, which mints an assets and create a note for the provided recipient.
+/// - `burn`, which burns the provided asset.
+/// - `rotate_owner_key`, which replaces the public key of the faucet owner.
+/// - `lower_max_supply`, which lowers the max supply of the token.
+/// - `freeze_minting`, which permanently disables `distribute`.
+/// - the authentication procedure of the owner's authentication scheme.
+///
+/// The component uses two storage slots: the public key of the owner is stored in the first slot
+/// and the token metadata in the second one. The component supports fungible faucets only.
+pub struct BasicFungibleFaucet {
+    symbol: TokenSymbol,
+    decimals: u8,
+    max_supply: Felt,
+    auth_scheme: AuthScheme,
+}
+
+impl BasicFungibleFaucet {
+    /// Returns a new [BasicFungibleFaucet] component with the provided token metadata, owned by
+    /// the key of the provided authentication scheme.
+    ///
+    /// # Errors
+    /// Returns an error if the decimals are greater than 12 or the max supply is not smaller than
+    /// 2^63.
+    pub fn new(
+        symbol: TokenSymbol,
+        decimals: u8,
+        max_supply: Felt,
+        auth_scheme: AuthScheme,
+    ) -> Result<Self, AccountError> {
+        if decimals > MAX_DECIMALS {
+            return Err(AccountError::FungibleFaucetInvalidMetadata(
+                "Decimals must be less than 13".to_string(),
+            ));
+        } else if max_supply.as_int() > MAX_MAX_SUPPLY {
+            return Err(AccountError::FungibleFaucetInvalidMetadata(
+                "Max supply must be < 2^63".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            symbol,
+            decimals,
+            max_supply,
+            auth_scheme,
+        })
+    }
+}
+
+impl From<BasicFungibleFaucet> for AccountComponent {
+    fn from(faucet: BasicFungibleFaucet) -> Self {
+        let (auth_scheme_procedure, auth_data): (&str, Word) = match faucet.auth_scheme {
+            AuthScheme::RpoFalcon512 { pub_key } => ("auth_tx_rpo_falcon512", pub_key.into()),
+        };
+
+        let source_code = format!(
+            "
+            export.::miden::contracts::faucets::basic_fungible::distribute
+            export.::miden::contracts::faucets::basic_fungible::burn
+            export.::miden::contracts::faucets::basic_fungible::rotate_owner_key
+            export.::miden::contracts::faucets::basic_fungible::lower_max_supply
+            export.::miden::contracts::faucets::basic_fungible::freeze_minting
+            export.::miden::contracts::auth::basic::{auth_scheme_procedure}
+        "
+        );
+
+        // Note: data is stored as [a0, a1, a2, a3] but loaded onto the stack as
+        // [a3, a2, a1, a0, ...]
+        let metadata = [faucet.max_supply, Felt::from(faucet.decimals), faucet.symbol.into(), ZERO];
+
+        // The component stores the authentication data and the token metadata:
+        // - slot 0: authentication data
+        // - slot 1: token metadata as [max_supply, decimals, token_symbol, is_frozen]
+        AccountComponent::compile(
+            source_code,
+            TransactionKernel::assembler(),
+            vec![StorageSlot::new_value(auth_data), StorageSlot::new_value(metadata)],
+        )
+        .expect(
+            "basic fungible faucet component should satisfy the requirements of a valid component",
+        )
+        .with_supported_type(AccountType::FungibleFaucet)
+    }
+}
+
 /// Creates a new faucet account with basic fungible faucet interface,
 /// account storage type, specified authentication scheme, and provided meta data (token symbol,
 /// decimals, max supply).
 ///
-/// The basic faucet interface exposes two procedures:
+/// The account is built from the [BasicFungibleFaucet] component, and thus exposes the following
+/// procedures:
 /// - `distribute`, which mints an assets and create a note for the provided recipient.
 /// - `burn`, which burns the provided asset.
+/// - `rotate_owner_key`, which replaces the public key of the faucet owner.
+/// - `lower_max_supply`, which lowers the max supply of the token.
+/// - `freeze_minting`, which permanently disables `distribute`.
 ///
 /// `distribute` requires authentication. The authentication procedure is defined by the specified
 /// authentication scheme. `burn` does not require authentication and can be called by anyone.
+/// The owner operations (`rotate_owner_key`, `lower_max_supply` and `freeze_minting`) must be
+/// signed with the current owner key; transaction scripts invoking them can be built with
+/// [build_rotate_owner_key_tx_script()], [build_lower_max_supply_tx_script()] and
+/// [build_freeze_minting_tx_script()].
 ///
 /// Public key information for the scheme is stored in the account storage at slot 0. The token
 /// metadata is stored in the account storage at slot 1.
@@ -37,55 +134,224 @@ pub fn create_basic_fungible_faucet(
     account_storage_type: AccountStorageType,
     auth_scheme: AuthScheme,
 ) -> Result<(Account, Word), AccountError> {
-    // Atm we only have RpoFalcon512 as authentication scheme and this is also the default in the
-    // faucet contract, so we can just use the public key as storage slot 0.
+    AccountBuilder::new(init_seed)
+        .account_type(AccountType::FungibleFaucet)
+        .storage_type(account_storage_type)
+        .with_component(BasicFungibleFaucet::new(symbol, decimals, max_supply, auth_scheme)?)
+        .build()
+}
 
-    let (auth_scheme_procedure, auth_data): (&str, Word) = match auth_scheme {
-        AuthScheme::RpoFalcon512 { pub_key } => ("auth_tx_rpo_falcon512", pub_key.into()),
+/// Returns a transaction script which rotates the owner key of a basic fungible faucet to the
+/// public key of the provided authentication scheme.
+///
+/// The transaction must be signed with the current owner key, which signs both the key rotation and
+/// the transaction itself. Once the transaction is executed, only the new key can authenticate the
+/// faucet.
+pub fn build_rotate_owner_key_tx_script(
+    new_auth_scheme: AuthScheme,
+) -> Result<TransactionScript, TransactionScriptError> {
+    let new_pub_key: Word = match new_auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } => pub_key.into(),
     };
 
     let source_code = format!(
         "
-        export.::miden::contracts::faucets::basic_fungible::distribute
-        export.::miden::contracts::faucets::basic_fungible::burn
-        export.::miden::contracts::auth::basic::{auth_scheme_procedure}
+        begin
+            call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+            push.{new_pub_key}
+            call.::miden::contracts::faucets::basic_fungible::rotate_owner_key
+            dropw
+        end
+    ",
+        new_pub_key = word_to_masm_push_string(&new_pub_key),
+    );
+
+    TransactionScript::compile(source_code, [], TransactionKernel::assembler())
+}
+
+/// Returns a transaction script which lowers the max supply of a basic fungible faucet.
+///
+/// The transaction must be signed with the current owner key, which signs both the operation and
+/// the transaction itself. The transaction fails if the new max supply is not lower than the
+/// current one or is lower than the total issuance of the faucet.
+pub fn build_lower_max_supply_tx_script(
+    new_max_supply: Felt,
+) -> Result<TransactionScript, TransactionScriptError> {
+    let source_code = format!(
+        "
+        begin
+            push.{new_max_supply}
+            call.::miden::contracts::faucets::basic_fungible::lower_max_supply
+            drop
+            call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+        end
     "
     );
 
-    let assembler = TransactionKernel::assembler();
-    let account_code = AccountCode::compile(source_code, assembler)?;
-
-    // First check that the metadata is valid.
-    if decimals > MAX_DECIMALS {
-        return Err(AccountError::FungibleFaucetInvalidMetadata(
-            "Decimals must be less than 13".to_string(),
-        ));
-    } else if max_supply.as_int() > MAX_MAX_SUPPLY {
-        return Err(AccountError::FungibleFaucetInvalidMetadata(
-            "Max supply must be < 2^63".to_string(),
-        ));
+    TransactionScript::compile(source_code, [], TransactionKernel::assembler())
+}
+
+/// Returns a transaction script which permanently freezes minting of a basic fungible faucet.
+///
+/// The transaction must be signed with the current owner key, which signs both the operation and
+/// the transaction itself.
+pub fn build_freeze_minting_tx_script() -> Result<TransactionScript, TransactionScriptError> {
+    let source_code = "
+        begin
+            call.::miden::contracts::faucets::basic_fungible::freeze_minting
+            call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+        end
+    ";
+
+    TransactionScript::compile(source_code, [], TransactionKernel::assembler())
+}
+
+// NON-FUNGIBLE FAUCET
+// ================================================================================================
+
+const MAX_ROYALTY_BASIS_POINTS: u16 = 10_000;
+
+/// An [AccountComponent] implementing a basic non-fungible faucet.
+///
+/// The component exposes the following procedures:
+/// - `distribute_nft`, which mints a non-fungible asset and creates a note for the provided
+///   recipient.
+/// - `burn_nft`, which burns the provided non-fungible asset.
+/// - the authentication procedure of the owner's authentication scheme.
+///
+/// The component uses three storage slots: the public key of the owner is stored in the first
+/// slot, the hash of the collection name in the second one and the collection info in the third
+/// one. The issued assets are tracked in the faucet slot reserved by the transaction kernel, which
+/// is initialized by [AccountBuilder]. The component supports non-fungible faucets only.
+pub struct BasicNonFungibleFaucet {
+    name_hash: Word,
+    max_items: u64,
+    royalty_recipient: Option<AccountId>,
+    royalty_basis_points: u16,
+    auth_scheme: AuthScheme,
+}
+
+impl BasicNonFungibleFaucet {
+    /// Returns a new [BasicNonFungibleFaucet] component with the provided collection metadata,
+    /// owned by the key of the provided authentication scheme.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The maximum number of items is zero or not smaller than 2^63.
+    /// - The royalty basis points are greater than 10000.
+    /// - The royalty basis points are not zero but no royalty recipient is provided.
+    pub fn new(
+        collection_name: &str,
+        max_items: u64,
+        royalty_recipient: Option<AccountId>,
+        royalty_basis_points: u16,
+        auth_scheme: AuthScheme,
+    ) -> Result<Self, AccountError> {
+        if max_items == 0 || max_items > MAX_MAX_SUPPLY {
+            return Err(AccountError::NonFungibleFaucetInvalidMetadata(
+                "Max items must be > 0 and < 2^63".to_string(),
+            ));
+        } else if royalty_basis_points > MAX_ROYALTY_BASIS_POINTS {
+            return Err(AccountError::NonFungibleFaucetInvalidMetadata(
+                "Royalty basis points must be at most 10000".to_string(),
+            ));
+        } else if royalty_basis_points > 0 && royalty_recipient.is_none() {
+            return Err(AccountError::NonFungibleFaucetInvalidMetadata(
+                "Royalty recipient must be provided for non-zero royalties".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            name_hash: Hasher::hash(collection_name.as_bytes()).into(),
+            max_items,
+            royalty_recipient,
+            royalty_basis_points,
+            auth_scheme,
+        })
+    }
+}
+
+impl From<BasicNonFungibleFaucet> for AccountComponent {
+    fn from(faucet: BasicNonFungibleFaucet) -> Self {
+        let (auth_scheme_procedure, auth_data): (&str, Word) = match faucet.auth_scheme {
+            AuthScheme::RpoFalcon512 { pub_key } => ("auth_tx_rpo_falcon512", pub_key.into()),
+        };
+
+        let source_code = format!(
+            "
+            export.::miden::contracts::faucets::basic_non_fungible::distribute_nft
+            export.::miden::contracts::faucets::basic_non_fungible::burn_nft
+            export.::miden::contracts::auth::basic::{auth_scheme_procedure}
+        "
+        );
+
+        // Note: data is stored as [a0, a1, a2, a3] but loaded onto the stack as
+        // [a3, a2, a1, a0, ...]
+        let collection_info = [
+            Felt::new(faucet.max_items),
+            Felt::from(faucet.royalty_basis_points),
+            faucet.royalty_recipient.map(Felt::from).unwrap_or(ZERO),
+            ZERO,
+        ];
+
+        // The component stores the authentication data and the collection metadata:
+        // - slot 0: authentication data
+        // - slot 1: hash of the collection name
+        // - slot 2: collection info as [max_items, royalty_basis_points, royalty_recipient, 0]
+        AccountComponent::compile(
+            source_code,
+            TransactionKernel::assembler(),
+            vec![
+                StorageSlot::new_value(auth_data),
+                StorageSlot::new_value(faucet.name_hash),
+                StorageSlot::new_value(collection_info),
+            ],
+        )
+        .expect(
+            "basic non-fungible faucet component should satisfy the requirements of a valid \
+             component",
+        )
+        .with_supported_type(AccountType::NonFungibleFaucet)
     }
+}
 
-    // Note: data is stored as [a0, a1, a2, a3] but loaded onto the stack as [a3, a2, a1, a0, ...]
-    let metadata = [max_supply, Felt::from(decimals), symbol.into(), ZERO];
-
-    // We store the authentication data and the token metadata in the account storage:
-    // - slot 0: authentication data
-    // - slot 1: token metadata as [max_supply, decimals, token_symbol, 0]
-    let account_storage = AccountStorage::new(
-        vec![SlotItem::new_value(0, 0, auth_data), SlotItem::new_value(1, 0, metadata)],
-        BTreeMap::new(),
-    )?;
-
-    let account_seed = AccountId::get_account_seed(
-        init_seed,
-        AccountType::FungibleFaucet,
-        account_storage_type,
-        account_code.commitment(),
-        account_storage.root(),
-    )?;
-
-    Ok((Account::new(account_seed, account_code, account_storage)?, account_seed))
+/// Creates a new faucet account with basic non-fungible faucet interface, account storage type,
+/// specified authentication scheme, and provided collection metadata (collection name, maximum
+/// number of items, royalty info).
+///
+/// The account is built from the [BasicNonFungibleFaucet] component, and thus exposes the
+/// following procedures:
+/// - `distribute_nft`, which mints a non-fungible asset and creates a note for the provided
+///   recipient.
+/// - `burn_nft`, which burns the provided non-fungible asset.
+///
+/// Both procedures require authentication, i.e., the transactions invoking them must be
+/// authenticated by the owner of the faucet. The authentication procedure is defined by the
+/// specified authentication scheme.
+///
+/// Public key information for the scheme is stored in the account storage at slot 0. The hash of
+/// the collection name is stored at slot 1, and the collection info at slot 2. The issued assets
+/// are tracked in the map at slot 254.
+pub fn create_basic_non_fungible_faucet(
+    init_seed: [u8; 32],
+    collection_name: &str,
+    max_items: u64,
+    royalty_recipient: Option<AccountId>,
+    royalty_basis_points: u16,
+    account_storage_type: AccountStorageType,
+    auth_scheme: AuthScheme,
+) -> Result<(Account, Word), AccountError> {
+    AccountBuilder::new(init_seed)
+        .account_type(AccountType::NonFungibleFaucet)
+        .storage_type(account_storage_type)
+        .with_component(BasicNonFungibleFaucet::new(
+            collection_name,
+            max_items,
+            royalty_recipient,
+            royalty_basis_points,
+            auth_scheme,
+        )?)
+        .build()
 }
 
 // TESTS
@@ -93,11 +359,15 @@ pub fn create_basic_fungible_faucet(
 
 #[cfg(test)]
 mod tests {
-    use miden_objects::{crypto::dsa::rpo_falcon512, ONE};
+    use miden_objects::{
+        accounts::account_id::testing::ACCOUNT_ID_SENDER, crypto::dsa::rpo_falcon512, ONE,
+    };
 
     use super::{
-        create_basic_fungible_faucet, AccountStorageType, AuthScheme, Felt, TokenSymbol, ZERO,
+        create_basic_fungible_faucet, create_basic_non_fungible_faucet, AccountError, AccountId,
+        AccountStorageType, AccountType, AuthScheme, Felt, Hasher, TokenSymbol, Word, ZERO,
     };
+    use crate::transaction::memory::FAUCET_STORAGE_DATA_SLOT;
 
     #[test]
     fn faucet_contract_creation() {
@@ -134,4 +404,69 @@ mod tests {
 
         assert!(faucet_account.is_faucet());
     }
+
+    #[test]
+    fn non_fungible_faucet_contract_creation() {
+        let pub_key = rpo_falcon512::PublicKey::new([ONE; 4]);
+        let auth_scheme: AuthScheme = AuthScheme::RpoFalcon512 { pub_key };
+
+        let init_seed: [u8; 32] = [
+            90, 110, 209, 94, 84, 105, 250, 242, 223, 203, 216, 124, 22, 159, 14, 132, 215, 85,
+            183, 204, 149, 90, 166, 68, 100, 73, 106, 168, 125, 237, 138, 16,
+        ];
+
+        let royalty_recipient = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let storage_type = AccountStorageType::OffChain;
+
+        let (faucet_account, _) = create_basic_non_fungible_faucet(
+            init_seed,
+            "POL PUNKS",
+            1000,
+            Some(royalty_recipient),
+            250,
+            storage_type,
+            auth_scheme,
+        )
+        .unwrap();
+
+        // check that the collection name hash (slot 1) and collection info (slot 2) are stored
+        let name_hash: Word = Hasher::hash("POL PUNKS".as_bytes()).into();
+        assert_eq!(faucet_account.storage().get_item(1), name_hash.into());
+        assert_eq!(
+            faucet_account.storage().get_item(2),
+            [Felt::new(1000), Felt::new(250), royalty_recipient.into(), ZERO].into()
+        );
+
+        // check that the reserved slot holds an empty map
+        assert!(faucet_account.storage().maps().contains_key(&FAUCET_STORAGE_DATA_SLOT));
+
+        assert_eq!(faucet_account.account_type(), AccountType::NonFungibleFaucet);
+    }
+
+    #[test]
+    fn non_fungible_faucet_contract_creation_fails_on_invalid_metadata() {
+        let pub_key = rpo_falcon512::PublicKey::new([ONE; 4]);
+
+        let result = create_basic_non_fungible_faucet(
+            [1; 32],
+            "POL PUNKS",
+            1000,
+            None,
+            250,
+            AccountStorageType::OffChain,
+            AuthScheme::RpoFalcon512 { pub_key },
+        );
+        assert!(matches!(result, Err(AccountError::NonFungibleFaucetInvalidMetadata(_))));
+
+        let result = create_basic_non_fungible_faucet(
+            [1; 32],
+            "POL PUNKS",
+            0,
+            None,
+            0,
+            AccountStorageType::OffChain,
+            AuthScheme::RpoFalcon512 { pub_key },
+        );
+        assert!(matches!(result, Err(AccountError::NonFungibleFaucetInvalidMetadata(_))));
+    }
 }
@@ -1,4 +1,21 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use miden_objects::Word;
+
 use super::{auth::AuthScheme, transaction::TransactionKernel};
 
+pub mod auth;
 pub mod faucets;
 pub mod wallets;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the MASM representation of the provided word, such that `push.{word}` puts the word on
+/// the stack in the same order as it is loaded from account storage.
+fn word_to_masm_push_string(word: &Word) -> String {
+    word.iter().map(|x| x.as_int().to_string()).collect::<Vec<_>>().join(".")
+}
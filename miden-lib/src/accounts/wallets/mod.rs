@@ -1,29 +1,58 @@
-use alloc::{
-    collections::BTreeMap,
-    string::{String, ToString},
-};
+use alloc::string::ToString;
 
 use miden_objects::{
     accounts::{
-        Account, AccountCode, AccountId, AccountStorage, AccountStorageType, AccountType, SlotItem,
+        Account, AccountBuilder, AccountComponent, AccountId, AccountStorageType, AccountType,
+        StorageMap, StorageSlot,
     },
-    AccountError, Word,
+    assets::FungibleAsset,
+    transaction::TransactionScript,
+    AccountError, Digest, Felt, TransactionScriptError, Word, ONE, ZERO,
 };
 
-use super::{AuthScheme, TransactionKernel};
+use super::{auth::RpoFalcon512, word_to_masm_push_string, AuthScheme, TransactionKernel};
 
 // BASIC WALLET
 // ================================================================================================
 
+/// An [AccountComponent] implementing a basic wallet.
+///
+/// The component exposes two procedures:
+/// - `receive_asset`, which can be used to add an asset to the account.
+/// - `send_asset`, which can be used to remove an asset from the account and put into a note
+///   addressed to the specified recipient.
+///
+/// All procedures require authentication, and thus the component must be combined with an
+/// authentication component, e.g., [RpoFalcon512] or [SocialRecovery]. The component does not use
+/// any storage slots. The component supports regular account types only.
+pub struct BasicWallet;
+
+impl From<BasicWallet> for AccountComponent {
+    fn from(_: BasicWallet) -> Self {
+        let source_code = "
+            export.::miden::contracts::wallets::basic::receive_asset
+            export.::miden::contracts::wallets::basic::send_asset
+        ";
+
+        AccountComponent::compile(source_code, TransactionKernel::assembler(), vec![])
+            .expect("basic wallet component should satisfy the requirements of a valid component")
+            .with_supported_type(AccountType::RegularAccountImmutableCode)
+            .with_supported_type(AccountType::RegularAccountUpdatableCode)
+    }
+}
+
 /// Creates a new account with basic wallet interface, the specified authentication scheme and the
 /// account storage type. Basic wallets can be specified to have either mutable or immutable code.
 ///
-/// The basic wallet interface exposes two procedures:
+/// The account is built from the authentication component of the specified scheme and the
+/// [BasicWallet] component, and thus exposes the following procedures:
 /// - `receive_asset`, which can be used to add an asset to the account.
 /// - `send_asset`, which can be used to remove an asset from the account and put into a note
 ///   addressed to the specified recipient.
+/// - `rotate_key_rpo_falcon512`, which can be used to replace the public key of the account. A
+///   transaction script invoking it can be built with [build_rotate_key_tx_script()].
 ///
-/// Both methods require authentication. The authentication procedure is defined by the specified
+/// All methods require authentication. The authentication procedure is defined by the specified
 /// authentication scheme. Public key information for the scheme is stored in the account storage
 /// at slot 0.
 pub fn create_basic_wallet(
@@ -38,33 +67,484 @@ pub fn create_basic_wallet(
         ));
     }
 
-    let (auth_scheme_procedure, storage_slot_0_data): (&str, Word) = match auth_scheme {
-        AuthScheme::RpoFalcon512 { pub_key } => ("auth_tx_rpo_falcon512", pub_key.into()),
+    AccountBuilder::new(init_seed)
+        .account_type(account_type)
+        .storage_type(account_storage_type)
+        .with_component(auth_component(auth_scheme))
+        .with_component(BasicWallet)
+        .build()
+}
+
+/// Returns a transaction script which rotates the key of an account authenticated by [RpoFalcon512]
+/// or [SocialRecovery] to the public key of the provided authentication scheme.
+///
+/// The transaction must be signed with the current key of the wallet, which signs both the key
+/// rotation and the transaction itself. Once the transaction is executed, only the new key can
+/// authenticate the wallet, i.e., the new secret key should be added to the authenticator used for
+/// subsequent transactions.
+pub fn build_rotate_key_tx_script(
+    new_auth_scheme: AuthScheme,
+) -> Result<TransactionScript, TransactionScriptError> {
+    let new_pub_key: Word = match new_auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } => pub_key.into(),
+    };
+
+    let source_code = format!(
+        "
+        begin
+            call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+            push.{new_pub_key}
+            call.::miden::contracts::auth::basic::rotate_key_rpo_falcon512
+            dropw
+        end
+    ",
+        new_pub_key = word_to_masm_push_string(&new_pub_key),
+    );
+
+    TransactionScript::compile(source_code, [], TransactionKernel::assembler())
+}
+
+// RECOVERABLE WALLET
+// ================================================================================================
+
+/// An [AccountComponent] implementing authentication with a public key which can be replaced via
+/// social recovery.
+///
+/// In addition to the procedures of [RpoFalcon512], which authenticate transactions with the public
+/// key and rotate it, the component exposes four procedures:
+/// - `approve_recovery`, which is invoked by recovery notes sent by guardians (see
+///   [create_recovery_note()](crate::notes::create_recovery_note)) to approve a new public key.
+/// - `auth_tx_recovery`, which authenticates transactions recording approvals of guardians and
+///   fails if these transactions change the account in any other way or create notes, see
+///   [build_approve_recovery_tx_script()].
+/// - `finalize_recovery`, which replaces the public key of the account once `threshold` guardians
+///   approved it and `recovery_delay` blocks passed since then. The transaction must be
+///   authenticated with the new key, e.g., via [build_finalize_recovery_tx_script()].
+/// - `cancel_recovery`, which invalidates all approvals given so far and requires authentication
+///   with the current key, e.g., via [build_cancel_recovery_tx_script()].
+///
+/// The component uses five storage slots: the public key, the recovery config, the guardians, and
+/// two maps holding the approvals of the guardians. As the component authenticates the account,
+/// it replaces the [RpoFalcon512] component and must not be combined with it. The component
+/// supports regular account types only.
+pub struct SocialRecovery {
+    pub_key: Word,
+    guardians: StorageMap,
+    threshold: u32,
+    recovery_delay: u32,
+}
+
+impl SocialRecovery {
+    /// Returns a new [SocialRecovery] component authenticating the account with the public key of
+    /// the provided authentication scheme, and allowing `threshold` of the provided guardians to
+    /// replace the key, `recovery_delay` blocks after they approved it.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The list of guardians is empty or contains duplicates.
+    /// - The threshold is zero or greater than the number of guardians.
+    pub fn new(
+        auth_scheme: AuthScheme,
+        guardians: &[AccountId],
+        threshold: u32,
+        recovery_delay: u32,
+    ) -> Result<Self, AccountError> {
+        let guardians_map =
+            StorageMap::with_entries(guardians.iter().map(|id| {
+                (Digest::from([ZERO, ZERO, ZERO, (*id).into()]), [ONE, ZERO, ZERO, ZERO])
+            }))?;
+        if guardians.is_empty() || guardians_map.entries().count() != guardians.len() {
+            return Err(AccountError::SocialRecoveryInvalidConfig(
+                "Guardians must be a non-empty list of distinct accounts".to_string(),
+            ));
+        } else if threshold == 0 || threshold as usize > guardians.len() {
+            return Err(AccountError::SocialRecoveryInvalidConfig(
+                "Threshold must be > 0 and <= number of guardians".to_string(),
+            ));
+        }
+
+        let pub_key: Word = match auth_scheme {
+            AuthScheme::RpoFalcon512 { pub_key } => pub_key.into(),
+        };
+
+        Ok(Self {
+            pub_key,
+            guardians: guardians_map,
+            threshold,
+            recovery_delay,
+        })
+    }
+}
+
+impl From<SocialRecovery> for AccountComponent {
+    fn from(recovery: SocialRecovery) -> Self {
+        let source_code = "
+            export.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+            export.::miden::contracts::auth::basic::rotate_key_rpo_falcon512
+            export.::miden::contracts::recovery::social::approve_recovery
+            export.::miden::contracts::recovery::social::auth_tx_recovery
+            export.::miden::contracts::recovery::social::finalize_recovery
+            export.::miden::contracts::recovery::social::cancel_recovery
+        ";
+
+        // Note: data is stored as [a0, a1, a2, a3] but loaded onto the stack as
+        // [a3, a2, a1, a0, ...]
+        let recovery_config =
+            [Felt::from(recovery.threshold), Felt::from(recovery.recovery_delay), ZERO, ZERO];
+
+        // The component stores the public key and the recovery state:
+        // - slot 0: public key
+        // - slot 1: recovery config as [threshold, recovery_delay, epoch, 0]
+        // - slot 2: guardians map
+        // - slot 3: approvals map
+        // - slot 4: tallies map
+        let approvals_map = StorageMap::new();
+        let tallies_map = StorageMap::new();
+        AccountComponent::compile(
+            source_code,
+            TransactionKernel::assembler(),
+            vec![
+                StorageSlot::new_value(recovery.pub_key),
+                StorageSlot::new_value(recovery_config),
+                StorageSlot::new_map(recovery.guardians.root().into()),
+                StorageSlot::new_map(approvals_map.root().into()),
+                StorageSlot::new_map(tallies_map.root().into()),
+            ],
+        )
+        .and_then(|component| component.with_storage_map(2, recovery.guardians))
+        .and_then(|component| component.with_storage_map(3, approvals_map))
+        .and_then(|component| component.with_storage_map(4, tallies_map))
+        .expect("social recovery component should satisfy the requirements of a valid component")
+        .with_supported_type(AccountType::RegularAccountImmutableCode)
+        .with_supported_type(AccountType::RegularAccountUpdatableCode)
+    }
+}
+
+/// Creates a new account with basic wallet interface extended with social recovery, the specified
+/// authentication scheme and the account storage type.
+///
+/// The account is built from the [SocialRecovery] component authenticating with the key of the
+/// specified scheme and the [BasicWallet] component. Thus, in addition to the basic wallet
+/// procedures, the account exposes `approve_recovery`, `auth_tx_recovery`, `finalize_recovery` and
+/// `cancel_recovery`.
+///
+/// Public key information for the scheme is stored in the account storage at slot 0, the recovery
+/// config at slot 1 and the guardians at slot 2. Slots 3 and 4 hold the approvals of the guardians.
+///
+/// # Errors
+/// Returns an error if:
+/// - The account type is a faucet.
+/// - The list of guardians is empty or contains duplicates.
+/// - The threshold is zero or greater than the number of guardians.
+pub fn create_recoverable_wallet(
+    init_seed: [u8; 32],
+    auth_scheme: AuthScheme,
+    guardians: &[AccountId],
+    threshold: u32,
+    recovery_delay: u32,
+    account_type: AccountType,
+    account_storage_type: AccountStorageType,
+) -> Result<(Account, Word), AccountError> {
+    if matches!(account_type, AccountType::FungibleFaucet | AccountType::NonFungibleFaucet) {
+        return Err(AccountError::AccountIdInvalidFieldElement(
+            "Recoverable wallet accounts cannot have a faucet account type".to_string(),
+        ));
+    }
+
+    AccountBuilder::new(init_seed)
+        .account_type(account_type)
+        .storage_type(account_storage_type)
+        .with_component(SocialRecovery::new(auth_scheme, guardians, threshold, recovery_delay)?)
+        .with_component(BasicWallet)
+        .build()
+}
+
+/// Returns a transaction script which authenticates a transaction consuming the recovery notes of
+/// the provided guardians, which approve the public key of the provided authentication scheme.
+///
+/// The transaction does not need to be signed, but fails if the approvals of the guardians are not
+/// the only changes of the account, e.g., if the transaction consumes other notes, the recovery
+/// notes carry assets or the transaction creates notes.
+pub fn build_approve_recovery_tx_script(
+    new_auth_scheme: AuthScheme,
+    guardians: &[AccountId],
+) -> Result<TransactionScript, TransactionScriptError> {
+    let new_pub_key: Word = match new_auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } => pub_key.into(),
+    };
+
+    let source_code = format!(
+        "
+        begin
+            push.{new_pub_key}
+            call.::miden::contracts::recovery::social::auth_tx_recovery
+            dropw
+        end
+    ",
+        new_pub_key = word_to_masm_push_string(&new_pub_key),
+    );
+    let guardians = guardians.iter().map(|id| Felt::from(*id)).collect();
+
+    TransactionScript::compile(
+        source_code,
+        [(new_pub_key, guardians)],
+        TransactionKernel::assembler(),
+    )
+}
+
+/// Returns a transaction script which finalizes the recovery of a recoverable wallet, replacing
+/// its key with the public key of the provided authentication scheme.
+///
+/// The transaction must be signed with the new key, and fails if not enough guardians approved the
+/// new key or if the recovery delay has not elapsed yet.
+pub fn build_finalize_recovery_tx_script(
+    new_auth_scheme: AuthScheme,
+) -> Result<TransactionScript, TransactionScriptError> {
+    let new_pub_key: Word = match new_auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } => pub_key.into(),
     };
 
-    let source_code: String = format!(
+    let source_code = format!(
         "
-        export.::miden::contracts::wallets::basic::receive_asset
-        export.::miden::contracts::wallets::basic::send_asset
-        export.::miden::contracts::auth::basic::{auth_scheme_procedure}
-    "
+        begin
+            push.{new_pub_key}
+            call.::miden::contracts::recovery::social::finalize_recovery
+            dropw
+            call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+        end
+    ",
+        new_pub_key = word_to_masm_push_string(&new_pub_key),
     );
 
-    let assembler = TransactionKernel::assembler();
-    let account_code = AccountCode::compile(source_code, assembler)?;
+    TransactionScript::compile(source_code, [], TransactionKernel::assembler())
+}
+
+/// Returns a transaction script which cancels all pending recoveries of a recoverable wallet.
+///
+/// The transaction must be signed with the current key of the wallet, which signs both the
+/// cancellation and the transaction itself.
+pub fn build_cancel_recovery_tx_script() -> Result<TransactionScript, TransactionScriptError> {
+    let source_code = "
+        begin
+            call.::miden::contracts::recovery::social::cancel_recovery
+            call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+        end
+    ";
+
+    TransactionScript::compile(source_code, [], TransactionKernel::assembler())
+}
 
-    let account_storage =
-        AccountStorage::new(vec![SlotItem::new_value(0, 0, storage_slot_0_data)], BTreeMap::new())?;
+// SPENDING LIMITS
+// ================================================================================================
 
-    let account_seed = AccountId::get_account_seed(
-        init_seed,
-        account_type,
-        account_storage_type,
-        account_code.commitment(),
-        account_storage.root(),
-    )?;
+/// A limit on the amount of a fungible asset which can be sent out of a wallet within a window of
+/// blocks without being co-signed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpendingLimit {
+    faucet_id: AccountId,
+    amount: u64,
+    window_length: u32,
+}
+
+impl SpendingLimit {
+    /// Returns a new [SpendingLimit] allowing to send `amount` of the assets issued by the
+    /// specified faucet within every window of `window_length` blocks.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The faucet is not a fungible faucet.
+    /// - The amount is greater than the maximum amount of a fungible asset.
+    /// - The window length is zero.
+    pub fn new(
+        faucet_id: AccountId,
+        amount: u64,
+        window_length: u32,
+    ) -> Result<Self, AccountError> {
+        if faucet_id.account_type() != AccountType::FungibleFaucet {
+            return Err(AccountError::SpendingLimitInvalidConfig(
+                "Spending limits can only be set for fungible faucets".to_string(),
+            ));
+        } else if amount > FungibleAsset::MAX_AMOUNT {
+            return Err(AccountError::SpendingLimitInvalidConfig(format!(
+                "Spending limit must be <= {}",
+                FungibleAsset::MAX_AMOUNT
+            )));
+        } else if window_length == 0 {
+            return Err(AccountError::SpendingLimitInvalidConfig(
+                "Window length must be > 0".to_string(),
+            ));
+        }
+
+        Ok(Self { faucet_id, amount, window_length })
+    }
+
+    /// Returns the ID of the faucet whose assets are limited.
+    pub fn faucet_id(&self) -> AccountId {
+        self.faucet_id
+    }
 
-    Ok((Account::new(account_seed, account_code, account_storage)?, account_seed))
+    /// Returns the amount which can be sent within a window without being co-signed.
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    /// Returns the length of a window in blocks.
+    pub fn window_length(&self) -> u32 {
+        self.window_length
+    }
+
+    /// Returns the key and the value under which the limit is stored in the limits map.
+    fn to_map_entry(self) -> (Digest, Word) {
+        (
+            Digest::from([ZERO, ZERO, ZERO, self.faucet_id.into()]),
+            [Felt::new(self.amount), Felt::from(self.window_length), ZERO, ZERO],
+        )
+    }
+}
+
+/// An [AccountComponent] implementing a wallet with spending limits.
+///
+/// The component exposes the same procedures as [BasicWallet], except that `send_asset` enforces
+/// the spending limits: sending more than the limit of a faucet within a window of blocks requires
+/// the transfer to be co-signed with the co-signer key. The co-signature commits to the asset and
+/// the recipient of the transfer, and is required in addition to the signature of the transaction
+/// with the key of the account. Co-signed transfers do not count towards the limit. Assets of
+/// faucets without a spending limit can be sent freely. The limits can be changed via
+/// `set_spending_limit`, see [build_set_spending_limit_tx_script()]. Thus, the component replaces
+/// the [BasicWallet] component and must not be combined with it.
+///
+/// The component uses three storage slots: the co-signer public key, the spending limits and the
+/// amounts spent. It must be combined with an authentication component, e.g., [RpoFalcon512] or
+/// [SocialRecovery]. The component supports regular account types only.
+pub struct SpendingLimits {
+    cosigner_pub_key: Word,
+    limits: StorageMap,
+}
+
+impl SpendingLimits {
+    /// Returns a new [SpendingLimits] component enforcing the provided spending limits, which can
+    /// be exceeded with the co-signature of the key of `cosigner_auth_scheme`.
+    ///
+    /// # Errors
+    /// Returns an error if the spending limits contain several limits for the same faucet.
+    pub fn new(
+        cosigner_auth_scheme: AuthScheme,
+        spending_limits: &[SpendingLimit],
+    ) -> Result<Self, AccountError> {
+        let limits_map =
+            StorageMap::with_entries(spending_limits.iter().map(|limit| limit.to_map_entry()))?;
+        if limits_map.entries().count() != spending_limits.len() {
+            return Err(AccountError::SpendingLimitInvalidConfig(
+                "Spending limits must be set for distinct faucets".to_string(),
+            ));
+        }
+
+        let cosigner_pub_key: Word = match cosigner_auth_scheme {
+            AuthScheme::RpoFalcon512 { pub_key } => pub_key.into(),
+        };
+
+        Ok(Self { cosigner_pub_key, limits: limits_map })
+    }
+}
+
+impl From<SpendingLimits> for AccountComponent {
+    fn from(limits: SpendingLimits) -> Self {
+        let source_code = "
+            export.::miden::contracts::wallets::basic::receive_asset
+            export.::miden::contracts::limits::spending::send_asset
+            export.::miden::contracts::limits::spending::set_spending_limit
+        ";
+
+        // The component stores the spending limits state:
+        // - slot 0: co-signer public key
+        // - slot 1: spending limits map
+        // - slot 2: amounts spent map
+        let spending_map = StorageMap::new();
+        AccountComponent::compile(
+            source_code,
+            TransactionKernel::assembler(),
+            vec![
+                StorageSlot::new_value(limits.cosigner_pub_key),
+                StorageSlot::new_map(limits.limits.root().into()),
+                StorageSlot::new_map(spending_map.root().into()),
+            ],
+        )
+        .and_then(|component| component.with_storage_map(1, limits.limits))
+        .and_then(|component| component.with_storage_map(2, spending_map))
+        .expect("spending limits component should satisfy the requirements of a valid component")
+        .with_supported_type(AccountType::RegularAccountImmutableCode)
+        .with_supported_type(AccountType::RegularAccountUpdatableCode)
+    }
+}
+
+/// Creates a new account with basic wallet interface extended with spending limits, the specified
+/// authentication scheme and the account storage type.
+///
+/// The account is built from the authentication component of the specified scheme and the
+/// [SpendingLimits] component. Thus, the account exposes the same procedures as the basic wallet,
+/// except that `send_asset` enforces the spending limits, and `set_spending_limit`.
+///
+/// Public key information for the scheme is stored in the account storage at slot 0, the co-signer
+/// public key at slot 1, the spending limits at slot 2 and the amounts spent at slot 3.
+///
+/// # Errors
+/// Returns an error if:
+/// - The account type is a faucet.
+/// - The spending limits contain several limits for the same faucet.
+pub fn create_wallet_with_spending_limits(
+    init_seed: [u8; 32],
+    auth_scheme: AuthScheme,
+    cosigner_auth_scheme: AuthScheme,
+    spending_limits: &[SpendingLimit],
+    account_type: AccountType,
+    account_storage_type: AccountStorageType,
+) -> Result<(Account, Word), AccountError> {
+    if matches!(account_type, AccountType::FungibleFaucet | AccountType::NonFungibleFaucet) {
+        return Err(AccountError::AccountIdInvalidFieldElement(
+            "Wallet accounts cannot have a faucet account type".to_string(),
+        ));
+    }
+
+    AccountBuilder::new(init_seed)
+        .account_type(account_type)
+        .storage_type(account_storage_type)
+        .with_component(auth_component(auth_scheme))
+        .with_component(SpendingLimits::new(cosigner_auth_scheme, spending_limits)?)
+        .build()
+}
+
+/// Returns a transaction script which sets the provided spending limit on a wallet with spending
+/// limits.
+///
+/// The transaction must be signed with both the key of the wallet and the co-signer key.
+pub fn build_set_spending_limit_tx_script(
+    spending_limit: SpendingLimit,
+) -> Result<TransactionScript, TransactionScriptError> {
+    let source_code = format!(
+        "
+        begin
+            push.{window_length}.{amount}.{faucet_id}
+            call.::miden::contracts::limits::spending::set_spending_limit
+            dropw
+            call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+        end
+    ",
+        window_length = spending_limit.window_length,
+        amount = spending_limit.amount,
+        faucet_id = u64::from(spending_limit.faucet_id),
+    );
+
+    TransactionScript::compile(source_code, [], TransactionKernel::assembler())
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the authentication component of the provided authentication scheme.
+fn auth_component(auth_scheme: AuthScheme) -> AccountComponent {
+    match auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } => RpoFalcon512::new(pub_key).into(),
+    }
 }
 
 // TESTS
@@ -73,10 +553,28 @@ pub fn create_basic_wallet(
 #[cfg(test)]
 mod tests {
 
-    use miden_objects::{crypto::dsa::rpo_falcon512, ONE};
+    use alloc::{format, string::ToString, vec::Vec};
+
+    use miden_objects::{
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+                ACCOUNT_ID_OFF_CHAIN_SENDER, ACCOUNT_ID_SENDER,
+            },
+            SchemaValueType, SlotSchema, SlotSchemaType, StorageSchema,
+        },
+        assets::FungibleAsset,
+        crypto::dsa::rpo_falcon512,
+        ONE,
+    };
     use vm_processor::utils::{Deserializable, Serializable};
 
-    use super::{create_basic_wallet, Account, AccountStorageType, AccountType, AuthScheme};
+    use super::{
+        create_basic_wallet, create_recoverable_wallet, create_wallet_with_spending_limits,
+        Account, AccountBuilder, AccountComponent, AccountError, AccountId, AccountStorageType,
+        AccountType, AuthScheme, Digest, Felt, RpoFalcon512, SocialRecovery, SpendingLimit,
+        SpendingLimits, Word, ZERO,
+    };
 
     #[test]
     fn test_create_basic_wallet() {
@@ -109,4 +607,200 @@ mod tests {
         let deserialized_wallet = Account::read_from_bytes(&bytes).unwrap();
         assert_eq!(wallet, deserialized_wallet);
     }
+
+    #[test]
+    fn test_create_recoverable_wallet() {
+        let pub_key = rpo_falcon512::PublicKey::new([ONE; 4]);
+        let guardian_1 = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let guardian_2 = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+
+        let create_wallet = |guardians: &[AccountId], threshold, account_type| {
+            create_recoverable_wallet(
+                [1; 32],
+                AuthScheme::RpoFalcon512 { pub_key },
+                guardians,
+                threshold,
+                10,
+                account_type,
+                AccountStorageType::OnChain,
+            )
+        };
+
+        let (wallet, _) =
+            create_wallet(&[guardian_1, guardian_2], 2, AccountType::RegularAccountUpdatableCode)
+                .unwrap();
+        assert_eq!(wallet.storage().maps().len(), 3);
+
+        // invalid guardians
+        for guardians in [&[][..], &[guardian_1, guardian_1][..]] {
+            assert!(matches!(
+                create_wallet(guardians, 1, AccountType::RegularAccountUpdatableCode),
+                Err(AccountError::SocialRecoveryInvalidConfig(_))
+            ));
+        }
+
+        // invalid threshold
+        for threshold in [0, 3] {
+            assert!(matches!(
+                create_wallet(
+                    &[guardian_1, guardian_2],
+                    threshold,
+                    AccountType::RegularAccountUpdatableCode
+                ),
+                Err(AccountError::SocialRecoveryInvalidConfig(_))
+            ));
+        }
+
+        // invalid account type
+        assert!(create_wallet(&[guardian_1], 1, AccountType::FungibleFaucet).is_err());
+    }
+
+    #[test]
+    fn test_recoverable_wallet_storage_schema() {
+        let pub_key = rpo_falcon512::PublicKey::new([ONE; 4]);
+        let guardian_1 = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let guardian_2 = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+
+        let (wallet, _) = create_recoverable_wallet(
+            [1; 32],
+            AuthScheme::RpoFalcon512 { pub_key },
+            &[guardian_1, guardian_2],
+            2,
+            10,
+            AccountType::RegularAccountUpdatableCode,
+            AccountStorageType::OnChain,
+        )
+        .unwrap();
+
+        // the guardians map is the only map of the wallet holding an entry per guardian
+        let guardians_slot = wallet
+            .storage()
+            .maps()
+            .iter()
+            .find(|(_, map)| map.entries().count() == 2)
+            .map(|(index, _)| *index)
+            .unwrap();
+
+        let schema = StorageSchema::new()
+            .with_slot(
+                guardians_slot,
+                SlotSchema::new(
+                    "guardians",
+                    SlotSchemaType::Map {
+                        key: SchemaValueType::AccountId,
+                        value: SchemaValueType::Felt,
+                    },
+                ),
+            )
+            .unwrap();
+        schema.validate(wallet.storage()).unwrap();
+
+        let rendered = schema.display(wallet.storage()).to_string();
+        for guardian in [guardian_1, guardian_2] {
+            assert!(rendered.lines().any(|line| line == format!("    {guardian} => 1")));
+        }
+    }
+
+    #[test]
+    fn test_create_wallet_with_spending_limits() {
+        let pub_key = rpo_falcon512::PublicKey::new([ONE; 4]);
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let limit = SpendingLimit::new(faucet_id, 100, 10).unwrap();
+
+        let create_wallet = |spending_limits: &[SpendingLimit]| {
+            create_wallet_with_spending_limits(
+                [1; 32],
+                AuthScheme::RpoFalcon512 { pub_key },
+                AuthScheme::RpoFalcon512 { pub_key },
+                spending_limits,
+                AccountType::RegularAccountUpdatableCode,
+                AccountStorageType::OnChain,
+            )
+        };
+
+        let (wallet, _) = create_wallet(&[limit]).unwrap();
+        assert_eq!(wallet.storage().maps().len(), 2);
+
+        assert!(matches!(
+            create_wallet(&[limit, limit]),
+            Err(AccountError::SpendingLimitInvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_recoverable_wallet_with_spending_limits() {
+        let pub_key = rpo_falcon512::PublicKey::new([ONE; 4]);
+        let cosigner_pub_key = rpo_falcon512::PublicKey::new([ONE, ONE, ONE, ZERO]);
+        let guardian = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let limit = SpendingLimit::new(faucet_id, 100, 10).unwrap();
+
+        let recovery = || {
+            SocialRecovery::new(AuthScheme::RpoFalcon512 { pub_key }, &[guardian], 1, 10).unwrap()
+        };
+        let spending_limits = || {
+            SpendingLimits::new(AuthScheme::RpoFalcon512 { pub_key: cosigner_pub_key }, &[limit])
+                .unwrap()
+        };
+
+        // the slots of the spending limits are placed after the slots of the social recovery
+        let (wallet, _) = AccountBuilder::new([1; 32])
+            .with_component(recovery())
+            .with_component(spending_limits())
+            .build()
+            .unwrap();
+
+        let storage = wallet.storage();
+        assert_eq!(storage.get_item(0), Word::from(pub_key).into());
+        assert_eq!(storage.get_item(1), [Felt::from(1_u32), Felt::from(10_u32), ZERO, ZERO].into());
+        assert_eq!(storage.get_item(5), Word::from(cosigner_pub_key).into());
+        assert_eq!(storage.get_map_item(2, [ZERO, ZERO, ZERO, guardian.into()]).unwrap()[0], ONE);
+        assert_eq!(
+            storage.get_map_item(6, [ZERO, ZERO, ZERO, faucet_id.into()]).unwrap(),
+            [Felt::new(100), Felt::from(10_u32), ZERO, ZERO]
+        );
+
+        // the procedures of each component can only access the slots of the component
+        let recovery_roots: Vec<Digest> =
+            AccountComponent::from(recovery()).procedure_roots().collect();
+        let code = wallet.code();
+        assert_eq!(code.num_procedures(), recovery_roots.len() + 3);
+        for procedure in code.procedures() {
+            let expected = if recovery_roots.contains(procedure.mast_root()) {
+                (0, 5)
+            } else {
+                (5, 3)
+            };
+            assert_eq!((procedure.storage_offset(), procedure.storage_size()), expected);
+        }
+
+        // the social recovery authenticates the account, and thus cannot be combined with another
+        // authentication component
+        let auth_roots: Vec<Digest> =
+            AccountComponent::from(RpoFalcon512::new(pub_key)).procedure_roots().collect();
+        let result = AccountBuilder::new([1; 32])
+            .with_component(RpoFalcon512::new(pub_key))
+            .with_component(spending_limits())
+            .with_component(recovery())
+            .build();
+        assert!(matches!(
+            result,
+            Err(AccountError::AccountComponentDuplicateProcedure(root)) if auth_roots.contains(&root)
+        ));
+    }
+
+    #[test]
+    fn test_invalid_spending_limit() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let non_fungible_faucet_id =
+            AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        for result in [
+            SpendingLimit::new(non_fungible_faucet_id, 100, 10),
+            SpendingLimit::new(faucet_id, FungibleAsset::MAX_AMOUNT + 1, 10),
+            SpendingLimit::new(faucet_id, 100, 0),
+        ] {
+            assert!(matches!(result, Err(AccountError::SpendingLimitInvalidConfig(_))));
+        }
+    }
 }
@@ -1,20 +1,39 @@
 use alloc::vec::Vec;
 
+#[cfg(feature = "note-encryption")]
+use miden_objects::notes::StealthAddress;
 use miden_objects::{
     accounts::AccountId,
     assets::Asset,
     crypto::rand::FeltRng,
     notes::{
         Note, NoteAssets, NoteDetails, NoteExecutionHint, NoteExecutionMode, NoteInputs,
-        NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType,
+        NoteMetadata, NoteRecipient, NoteTag, NoteType,
     },
-    utils::Deserializable,
-    vm::Program,
-    Felt, NoteError, Word,
+    Felt, NoteError, Word, ZERO,
 };
 
+use crate::AuthScheme;
+
 pub mod utils;
 
+mod note_tag_use_case;
+pub use note_tag_use_case::{NoteTagUseCase, STEALTH_USE_CASE_ID, SWAP_USE_CASE_ID};
+
+mod registry;
+pub use registry::NoteLibraryRegistry;
+
+#[cfg(feature = "note-encryption")]
+mod stealth;
+#[cfg(feature = "note-encryption")]
+pub use stealth::StealthNoteScanner;
+
+mod well_known_note;
+pub use well_known_note::{
+    NoteConsumability, P2idNoteInputs, P2idrNoteInputs, RecoveryNoteInputs, StealthNoteInputs,
+    SwapNoteInputs, WellKnownNote, WellKnownNoteInputs,
+};
+
 // STANDARDIZED SCRIPTS
 // ================================================================================================
 
@@ -27,7 +46,7 @@ pub mod utils;
 /// is set to the target's account ID.
 ///
 /// # Errors
-/// Returns an error if deserialization or compilation of the `P2ID` script fails.
+/// Returns an error if the provided assets or the note metadata are invalid.
 pub fn create_p2id_note<R: FeltRng>(
     sender: AccountId,
     target: AccountId,
@@ -36,10 +55,7 @@ pub fn create_p2id_note<R: FeltRng>(
     aux: Felt,
     rng: &mut R,
 ) -> Result<Note, NoteError> {
-    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID.masb"));
-    let program =
-        Program::read_from_bytes(bytes).map_err(NoteError::NoteScriptDeserializationError)?;
-    let note_script = NoteScript::new(program);
+    let note_script = WellKnownNote::P2ID.script();
 
     let inputs = NoteInputs::new(vec![target.into()])?;
     let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
@@ -51,6 +67,49 @@ pub fn create_p2id_note<R: FeltRng>(
     Ok(Note::new(vault, metadata, recipient))
 }
 
+/// Generates a STEALTH note - pay to a one-time recipient of a stealth address.
+///
+/// This script enables the transfer of assets from the `sender` account to the account of the
+/// provided stealth address, without revealing the target account in the note. Instead, the note
+/// commits to a one-time recipient derived from the viewing key of the address, and the target
+/// account consumes the note by providing the blinding factor of the recipient as the note args.
+///
+/// The passed-in `rng` is used to generate the serial number of the note and the ephemeral secret
+/// of the one-time recipient. The returned note is public and its tag is shared by all STEALTH
+/// notes, while its `aux` value is set to the view tag of the recipient; the target account can
+/// detect it using a [StealthNoteScanner].
+///
+/// # Errors
+/// Returns an error if the viewing key of the address is invalid, or if the provided assets or
+/// the note metadata are invalid.
+#[cfg(feature = "note-encryption")]
+pub fn create_stealth_note<R: FeltRng>(
+    sender: AccountId,
+    address: &StealthAddress,
+    assets: Vec<Asset>,
+    rng: &mut R,
+) -> Result<Note, NoteError> {
+    let note_script = WellKnownNote::STEALTH.script();
+
+    let ephemeral_secret = rng.draw_word();
+    let mut ephemeral_secret_bytes = [0; 32];
+    for (bytes, element) in ephemeral_secret_bytes.chunks_mut(8).zip(ephemeral_secret) {
+        bytes.copy_from_slice(&element.as_int().to_le_bytes());
+    }
+    let recipient = address.derive_recipient(ephemeral_secret_bytes)?;
+
+    let inputs = stealth::build_stealth_note_inputs(&recipient)?;
+    let tag = NoteTagUseCase::Stealth.tag();
+    let aux = Felt::from(recipient.view_tag());
+    let serial_num = rng.draw_word();
+
+    let metadata =
+        NoteMetadata::new(sender, NoteType::Public, tag, NoteExecutionHint::always(), aux)?;
+    let vault = NoteAssets::new(assets)?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    Ok(Note::new(vault, metadata, recipient))
+}
+
 /// Generates a P2IDR note - pay to id with recall after a certain block height.
 ///
 /// This script enables the transfer of assets from the sender `sender` account to the `target`
@@ -62,7 +121,7 @@ pub fn create_p2id_note<R: FeltRng>(
 /// is set to the target's account ID.
 ///
 /// # Errors
-/// Returns an error if deserialization or compilation of the `P2IDR` script fails.
+/// Returns an error if the provided assets or the note metadata are invalid.
 pub fn create_p2idr_note<R: FeltRng>(
     sender: AccountId,
     target: AccountId,
@@ -72,10 +131,7 @@ pub fn create_p2idr_note<R: FeltRng>(
     recall_height: u32,
     rng: &mut R,
 ) -> Result<Note, NoteError> {
-    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2IDR.masb"));
-    let program =
-        Program::read_from_bytes(bytes).map_err(NoteError::NoteScriptDeserializationError)?;
-    let note_script = NoteScript::new(program);
+    let note_script = WellKnownNote::P2IDR.script();
 
     let inputs = NoteInputs::new(vec![target.into(), recall_height.into()])?;
     let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
@@ -87,6 +143,48 @@ pub fn create_p2idr_note<R: FeltRng>(
     Ok(Note::new(vault, metadata, recipient))
 }
 
+/// Generates a RECOVERY note - a note approving the recovery of an account with social recovery.
+///
+/// This script enables a guardian (the `sender` account) to approve the replacement of the public
+/// key of the `target` account by the public key of the provided authentication scheme. The
+/// approval is only accepted if the `sender` is a guardian of the `target` account. The approval
+/// does not authenticate the transaction consuming the note, see
+/// [build_approve_recovery_tx_script()](crate::accounts::wallets::build_approve_recovery_tx_script).
+///
+/// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag
+/// is set to the target's account ID.
+///
+/// # Errors
+/// Returns an error if the note metadata is invalid.
+pub fn create_recovery_note<R: FeltRng>(
+    sender: AccountId,
+    target: AccountId,
+    new_auth_scheme: AuthScheme,
+    note_type: NoteType,
+    rng: &mut R,
+) -> Result<Note, NoteError> {
+    let note_script = WellKnownNote::RECOVERY.script();
+
+    let new_pub_key: Word = match new_auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } => pub_key.into(),
+    };
+
+    let inputs = NoteInputs::new(vec![
+        new_pub_key[0],
+        new_pub_key[1],
+        new_pub_key[2],
+        new_pub_key[3],
+        target.into(),
+    ])?;
+    let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
+    let serial_num = rng.draw_word();
+
+    let vault = NoteAssets::new(vec![])?;
+    let metadata = NoteMetadata::new(sender, note_type, tag, NoteExecutionHint::always(), ZERO)?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    Ok(Note::new(vault, metadata, recipient))
+}
+
 /// Generates a SWAP note - swap of assets between two accounts - and returns the note as well as
 /// [NoteDetails] for the payback note.
 ///
@@ -94,8 +192,11 @@ pub fn create_p2idr_note<R: FeltRng>(
 /// is willing to consume the note. The consumer will receive the `offered_asset` and will create a
 /// new P2ID note with `sender` as target, containing the `requested_asset`.
 ///
+/// The returned note's tag is shared by all SWAP notes of the same type trading the same pair of
+/// assets, in either direction, so that the order book of a pair can be discovered by its tag.
+///
 /// # Errors
-/// Returns an error if deserialization or compilation of the `SWAP` script fails.
+/// Returns an error if the provided assets or the note metadata are invalid.
 pub fn create_swap_note<R: FeltRng>(
     sender: AccountId,
     offered_asset: Asset,
@@ -104,10 +205,7 @@ pub fn create_swap_note<R: FeltRng>(
     aux: Felt,
     rng: &mut R,
 ) -> Result<(Note, NoteDetails), NoteError> {
-    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SWAP.masb"));
-    let program =
-        Program::read_from_bytes(bytes).map_err(NoteError::NoteScriptDeserializationError)?;
-    let note_script = NoteScript::new(program);
+    let note_script = WellKnownNote::SWAP.script();
 
     let payback_serial_num = rng.draw_word();
     let payback_recipient = utils::build_p2id_recipient(sender, payback_serial_num)?;
@@ -151,10 +249,10 @@ pub fn create_swap_note<R: FeltRng>(
 
 /// Returns a note tag for a swap note with the specified parameters.
 ///
-/// Use case ID for the returned tag is set to 0.
+/// Use case ID for the returned tag is set to [SWAP_USE_CASE_ID].
 ///
-/// Tag payload is constructed by taking asset tags (8 bits of faucet ID) and concatenating them
-/// together as offered_asset_tag + requested_asset tag.
+/// Tag payload is set to the ID of the pair of faucets of the offered and requested assets, which
+/// does not depend on the direction of the swap; see [NoteTagUseCase::SwapPair] for details.
 ///
 /// Network execution hint for the returned tag is set to `Local`.
 pub fn build_swap_tag(
@@ -162,23 +260,11 @@ pub fn build_swap_tag(
     offered_asset: &Asset,
     requested_asset: &Asset,
 ) -> Result<NoteTag, NoteError> {
-    const SWAP_USE_CASE_ID: u16 = 0;
-
-    // get bits 4..12 from faucet IDs of both assets, these bits will form the tag payload; the
-    // reason we skip the 4 most significant bits is that these encode metadata of underlying
-    // faucets and are likely to be the same for many different faucets.
-
-    let offered_asset_id: u64 = offered_asset.faucet_id().into();
-    let offered_asset_tag = (offered_asset_id >> 52) as u8;
+    let use_case = NoteTagUseCase::swap_pair(
+        note_type,
+        offered_asset.faucet_id(),
+        requested_asset.faucet_id(),
+    );
 
-    let requested_asset_id: u64 = requested_asset.faucet_id().into();
-    let requested_asset_tag = (requested_asset_id >> 52) as u8;
-
-    let payload = ((offered_asset_tag as u16) << 8) | (requested_asset_tag as u16);
-
-    let execution = NoteExecutionMode::Local;
-    match note_type {
-        NoteType::Public => NoteTag::for_public_use_case(SWAP_USE_CASE_ID, payload, execution),
-        _ => NoteTag::for_local_use_case(SWAP_USE_CASE_ID, payload),
-    }
+    use_case.tag().validate(note_type)
 }
@@ -0,0 +1,275 @@
+use miden_objects::{
+    accounts::AccountId,
+    notes::{NoteExecutionMode, NoteTag, NoteType},
+    Felt, Hasher,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The use case ID of tags of SWAP notes.
+pub const SWAP_USE_CASE_ID: u16 = 0;
+
+/// The use case ID of tags of STEALTH notes.
+pub const STEALTH_USE_CASE_ID: u16 = 1;
+
+/// The two most significant bits of a [NoteTag].
+const TAG_PREFIX_MASK: u32 = 0xc0000000;
+
+/// Tags with a specific network target have the two most significant bits set to `0b00`.
+const NETWORK_ACCOUNT_PREFIX: u32 = 0x00000000;
+
+/// Public use case tags for local execution have the two most significant bits set to `0b10`.
+const PUBLIC_LOCAL_PREFIX: u32 = 0x80000000;
+
+/// Tags for local execution which allow any note type have the two most significant bits set to
+/// `0b11`.
+const ANY_LOCAL_PREFIX: u32 = 0xc0000000;
+
+// NOTE TAG USE CASE
+// ================================================================================================
+
+/// The uses of [NoteTag]s by the notes provided by miden-lib.
+///
+/// A [NoteTagUseCase] can be converted into the [NoteTag] it is represented by, and decoded back
+/// from a tag using [NoteTagUseCase::from_tag()]. Since only some bits of the account and faucet
+/// IDs make it into the tag, a decoded use case identifies a set of candidate notes rather than
+/// a single note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteTagUseCase {
+    /// A note targeting a specific account, such as a P2ID or P2IDR note.
+    ///
+    /// For local execution, the prefix holds the 14 most significant bits of the account ID; for
+    /// network execution, it holds the 31 most significant bits.
+    Account {
+        account_id_prefix: u32,
+        execution: NoteExecutionMode,
+    },
+
+    /// A SWAP note trading the assets of two faucets.
+    ///
+    /// The pair ID does not depend on which of the assets is offered, so all SWAP notes trading
+    /// the same pair of assets share a tag, and an order book for the pair can be discovered by
+    /// syncing on a single tag.
+    ///
+    /// Pair IDs computed by [NoteTagUseCase::swap_pair()] are never 0: the tag of a private SWAP
+    /// note with pair ID 0 is the same as the tag of a note targeting a local account whose ID
+    /// prefix is 0, and is decoded as [NoteTagUseCase::Account].
+    SwapPair { pair_id: u16, is_public: bool },
+
+    /// A STEALTH note, i.e., a public note sent to a one-time recipient.
+    ///
+    /// All STEALTH notes share a tag, so the tag does not reveal the recipient of the note.
+    Stealth,
+}
+
+impl NoteTagUseCase {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the use case of a note targeting the specified account.
+    ///
+    /// This matches the tags built by [NoteTag::from_account_id()].
+    pub fn account(account_id: AccountId, execution: NoteExecutionMode) -> Self {
+        let id: u64 = account_id.into();
+        let account_id_prefix = match execution {
+            NoteExecutionMode::Local => (id >> 50) as u32,
+            NoteExecutionMode::Network => (id >> 33) as u32,
+        };
+
+        Self::Account { account_id_prefix, execution }
+    }
+
+    /// Returns the use case of a SWAP note of the specified type trading the assets of the
+    /// specified faucets.
+    ///
+    /// The order of the faucets does not matter, i.e., swapping the offered and requested faucets
+    /// results in the same use case.
+    pub fn swap_pair(note_type: NoteType, faucet_a: AccountId, faucet_b: AccountId) -> Self {
+        Self::SwapPair {
+            pair_id: swap_pair_id(faucet_a, faucet_b),
+            is_public: note_type == NoteType::Public,
+        }
+    }
+
+    /// Decodes the use case represented by the specified tag, or returns `None` if the tag does
+    /// not belong to any of the use cases defined in miden-lib.
+    ///
+    /// Tags of notes targeting local accounts share their encoding with local use case tags whose
+    /// payload is 0; such tags are decoded as [NoteTagUseCase::Account].
+    pub fn from_tag(tag: NoteTag) -> Option<Self> {
+        let tag = tag.inner();
+        let use_case_id = ((tag >> 16) & 0x3fff) as u16;
+        let payload = tag as u16;
+
+        match tag & TAG_PREFIX_MASK {
+            NETWORK_ACCOUNT_PREFIX => Some(Self::Account {
+                account_id_prefix: tag,
+                execution: NoteExecutionMode::Network,
+            }),
+            ANY_LOCAL_PREFIX if payload == 0 => Some(Self::Account {
+                account_id_prefix: use_case_id as u32,
+                execution: NoteExecutionMode::Local,
+            }),
+            PUBLIC_LOCAL_PREFIX if use_case_id == SWAP_USE_CASE_ID => {
+                Some(Self::SwapPair { pair_id: payload, is_public: true })
+            },
+            ANY_LOCAL_PREFIX if use_case_id == SWAP_USE_CASE_ID => {
+                Some(Self::SwapPair { pair_id: payload, is_public: false })
+            },
+            PUBLIC_LOCAL_PREFIX if use_case_id == STEALTH_USE_CASE_ID && payload == 0 => {
+                Some(Self::Stealth)
+            },
+            _ => None,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the tag representing this use case.
+    pub fn tag(&self) -> NoteTag {
+        match *self {
+            Self::Account {
+                account_id_prefix,
+                execution: NoteExecutionMode::Local,
+            } => NoteTag::from(ANY_LOCAL_PREFIX | ((account_id_prefix & 0x3fff) << 16)),
+            Self::Account {
+                account_id_prefix,
+                execution: NoteExecutionMode::Network,
+            } => NoteTag::from(account_id_prefix & !TAG_PREFIX_MASK),
+            Self::SwapPair { pair_id, is_public: true } => {
+                NoteTag::for_public_use_case(SWAP_USE_CASE_ID, pair_id, NoteExecutionMode::Local)
+                    .expect("swap use case ID should be valid")
+            },
+            Self::SwapPair { pair_id, is_public: false } => {
+                NoteTag::for_local_use_case(SWAP_USE_CASE_ID, pair_id)
+                    .expect("swap use case ID should be valid")
+            },
+            Self::Stealth => {
+                NoteTag::for_public_use_case(STEALTH_USE_CASE_ID, 0, NoteExecutionMode::Local)
+                    .expect("stealth use case ID should be valid")
+            },
+        }
+    }
+
+    /// Returns true if this use case is a note targeting the specified account.
+    ///
+    /// Since the tag only holds a prefix of the account ID, other accounts sharing the prefix are
+    /// matched as well.
+    pub fn targets_account(&self, account_id: AccountId) -> bool {
+        match *self {
+            Self::Account { execution, .. } => *self == Self::account(account_id, execution),
+            Self::SwapPair { .. } | Self::Stealth => false,
+        }
+    }
+}
+
+impl From<NoteTagUseCase> for NoteTag {
+    fn from(use_case: NoteTagUseCase) -> Self {
+        use_case.tag()
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the ID of the pair of the specified faucets.
+///
+/// The ID is taken from the hash of the faucet IDs sorted in ascending order, which spreads pairs
+/// evenly over the tag payload regardless of the metadata bits shared by most faucet IDs. The ID
+/// is in the range `1..=u16::MAX`, see [NoteTagUseCase::SwapPair].
+fn swap_pair_id(faucet_a: AccountId, faucet_b: AccountId) -> u16 {
+    let (low, high) = if faucet_a <= faucet_b {
+        (faucet_a, faucet_b)
+    } else {
+        (faucet_b, faucet_a)
+    };
+    let digest = Hasher::hash_elements(&[Felt::from(low), Felt::from(high)]);
+
+    (digest[0].as_int() % u16::MAX as u64) as u16 + 1
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::{
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+                ACCOUNT_ID_OFF_CHAIN_SENDER, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+            },
+            AccountId,
+        },
+        notes::{NoteExecutionMode, NoteTag, NoteType},
+        Felt,
+    };
+
+    use super::NoteTagUseCase;
+
+    #[test]
+    fn test_note_tag_use_cases() {
+        let on_chain_account =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let off_chain_account = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+
+        // account tags match the tags built from the account ID and decode back to the use case
+        for (account_id, execution) in [
+            (on_chain_account, NoteExecutionMode::Local),
+            (on_chain_account, NoteExecutionMode::Network),
+            (off_chain_account, NoteExecutionMode::Local),
+        ] {
+            let use_case = NoteTagUseCase::account(account_id, execution);
+            let tag = NoteTag::from_account_id(account_id, execution).unwrap();
+            assert_eq!(use_case.tag(), tag);
+            assert_eq!(NoteTagUseCase::from_tag(tag), Some(use_case));
+            assert!(use_case.targets_account(account_id));
+        }
+        assert!(!NoteTagUseCase::account(on_chain_account, NoteExecutionMode::Network)
+            .targets_account(off_chain_account));
+
+        // swap pair tags do not depend on the direction of the swap
+        let faucet_a = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let faucet_b = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+        for note_type in [NoteType::Public, NoteType::Private] {
+            let use_case = NoteTagUseCase::swap_pair(note_type, faucet_a, faucet_b);
+            assert_eq!(use_case, NoteTagUseCase::swap_pair(note_type, faucet_b, faucet_a));
+            assert_eq!(use_case.tag().validate(note_type), Ok(use_case.tag()));
+            assert_eq!(NoteTagUseCase::from_tag(use_case.tag()), Some(use_case));
+            assert!(!use_case.targets_account(faucet_a));
+        }
+
+        // private swap pair tags with pair ID 0 collide with local account tags, so swap pair IDs
+        // are never 0
+        let use_case = NoteTagUseCase::SwapPair { pair_id: 0, is_public: false };
+        assert_eq!(
+            NoteTagUseCase::from_tag(use_case.tag()),
+            Some(NoteTagUseCase::Account {
+                account_id_prefix: 0,
+                execution: NoteExecutionMode::Local
+            })
+        );
+        for faucet_id in 0..64u64 {
+            let faucet = AccountId::new_unchecked(Felt::new(faucet_id << 32));
+            let NoteTagUseCase::SwapPair { pair_id, .. } =
+                NoteTagUseCase::swap_pair(NoteType::Private, faucet, faucet_a)
+            else {
+                panic!("expected a swap pair use case");
+            };
+            assert_ne!(pair_id, 0);
+        }
+
+        // all stealth notes share a tag
+        let use_case = NoteTagUseCase::Stealth;
+        assert_eq!(use_case.tag().validate(NoteType::Public), Ok(use_case.tag()));
+        assert_eq!(NoteTagUseCase::from_tag(use_case.tag()), Some(use_case));
+
+        // tags of unknown use cases are not decoded
+        let tag = NoteTag::for_public_use_case(1, 1, NoteExecutionMode::Local).unwrap();
+        assert_eq!(NoteTagUseCase::from_tag(tag), None);
+        let tag = NoteTag::for_public_use_case(0, 1, NoteExecutionMode::Network).unwrap();
+        assert_eq!(NoteTagUseCase::from_tag(tag), None);
+    }
+}
@@ -0,0 +1,224 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use miden_objects::{
+    assembly::{Assembler, Compile, Library},
+    notes::NoteScript,
+    NoteError,
+};
+
+use crate::transaction::TransactionKernel;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Namespaces of the libraries which are always available to note scripts.
+const RESERVED_NAMESPACES: [&str; 2] = ["std", "miden"];
+
+// NOTE LIBRARY REGISTRY
+// ================================================================================================
+
+/// A registry of libraries publishing procedures which can be shared across note scripts.
+///
+/// Note scripts compiled via [NoteLibraryRegistry::compile_note_script()] can invoke the
+/// procedures of all registered libraries, e.g., `exec.my_notes::common::add_assets`, which keeps
+/// the code common to several note types in a single place. Since the procedures of a library are
+/// referenced by their MAST roots rather than copied into the scripts, the libraries must be
+/// loaded into the transaction executor consuming such notes, e.g., via
+/// `TransactionExecutor::load_note_libraries()`.
+///
+/// Each library namespace can be registered only once, and the `std` and `miden` namespaces are
+/// reserved for the Miden standard library and miden-lib respectively.
+#[derive(Debug, Clone, Default)]
+pub struct NoteLibraryRegistry {
+    libraries: Vec<Library>,
+    namespaces: BTreeMap<String, usize>,
+}
+
+impl NoteLibraryRegistry {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [NoteLibraryRegistry].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the library registered under the specified namespace, if any.
+    pub fn get(&self, namespace: &str) -> Option<&Library> {
+        self.namespaces.get(namespace).map(|&index| &self.libraries[index])
+    }
+
+    /// Returns an iterator over the registered libraries.
+    pub fn libraries(&self) -> impl Iterator<Item = &Library> {
+        self.libraries.iter()
+    }
+
+    /// Returns an iterator over the registered library namespaces.
+    pub fn namespaces(&self) -> impl Iterator<Item = &str> {
+        self.namespaces.keys().map(String::as_str)
+    }
+
+    /// Returns a new assembler for compiling note scripts, instantiated with the transaction
+    /// kernel and loaded with the Miden stdlib, miden-lib, and all registered libraries.
+    pub fn assembler(&self) -> Assembler {
+        self.libraries
+            .iter()
+            .fold(TransactionKernel::assembler(), |assembler, library| {
+                assembler
+                    .with_library(library)
+                    .expect("registered libraries should not conflict")
+            })
+    }
+
+    /// Returns a new [NoteScript] compiled from the provided source code, which can invoke the
+    /// procedures of all registered libraries.
+    ///
+    /// # Errors
+    /// Returns an error if the compilation of the provided source code fails.
+    pub fn compile_note_script(&self, source_code: impl Compile) -> Result<NoteScript, NoteError> {
+        NoteScript::compile_with_libraries(
+            source_code,
+            TransactionKernel::assembler(),
+            &self.libraries,
+        )
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Registers the provided library, making its procedures available to note scripts compiled
+    /// via this registry.
+    ///
+    /// # Errors
+    /// Returns an error if any namespace of the library is reserved or already registered.
+    pub fn register(&mut self, library: Library) -> Result<(), NoteError> {
+        let namespaces: BTreeSet<String> = library
+            .exports()
+            .map(|proc_name| proc_name.module.namespace().to_string())
+            .collect();
+
+        for namespace in namespaces.iter() {
+            if RESERVED_NAMESPACES.contains(&namespace.as_str()) {
+                return Err(NoteError::ReservedNoteLibraryNamespace(namespace.clone()));
+            }
+            if self.namespaces.contains_key(namespace) {
+                return Err(NoteError::DuplicateNoteLibraryNamespace(namespace.clone()));
+            }
+        }
+
+        let index = self.libraries.len();
+        self.libraries.push(library);
+        self.namespaces
+            .extend(namespaces.into_iter().map(|namespace| (namespace, index)));
+
+        Ok(())
+    }
+}
+
+#[cfg(any(feature = "testing", test))]
+impl NoteLibraryRegistry {
+    /// Returns a library with a single module at the specified path, compiled from the provided
+    /// source code with the transaction kernel assembler.
+    pub fn compile_library(path: &str, source_code: &str) -> Library {
+        use alloc::sync::Arc;
+
+        use miden_objects::assembly::{
+            ast::{Module, ModuleKind},
+            DefaultSourceManager, LibraryPath,
+        };
+
+        let source_manager = Arc::new(DefaultSourceManager::default());
+        let module = Module::parser(ModuleKind::Library)
+            .parse_str(
+                LibraryPath::new(path).expect("invalid library path"),
+                source_code,
+                &*source_manager,
+            )
+            .expect("failed to parse library module");
+
+        TransactionKernel::assembler()
+            .assemble_library([module])
+            .expect("failed to assemble library")
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::{sync::Arc, vec::Vec};
+
+    use miden_objects::{
+        assembly::{
+            ast::{Module, ModuleKind},
+            Assembler, DefaultSourceManager, LibraryPath,
+        },
+        notes::NoteScript,
+        NoteError,
+    };
+
+    use super::NoteLibraryRegistry;
+    use crate::transaction::TransactionKernel;
+
+    #[test]
+    fn test_register_note_libraries() {
+        let mut registry = NoteLibraryRegistry::new();
+        registry
+            .register(NoteLibraryRegistry::compile_library(
+                "notes::common",
+                "export.foo push.1 drop end",
+            ))
+            .unwrap();
+        assert!(registry.get("notes").is_some());
+
+        // the procedures of registered libraries can only be invoked via the registry
+        let source = "use.notes::common begin exec.common::foo end";
+        registry.compile_note_script(source).unwrap();
+        assert!(NoteScript::compile(source, TransactionKernel::assembler()).is_err());
+
+        // namespaces can be registered only once
+        assert_eq!(
+            registry.register(NoteLibraryRegistry::compile_library(
+                "notes::other",
+                "export.bar push.2 drop end"
+            )),
+            Err(NoteError::DuplicateNoteLibraryNamespace("notes".into()))
+        );
+        assert_eq!(
+            registry.register(NoteLibraryRegistry::compile_library(
+                "miden::notes",
+                "export.bar push.2 drop end"
+            )),
+            Err(NoteError::ReservedNoteLibraryNamespace("miden".into()))
+        );
+    }
+
+    #[test]
+    fn test_register_library_with_interleaved_namespaces() {
+        let source_manager = Arc::new(DefaultSourceManager::default());
+        let modules = ["a::x", "b::y", "a::z"].map(|path| {
+            Module::parser(ModuleKind::Library)
+                .parse_str(
+                    LibraryPath::new(path).unwrap(),
+                    "export.foo push.1 drop end",
+                    &*source_manager,
+                )
+                .unwrap()
+        });
+        let library = Assembler::new(source_manager).assemble_library(modules).unwrap();
+
+        // a library is registered under each of its namespaces once
+        let mut registry = NoteLibraryRegistry::new();
+        registry.register(library).unwrap();
+        assert_eq!(registry.namespaces().collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(registry.libraries().count(), 1);
+    }
+}
@@ -0,0 +1,107 @@
+use alloc::vec::Vec;
+
+use miden_objects::{
+    accounts::AccountId,
+    notes::{Note, NoteDecryptionKey, NoteHeader, NoteInputs, OneTimeRecipient, StealthAddress},
+    Felt, NoteError, Word,
+};
+
+use super::{NoteTagUseCase, StealthNoteInputs, WellKnownNote, WellKnownNoteInputs};
+
+// STEALTH NOTE SCANNER
+// ================================================================================================
+
+/// Detects the STEALTH notes sent to the [StealthAddress] of an account.
+///
+/// STEALTH notes are public notes which do not reveal the account they are sent to: their inputs
+/// contain a [OneTimeRecipient] commitment instead of the ID of the target account, and all of them
+/// share the same tag. The `aux` value of their metadata carries the view tag of the recipient,
+/// which lets the scanner discard almost all notes sent to other addresses with a single key
+/// exchange, before checking the full details of the remaining notes.
+#[derive(Debug, Clone)]
+pub struct StealthNoteScanner {
+    account_id: AccountId,
+    viewing_key: NoteDecryptionKey,
+}
+
+impl StealthNoteScanner {
+    /// Returns a new [StealthNoteScanner] for the specified account and viewing key.
+    pub fn new(account_id: AccountId, viewing_key: NoteDecryptionKey) -> Self {
+        Self { account_id, viewing_key }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the [StealthAddress] to which the notes detected by this scanner are sent.
+    pub fn address(&self) -> StealthAddress {
+        StealthAddress::new(self.account_id, self.viewing_key.encryption_key())
+    }
+
+    // SCANNING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if the note with the provided header and inputs may be a STEALTH note sent to
+    /// the address of this scanner.
+    ///
+    /// The view tag in the `aux` value of the note metadata is checked against the one recovered
+    /// from the ephemeral key of the note, which filters out all but about 1/256 of the notes sent
+    /// to other addresses. Only the notes passing this check need to be scanned.
+    pub fn is_candidate(&self, header: &NoteHeader, inputs: &NoteInputs) -> bool {
+        let metadata = header.metadata();
+        if NoteTagUseCase::from_tag(metadata.tag()) != Some(NoteTagUseCase::Stealth) {
+            return false;
+        }
+
+        let Ok(WellKnownNoteInputs::STEALTH(inputs)) = WellKnownNote::STEALTH.decode_inputs(inputs)
+        else {
+            return false;
+        };
+
+        OneTimeRecipient::recover_view_tag(&self.viewing_key, inputs.ephemeral_key)
+            .is_ok_and(|view_tag| metadata.aux() == Felt::from(view_tag))
+    }
+
+    /// Returns the [OneTimeRecipient] of the provided note if it is a STEALTH note sent to the
+    /// address of this scanner, or `None` otherwise.
+    ///
+    /// The note can be consumed by the account by providing the blinding factor of the returned
+    /// recipient as the note args.
+    pub fn scan(&self, note: &Note) -> Option<OneTimeRecipient> {
+        if note.script().hash() != WellKnownNote::STEALTH.script_root()
+            || !self.is_candidate(note.header(), note.inputs())
+        {
+            return None;
+        }
+
+        let Ok(WellKnownNoteInputs::STEALTH(StealthNoteInputs {
+            recipient_commitment,
+            ephemeral_key,
+        })) = WellKnownNote::STEALTH.decode_inputs(note.inputs())
+        else {
+            return None;
+        };
+        let recipient =
+            OneTimeRecipient::recover(self.account_id, &self.viewing_key, ephemeral_key).ok()?;
+
+        (recipient.commitment() == recipient_commitment).then_some(recipient)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the inputs of a STEALTH note sent to the provided one-time recipient.
+pub(super) fn build_stealth_note_inputs(
+    recipient: &OneTimeRecipient,
+) -> Result<NoteInputs, NoteError> {
+    let mut values: Vec<Felt> = Word::from(recipient.commitment()).to_vec();
+    values.extend(
+        recipient
+            .ephemeral_key()
+            .chunks(4)
+            .map(|limb| Felt::from(u32::from_le_bytes(limb.try_into().expect("limb has 4 bytes")))),
+    );
+
+    NoteInputs::new(values)
+}
@@ -0,0 +1,480 @@
+use alloc::string::ToString;
+
+use miden_objects::{
+    accounts::{Account, AccountId},
+    assets::Asset,
+    notes::{Note, NoteExecutionHint, NoteInputs, NoteScript, NoteTag},
+    utils::Deserializable,
+    vm::Program,
+    Digest, Felt, NoteError, Word,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The roots of the note scripts of miden-lib, computed when the scripts are compiled.
+mod note_script_roots {
+    use miden_objects::{Digest, Felt};
+
+    include!(concat!(env!("OUT_DIR"), "/assets/note_scripts/note_script_roots.rs"));
+}
+
+// WELL KNOWN NOTE
+// ================================================================================================
+
+/// The note scripts provided by miden-lib.
+///
+/// A [WellKnownNote] can be recognized from the root of a note's script, after which the inputs
+/// of the note can be decoded into the typed [WellKnownNoteInputs], and the consumability of the
+/// note by a given account can be checked without executing the note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WellKnownNote {
+    P2ID,
+    P2IDR,
+    SWAP,
+    RECOVERY,
+    STEALTH,
+}
+
+impl WellKnownNote {
+    /// All well-known notes.
+    pub const ALL: [WellKnownNote; 5] =
+        [Self::P2ID, Self::P2IDR, Self::SWAP, Self::RECOVERY, Self::STEALTH];
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the [WellKnownNote] with the specified script root, or `None` if the script root
+    /// does not belong to a well-known note.
+    pub fn from_script_root(script_root: Digest) -> Option<Self> {
+        Self::ALL.into_iter().find(|note| note.script_root() == script_root)
+    }
+
+    /// Returns the [WellKnownNote] the script of the provided note corresponds to, or `None` if
+    /// the note is not a well-known note.
+    pub fn from_note(note: &Note) -> Option<Self> {
+        Self::from_script_root(note.script().hash())
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the script of this note.
+    pub fn script(&self) -> NoteScript {
+        let bytes: &[u8] = match self {
+            Self::P2ID => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID.masb"))
+            },
+            Self::P2IDR => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2IDR.masb"))
+            },
+            Self::SWAP => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SWAP.masb"))
+            },
+            Self::RECOVERY => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/RECOVERY.masb"))
+            },
+            Self::STEALTH => {
+                include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/STEALTH.masb"))
+            },
+        };
+        let program = Program::read_from_bytes(bytes).expect("failed to read note script!");
+        NoteScript::new(program)
+    }
+
+    /// Returns the root of the script of this note.
+    ///
+    /// The root is computed when miden-lib is built, so this does not deserialize the script.
+    pub fn script_root(&self) -> Digest {
+        match self {
+            Self::P2ID => note_script_roots::P2ID,
+            Self::P2IDR => note_script_roots::P2IDR,
+            Self::SWAP => note_script_roots::SWAP,
+            Self::RECOVERY => note_script_roots::RECOVERY,
+            Self::STEALTH => note_script_roots::STEALTH,
+        }
+    }
+
+    /// Returns the number of inputs expected by the script of this note.
+    pub fn num_expected_inputs(&self) -> usize {
+        match self {
+            Self::P2ID => 1,
+            Self::P2IDR => 2,
+            Self::SWAP => 10,
+            Self::RECOVERY => 5,
+            Self::STEALTH => 12,
+        }
+    }
+
+    // INPUTS DECODING
+    // --------------------------------------------------------------------------------------------
+
+    /// Decodes the provided note inputs into the typed inputs of this note.
+    ///
+    /// # Errors
+    /// Returns an error if the number of inputs does not match the number expected by the script
+    /// of this note, or if any of the inputs is invalid.
+    pub fn decode_inputs(&self, inputs: &NoteInputs) -> Result<WellKnownNoteInputs, NoteError> {
+        let values = inputs.values();
+        if values.len() != self.num_expected_inputs() {
+            return Err(NoteError::InvalidWellKnownNoteInputs(format!(
+                "{self:?} note expects {} inputs, but {} were provided",
+                self.num_expected_inputs(),
+                values.len()
+            )));
+        }
+
+        match self {
+            Self::P2ID => Ok(WellKnownNoteInputs::P2ID(P2idNoteInputs {
+                target: decode_account_id(values[0])?,
+            })),
+            Self::P2IDR => Ok(WellKnownNoteInputs::P2IDR(P2idrNoteInputs {
+                target: decode_account_id(values[0])?,
+                recall_height: decode_u32(values[1])?,
+            })),
+            Self::SWAP => {
+                let payback_recipient = Digest::from([values[0], values[1], values[2], values[3]]);
+                let requested_asset: Word = [values[4], values[5], values[6], values[7]];
+                let requested_asset = Asset::try_from(requested_asset)
+                    .map_err(|err| NoteError::InvalidWellKnownNoteInputs(err.to_string()))?;
+                let payback_tag = NoteTag::from(decode_u32(values[8])?);
+                let payback_execution_hint = NoteExecutionHint::try_from(values[9].as_int())?;
+
+                Ok(WellKnownNoteInputs::SWAP(SwapNoteInputs {
+                    payback_recipient,
+                    requested_asset,
+                    payback_tag,
+                    payback_execution_hint,
+                }))
+            },
+            Self::RECOVERY => Ok(WellKnownNoteInputs::RECOVERY(RecoveryNoteInputs {
+                new_pub_key: [values[0], values[1], values[2], values[3]],
+                target: decode_account_id(values[4])?,
+            })),
+            Self::STEALTH => {
+                let mut ephemeral_key = [0; 32];
+                for (limb, &value) in ephemeral_key.chunks_mut(4).zip(&values[4..]) {
+                    limb.copy_from_slice(&decode_u32(value)?.to_le_bytes());
+                }
+
+                Ok(WellKnownNoteInputs::STEALTH(StealthNoteInputs {
+                    recipient_commitment: Digest::from([
+                        values[0], values[1], values[2], values[3],
+                    ]),
+                    ephemeral_key,
+                }))
+            },
+        }
+    }
+
+    // CONSUMABILITY
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns whether the provided account can consume the provided note of this type at the
+    /// specified block.
+    ///
+    /// This only checks the conditions which can be checked without executing the note script. In
+    /// addition, the account must expose the basic wallet interface to receive (and, for SWAP
+    /// notes, send) the assets of the note, and the sender of a RECOVERY note must be a guardian
+    /// of the account.
+    ///
+    /// Whether a STEALTH note was sent to the account cannot be determined without the viewing key
+    /// of the account, so [NoteConsumability::Unknown] is returned for such notes; see
+    /// `StealthNoteScanner` instead.
+    ///
+    /// # Errors
+    /// Returns an error if the inputs of the note cannot be decoded into the typed inputs of this
+    /// note.
+    pub fn check_consumability(
+        &self,
+        note: &Note,
+        account: &Account,
+        block_num: u32,
+    ) -> Result<NoteConsumability, NoteError> {
+        let consumability = match self.decode_inputs(note.inputs())? {
+            WellKnownNoteInputs::P2ID(inputs) => {
+                if inputs.target == account.id() {
+                    NoteConsumability::Consumable
+                } else {
+                    NoteConsumability::NotConsumable
+                }
+            },
+            WellKnownNoteInputs::P2IDR(inputs) => {
+                if inputs.target == account.id() {
+                    NoteConsumability::Consumable
+                } else if note.metadata().sender() != account.id() {
+                    NoteConsumability::NotConsumable
+                } else if block_num < inputs.recall_height {
+                    NoteConsumability::ConsumableAfter(inputs.recall_height)
+                } else {
+                    NoteConsumability::Consumable
+                }
+            },
+            WellKnownNoteInputs::SWAP(inputs) => {
+                let has_requested_asset = match inputs.requested_asset {
+                    Asset::Fungible(asset) => account
+                        .vault()
+                        .get_balance(asset.faucet_id())
+                        .is_ok_and(|balance| balance >= asset.amount()),
+                    asset @ Asset::NonFungible(_) => {
+                        account.vault().has_non_fungible_asset(asset).unwrap_or(false)
+                    },
+                };
+
+                if has_requested_asset {
+                    NoteConsumability::Consumable
+                } else {
+                    NoteConsumability::NotConsumable
+                }
+            },
+            WellKnownNoteInputs::RECOVERY(inputs) => {
+                if inputs.target == account.id() {
+                    NoteConsumability::Consumable
+                } else {
+                    NoteConsumability::NotConsumable
+                }
+            },
+            WellKnownNoteInputs::STEALTH(_) => NoteConsumability::Unknown,
+        };
+
+        Ok(consumability)
+    }
+}
+
+// WELL KNOWN NOTE INPUTS
+// ================================================================================================
+
+/// The typed inputs of a [WellKnownNote].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WellKnownNoteInputs {
+    P2ID(P2idNoteInputs),
+    P2IDR(P2idrNoteInputs),
+    SWAP(SwapNoteInputs),
+    RECOVERY(RecoveryNoteInputs),
+    STEALTH(StealthNoteInputs),
+}
+
+/// The inputs of a P2ID note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct P2idNoteInputs {
+    /// ID of the account which can consume the note.
+    pub target: AccountId,
+}
+
+/// The inputs of a P2IDR note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct P2idrNoteInputs {
+    /// ID of the account which can consume the note.
+    pub target: AccountId,
+    /// Block number from which the sender of the note can reclaim it.
+    pub recall_height: u32,
+}
+
+/// The inputs of a SWAP note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapNoteInputs {
+    /// Recipient of the payback note created by the consumer of the note.
+    pub payback_recipient: Digest,
+    /// Asset the consumer of the note must send to the sender via the payback note.
+    pub requested_asset: Asset,
+    /// Tag of the payback note.
+    pub payback_tag: NoteTag,
+    /// Execution hint of the payback note.
+    pub payback_execution_hint: NoteExecutionHint,
+}
+
+/// The inputs of a RECOVERY note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecoveryNoteInputs {
+    /// Public key whose recovery is approved by the sender of the note.
+    pub new_pub_key: Word,
+    /// ID of the account which can consume the note.
+    pub target: AccountId,
+}
+
+/// The inputs of a STEALTH note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StealthNoteInputs {
+    /// Commitment to the one-time recipient of the note.
+    pub recipient_commitment: Digest,
+    /// Ephemeral key from which the recipient derives the blinding factor of the commitment.
+    pub ephemeral_key: [u8; 32],
+}
+
+// NOTE CONSUMABILITY
+// ================================================================================================
+
+/// Describes whether an account can consume a [WellKnownNote].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteConsumability {
+    /// The note can be consumed by the account.
+    Consumable,
+    /// The note can be consumed by the account from the specified block on.
+    ConsumableAfter(u32),
+    /// The note cannot be consumed by the account.
+    NotConsumable,
+    /// Whether the note can be consumed by the account cannot be determined from the note alone.
+    Unknown,
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn decode_account_id(value: Felt) -> Result<AccountId, NoteError> {
+    AccountId::try_from(value).map_err(|err| NoteError::InvalidWellKnownNoteInputs(err.to_string()))
+}
+
+fn decode_u32(value: Felt) -> Result<u32, NoteError> {
+    u32::try_from(value.as_int()).map_err(|_| {
+        NoteError::InvalidWellKnownNoteInputs(format!("input {value} is not a u32 value"))
+    })
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::{
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_3,
+                ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+            },
+            Account, AccountId,
+        },
+        assets::{Asset, FungibleAsset},
+        crypto::rand::RpoRandomCoin,
+        notes::NoteType,
+        Digest, ONE, ZERO,
+    };
+
+    use super::{NoteConsumability, WellKnownNote, WellKnownNoteInputs};
+    use crate::{
+        notes::{create_p2id_note, create_p2idr_note, create_swap_note},
+        transaction::TransactionKernel,
+    };
+
+    #[test]
+    fn test_well_known_note_script_roots() {
+        for note in WellKnownNote::ALL {
+            assert_eq!(note.script_root(), note.script().hash());
+            assert_eq!(WellKnownNote::from_script_root(note.script_root()), Some(note));
+        }
+        assert_eq!(WellKnownNote::from_script_root(Digest::default()), None);
+    }
+
+    #[test]
+    fn test_well_known_notes() {
+        let mut rng = RpoRandomCoin::new([ONE, ZERO, ONE, ZERO]);
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let account = Account::mock(
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+            ONE,
+            TransactionKernel::assembler(),
+        );
+        let faucet = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset: Asset = FungibleAsset::new(faucet, 10).unwrap().into();
+
+        let p2id =
+            create_p2id_note(sender, account.id(), vec![asset], NoteType::Public, ZERO, &mut rng)
+                .unwrap();
+        assert_eq!(WellKnownNote::from_note(&p2id), Some(WellKnownNote::P2ID));
+        assert_eq!(
+            WellKnownNote::P2ID.check_consumability(&p2id, &account, 0),
+            Ok(NoteConsumability::Consumable)
+        );
+
+        // a P2IDR note can be reclaimed by its sender once the recall height is reached
+        let p2idr = create_p2idr_note(
+            account.id(),
+            sender,
+            vec![asset],
+            NoteType::Public,
+            ZERO,
+            5,
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(WellKnownNote::from_note(&p2idr), Some(WellKnownNote::P2IDR));
+        let WellKnownNoteInputs::P2IDR(inputs) =
+            WellKnownNote::P2IDR.decode_inputs(p2idr.inputs()).unwrap()
+        else {
+            panic!("expected P2IDR inputs");
+        };
+        assert_eq!((inputs.target, inputs.recall_height), (sender, 5));
+        assert_eq!(
+            WellKnownNote::P2IDR.check_consumability(&p2idr, &account, 3),
+            Ok(NoteConsumability::ConsumableAfter(5))
+        );
+        assert_eq!(
+            WellKnownNote::P2IDR.check_consumability(&p2idr, &account, 5),
+            Ok(NoteConsumability::Consumable)
+        );
+
+        // a SWAP note can only be consumed by accounts holding the requested asset
+        let other_faucet = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_3).unwrap();
+        let (swap, _) =
+            create_swap_note(sender, asset, asset, NoteType::Public, ZERO, &mut rng).unwrap();
+        assert_eq!(WellKnownNote::from_note(&swap), Some(WellKnownNote::SWAP));
+        let WellKnownNoteInputs::SWAP(inputs) =
+            WellKnownNote::SWAP.decode_inputs(swap.inputs()).unwrap()
+        else {
+            panic!("expected SWAP inputs");
+        };
+        assert_eq!(inputs.requested_asset, asset);
+        assert_eq!(
+            WellKnownNote::SWAP.check_consumability(&swap, &account, 0),
+            Ok(NoteConsumability::Consumable)
+        );
+        let unavailable_asset: Asset = FungibleAsset::new(other_faucet, 10).unwrap().into();
+        let (swap, _) =
+            create_swap_note(sender, asset, unavailable_asset, NoteType::Public, ZERO, &mut rng)
+                .unwrap();
+        assert_eq!(
+            WellKnownNote::SWAP.check_consumability(&swap, &account, 0),
+            Ok(NoteConsumability::NotConsumable)
+        );
+
+        // inputs of a different note type cannot be decoded
+        assert!(WellKnownNote::SWAP.decode_inputs(p2id.inputs()).is_err());
+        assert_eq!(WellKnownNote::from_script_root(Digest::from([ONE; 4])), None);
+    }
+
+    #[cfg(feature = "note-encryption")]
+    #[test]
+    fn test_well_known_stealth_note() {
+        use miden_objects::notes::{NoteDecryptionKey, StealthAddress};
+
+        use crate::notes::{create_stealth_note, StealthNoteScanner};
+
+        let mut rng = RpoRandomCoin::new([ONE, ZERO, ONE, ZERO]);
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let account = Account::mock(
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+            ONE,
+            TransactionKernel::assembler(),
+        );
+        let faucet = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset: Asset = FungibleAsset::new(faucet, 10).unwrap().into();
+
+        let viewing_key = NoteDecryptionKey::from([1; 32]);
+        let address = StealthAddress::new(account.id(), viewing_key.encryption_key());
+        let stealth = create_stealth_note(sender, &address, vec![asset], &mut rng).unwrap();
+        assert_eq!(WellKnownNote::from_note(&stealth), Some(WellKnownNote::STEALTH));
+        let WellKnownNoteInputs::STEALTH(inputs) =
+            WellKnownNote::STEALTH.decode_inputs(stealth.inputs()).unwrap()
+        else {
+            panic!("expected STEALTH inputs");
+        };
+        let recipient = StealthNoteScanner::new(account.id(), viewing_key).scan(&stealth).unwrap();
+        assert_eq!(inputs.recipient_commitment, recipient.commitment());
+        assert_eq!(inputs.ephemeral_key, recipient.ephemeral_key());
+
+        // whether a STEALTH note was sent to an account depends on the viewing key of the account
+        assert_eq!(
+            WellKnownNote::STEALTH.check_consumability(&stealth, &account, 0),
+            Ok(NoteConsumability::Unknown)
+        );
+    }
+}
@@ -2,9 +2,12 @@ use alloc::vec::Vec;
 
 use miden_objects::{
     accounts::Account,
-    transaction::{ChainMmr, InputNote, TransactionArgs, TransactionInputs, TransactionScript},
+    transaction::{
+        ChainMmr, ForeignAccountInputs, InputNote, TransactionArgs, TransactionInputs,
+        TransactionScript,
+    },
     vm::AdviceInputs,
-    Felt, FieldElement, Word, EMPTY_WORD, ZERO,
+    Felt, FieldElement, TransactionInputError, Word, EMPTY_WORD, ZERO,
 };
 
 // ADVICE INPUTS
@@ -13,21 +16,28 @@ use miden_objects::{
 /// Extends the provided advice inputs with the data required for executing a transaction with the
 /// specified inputs.
 ///
-/// This includes the initial account, an optional account seed (required for new accounts), and
-/// the input note data, including core note data + authentication paths all the way to the root
-/// of one of chain MMR peaks.
+/// This includes the initial account, an optional account seed (required for new accounts), the
+/// foreign accounts data, including authentication paths to the account root of the reference
+/// block, and the input note data, including core note data + authentication paths all the way to
+/// the root of one of chain MMR peaks.
+///
+/// # Errors
+/// Returns an error if the authentication path of any of the foreign accounts is invalid.
 pub(super) fn extend_advice_inputs(
     tx_inputs: &TransactionInputs,
     tx_args: &TransactionArgs,
     advice_inputs: &mut AdviceInputs,
-) {
+) -> Result<(), TransactionInputError> {
     build_advice_stack(tx_inputs, tx_args.tx_script(), advice_inputs);
 
     // build the advice map and Merkle store for relevant components
     add_chain_mmr_to_advice_inputs(tx_inputs.block_chain(), advice_inputs);
     add_account_to_advice_inputs(tx_inputs.account(), tx_inputs.account_seed(), advice_inputs);
+    add_foreign_accounts_to_advice_inputs(tx_inputs.foreign_accounts(), advice_inputs)?;
     add_input_notes_to_advice_inputs(tx_inputs, tx_args, advice_inputs);
     advice_inputs.extend(tx_args.advice_inputs().clone());
+
+    Ok(())
 }
 
 // ADVICE STACK BUILDER
@@ -51,6 +61,9 @@ pub(super) fn extend_advice_inputs(
 ///     ACCOUNT_VAULT_ROOT,
 ///     ACCOUNT_STORAGE_ROOT,
 ///     ACCOUNT_CODE_COMMITMENT,
+///     number_of_foreign_accounts,
+///     [[foreign_account_id, 0, 0, foreign_account_nonce], FOREIGN_ACCOUNT_VAULT_ROOT,
+///      FOREIGN_ACCOUNT_STORAGE_ROOT, FOREIGN_ACCOUNT_CODE_COMMITMENT], ...
 ///     number_of_input_notes,
 ///     TX_SCRIPT_ROOT,
 /// ]
@@ -85,6 +98,17 @@ fn build_advice_stack(
     inputs.extend_stack(account.storage().root());
     inputs.extend_stack(account.code().commitment());
 
+    // push core foreign account items onto the stack
+    // Note: keep in sync with the process_foreign_accounts_data kernel procedure
+    inputs.extend_stack([Felt::from(tx_inputs.foreign_accounts().len() as u32)]);
+    for foreign_account in tx_inputs.foreign_accounts() {
+        let account = foreign_account.account();
+        inputs.extend_stack([account.id().into(), ZERO, ZERO, account.nonce()]);
+        inputs.extend_stack(account.vault().commitment());
+        inputs.extend_stack(account.storage().root());
+        inputs.extend_stack(account.code().commitment());
+    }
+
     // push the number of input notes onto the stack
     inputs.extend_stack([Felt::from(tx_inputs.input_notes().num_notes() as u32)]);
 
@@ -192,6 +216,38 @@ fn add_account_to_advice_inputs(
     }
 }
 
+// FOREIGN ACCOUNTS INJECTOR
+// ------------------------------------------------------------------------------------------------
+
+/// Inserts the foreign accounts data into the provided advice inputs.
+///
+/// For each foreign account, inserts the core account data as described in
+/// [add_account_to_advice_inputs], and the Merkle nodes of the authentication path of the account
+/// state in the account tree of the reference block into the Merkle store.
+///
+/// # Errors
+/// Returns an error if the authentication path of any of the foreign accounts is invalid for the
+/// account ID.
+fn add_foreign_accounts_to_advice_inputs(
+    foreign_accounts: &[ForeignAccountInputs],
+    inputs: &mut AdviceInputs,
+) -> Result<(), TransactionInputError> {
+    for foreign_account in foreign_accounts {
+        let account = foreign_account.account();
+        add_account_to_advice_inputs(account, None, inputs);
+
+        // NOTE: keep in sync with the `prologue::process_foreign_accounts_data` kernel procedure
+        let account_id: Felt = account.id().into();
+        let inner_nodes = foreign_account
+            .account_path()
+            .inner_nodes(account_id.as_int(), account.hash())
+            .map_err(|_| TransactionInputError::InvalidForeignAccountPath(account.id()))?;
+        inputs.extend_merkle_store(inner_nodes);
+    }
+
+    Ok(())
+}
+
 // INPUT NOTE INJECTOR
 // ------------------------------------------------------------------------------------------------
 
@@ -18,6 +18,8 @@ pub type StorageSlot = u8;
 // | Chain MMR         | 300           | 332?         |
 // | Account data      | 400           | 651?         |
 // | Account procedures| 999           | ?            |
+// | Native acct backup| 2_400         | 3_511        |
+// | Foreign accounts  | 4_000         | 4_259        |
 // | Input notes       | 1_048_576     | ?            |
 // | Output notes      | 4_194_304     | ?            |
 
@@ -52,6 +54,10 @@ pub const INPUT_VAULT_ROOT_PTR: MemoryAddress = 3;
 /// The memory address at which the output vault root is stored
 pub const OUTPUT_VAULT_ROOT_PTR: MemoryAddress = 4;
 
+/// The memory address at which the flag indicating that a foreign account is loaded into the
+/// account data section is stored.
+pub const FOREIGN_CONTEXT_FLAG_PTR: MemoryAddress = 5;
+
 // GLOBAL INPUTS
 // ------------------------------------------------------------------------------------------------
 
@@ -192,6 +198,26 @@ pub const NUM_ACCT_PROCEDURES_PTR: MemoryAddress = 999;
 /// The memory address at which the account procedures section begins.
 pub const ACCT_PROCEDURES_SECTION_OFFSET: MemoryAddress = 1000;
 
+// NATIVE ACCOUNT DATA BACKUP
+// ------------------------------------------------------------------------------------------------
+
+/// The memory address at which a copy of the native account data and storage slot type data is
+/// stored while a foreign account is loaded into the account data section.
+pub const NATIVE_ACCT_DATA_BACKUP_OFFSET: MemoryOffset = 2400;
+
+/// The memory address at which a copy of the native account procedures, prefixed by their number,
+/// is stored while a foreign account is loaded into the account data section.
+pub const NATIVE_ACCT_PROCEDURES_BACKUP_OFFSET: MemoryOffset = 2999;
+
+// FOREIGN ACCOUNTS DATA
+// ------------------------------------------------------------------------------------------------
+
+/// The memory address at which the number of foreign accounts is stored.
+pub const NUM_FOREIGN_ACCTS_PTR: MemoryAddress = 4000;
+
+/// The memory address at which the foreign account data section begins.
+pub const FOREIGN_ACCT_DATA_SECTION_OFFSET: MemoryOffset = 4004;
+
 // NOTES DATA
 // ================================================================================================
 
@@ -8,7 +8,7 @@ use miden_objects::{
     },
     utils::{group_slice_elements, serde::Deserializable},
     vm::{AdviceInputs, AdviceMap, Program, ProgramInfo, StackInputs, StackOutputs},
-    Digest, Felt, TransactionOutputError, Word, EMPTY_WORD,
+    Digest, Felt, TransactionInputError, TransactionOutputError, Word, EMPTY_WORD,
 };
 use miden_stdlib::StdLibrary;
 
@@ -21,6 +21,9 @@ pub use events::{TransactionEvent, TransactionTrace};
 
 mod inputs;
 
+mod script_cache;
+pub use script_cache::ScriptCache;
+
 mod outputs;
 pub use outputs::{
     parse_final_account_stub, FINAL_ACCOUNT_HASH_WORD_IDX, OUTPUT_NOTES_COMMITMENT_WORD_IDX,
@@ -84,11 +87,15 @@ impl TransactionKernel {
     /// inputs needed to execute a transaction kernel for a specific transaction.
     ///
     /// If `init_advice_inputs` is provided, they will be included in the returned advice inputs.
+    ///
+    /// # Errors
+    /// Returns an error if the authentication path of any of the foreign accounts in the provided
+    /// [TransactionInputs] is invalid.
     pub fn prepare_inputs(
         tx_inputs: &TransactionInputs,
         tx_args: &TransactionArgs,
         init_advice_inputs: Option<AdviceInputs>,
-    ) -> (StackInputs, AdviceInputs) {
+    ) -> Result<(StackInputs, AdviceInputs), TransactionInputError> {
         let account = tx_inputs.account();
         let stack_inputs = TransactionKernel::build_input_stack(
             account.id(),
@@ -98,9 +105,9 @@ impl TransactionKernel {
         );
 
         let mut advice_inputs = init_advice_inputs.unwrap_or_default();
-        inputs::extend_advice_inputs(tx_inputs, tx_args, &mut advice_inputs);
+        inputs::extend_advice_inputs(tx_inputs, tx_args, &mut advice_inputs)?;
 
-        (stack_inputs, advice_inputs)
+        Ok((stack_inputs, advice_inputs))
     }
 
     // ASSEMBLER CONSTRUCTOR
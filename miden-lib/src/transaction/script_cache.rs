@@ -0,0 +1,443 @@
+use alloc::{
+    collections::{BTreeMap, VecDeque},
+    string::{String, ToString},
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::{fs, path::PathBuf};
+
+#[cfg(feature = "std")]
+use miden_objects::utils::{write_atomic, ByteReader, Deserializable, Serializable, SliceReader};
+use miden_objects::{
+    assembly::Library, notes::NoteScript, transaction::TransactionScript, utils::sync::RwLock,
+    vm::Program, Digest, Felt, Hasher, NoteError, TransactionScriptError, Word,
+};
+use miden_stdlib::StdLibrary;
+
+use super::TransactionKernel;
+use crate::{notes::NoteLibraryRegistry, MidenLib};
+
+// SCRIPT CACHE
+// ================================================================================================
+
+/// A cache of note and transaction scripts compiled by the transaction kernel assembler.
+///
+/// Compiled programs are keyed by the hash of their source code and the digest of the assembler
+/// they were compiled with, which commits to the version of miden-lib, the transaction kernel, and
+/// all libraries loaded into the assembler. Thus, a cached program is never returned for a
+/// different assembler configuration, and upgrading miden-lib or any of the libraries invalidates
+/// all programs compiled before the upgrade.
+///
+/// Programs are cached in memory, up to [ScriptCache::DEFAULT_CAPACITY] programs unless set
+/// otherwise via [ScriptCache::with_capacity()], the least recently inserted programs being
+/// evicted first. If a store directory is set via [ScriptCache::with_store_dir()], programs are
+/// also serialized into that directory so that they can be reused across processes. The store
+/// directory is used on a best-effort basis: programs which cannot be read from it, or whose
+/// source hash and assembler digest recorded in the file do not match the requested ones, are
+/// compiled again, and failures to write to it are ignored.
+///
+/// The cache can be shared between threads: all methods take `&self`.
+pub struct ScriptCache {
+    registry: NoteLibraryRegistry,
+    assembler_digest: Digest,
+    capacity: usize,
+    programs: RwLock<ProgramCache>,
+    #[cfg(feature = "std")]
+    store_dir: Option<PathBuf>,
+}
+
+impl ScriptCache {
+    /// The default maximum number of programs cached in memory.
+    pub const DEFAULT_CAPACITY: usize = 256;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [ScriptCache] compiling scripts with [TransactionKernel::assembler()].
+    pub fn new() -> Self {
+        Self::from_registry(&NoteLibraryRegistry::new())
+    }
+
+    /// Returns a new empty [ScriptCache] compiling scripts with the assembler of the provided
+    /// registry, i.e., scripts can invoke the procedures of all libraries in the registry.
+    pub fn from_registry(registry: &NoteLibraryRegistry) -> Self {
+        let libraries: Vec<&Library> = registry.libraries().collect();
+        Self {
+            registry: registry.clone(),
+            assembler_digest: compute_assembler_digest(&libraries),
+            capacity: Self::DEFAULT_CAPACITY,
+            programs: RwLock::new(ProgramCache::default()),
+            #[cfg(feature = "std")]
+            store_dir: None,
+        }
+    }
+
+    /// Sets the maximum number of programs cached in memory.
+    ///
+    /// Programs in the store directory are not limited by the capacity.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self.programs.get_mut().evict(capacity);
+        self
+    }
+
+    /// Sets the directory into which compiled programs are serialized, and from which programs
+    /// missing from memory are loaded.
+    ///
+    /// The directory is created when the first program is stored, and can be shared by caches
+    /// with different assembler configurations.
+    #[cfg(feature = "std")]
+    pub fn with_store_dir(mut self, store_dir: impl Into<PathBuf>) -> Self {
+        self.store_dir = Some(store_dir.into());
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the digest of the assembler configuration this cache compiles scripts with.
+    pub fn assembler_digest(&self) -> Digest {
+        self.assembler_digest
+    }
+
+    /// Returns the maximum number of programs cached in memory.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of programs cached in memory.
+    pub fn len(&self) -> usize {
+        self.programs.read().programs.len()
+    }
+
+    /// Returns true if no programs are cached in memory.
+    pub fn is_empty(&self) -> bool {
+        self.programs.read().programs.is_empty()
+    }
+
+    // COMPILATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the [NoteScript] compiled from the provided source code, compiling it only if it
+    /// is not cached yet.
+    ///
+    /// # Errors
+    /// Returns an error if the compilation of the provided source code fails.
+    pub fn compile_note_script(&self, source_code: &str) -> Result<NoteScript, NoteError> {
+        let program =
+            self.get_or_compile(source_code).map_err(NoteError::NoteScriptAssemblyError)?;
+
+        Ok(NoteScript::new(program))
+    }
+
+    /// Returns the [TransactionScript] compiled from the provided source code with the specified
+    /// inputs, compiling the source code only if it is not cached yet.
+    ///
+    /// # Errors
+    /// Returns an error if the compilation of the provided source code fails.
+    pub fn compile_tx_script(
+        &self,
+        source_code: &str,
+        inputs: impl IntoIterator<Item = (Word, Vec<Felt>)>,
+    ) -> Result<TransactionScript, TransactionScriptError> {
+        let program = self
+            .get_or_compile(source_code)
+            .map_err(TransactionScriptError::AssemblyError)?;
+
+        Ok(TransactionScript::new(program, inputs))
+    }
+
+    /// Removes all programs cached in memory.
+    ///
+    /// Programs serialized into the store directory are not removed.
+    pub fn clear(&self) {
+        *self.programs.write() = ProgramCache::default();
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the program compiled from the provided source code, looking it up in memory and in
+    /// the store directory before compiling it.
+    ///
+    /// The lock on the programs in memory is not held while compiling, so the same source code may
+    /// be compiled concurrently by several threads; all of them produce the same program.
+    ///
+    /// # Errors
+    /// Returns the rendered assembly report if the compilation of the source code fails.
+    fn get_or_compile(&self, source_code: &str) -> Result<Program, String> {
+        let source_hash = Hasher::hash(source_code.as_bytes());
+        let key = Hasher::merge(&[self.assembler_digest, source_hash]);
+        if let Some(program) = self.programs.read().programs.get(&key) {
+            return Ok(program.clone());
+        }
+
+        let program = match self.load(key, source_hash) {
+            Some(program) => program,
+            None => {
+                let program = self
+                    .registry
+                    .assembler()
+                    .assemble_program(source_code)
+                    .map_err(|report| report.to_string())?;
+                self.store(key, source_hash, &program);
+                program
+            },
+        };
+        self.programs.write().insert(key, program.clone(), self.capacity);
+
+        Ok(program)
+    }
+
+    /// Returns the program stored under the specified key in the store directory, if any.
+    ///
+    /// The program is returned only if the source hash and the assembler digest recorded in the
+    /// file match the provided source hash and the digest of the assembler of this cache.
+    #[cfg(feature = "std")]
+    fn load(&self, key: Digest, source_hash: Digest) -> Option<Program> {
+        let path = self.store_dir.as_ref()?.join(program_file_name(key));
+        let bytes = fs::read(path).ok()?;
+
+        let mut source = SliceReader::new(&bytes);
+        let stored_source_hash = Digest::read_from(&mut source).ok()?;
+        let stored_assembler_digest = Digest::read_from(&mut source).ok()?;
+        if stored_source_hash != source_hash || stored_assembler_digest != self.assembler_digest {
+            return None;
+        }
+
+        let program = Program::read_from(&mut source).ok()?;
+        if source.has_more_bytes() {
+            return None;
+        }
+
+        Some(program)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn load(&self, _key: Digest, _source_hash: Digest) -> Option<Program> {
+        None
+    }
+
+    /// Serializes the provided program into the store directory under the specified key, preceded
+    /// by the hash of its source code and the digest of the assembler of this cache.
+    #[cfg(feature = "std")]
+    fn store(&self, key: Digest, source_hash: Digest, program: &Program) {
+        if let Some(store_dir) = self.store_dir.as_ref() {
+            let mut bytes = source_hash.to_bytes();
+            self.assembler_digest.write_into(&mut bytes);
+            program.write_into(&mut bytes);
+
+            // the store is best-effort, the program is still cached in memory if writing fails
+            let _ = fs::create_dir_all(store_dir)
+                .and_then(|_| write_atomic(&store_dir.join(program_file_name(key)), &bytes));
+        }
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn store(&self, _key: Digest, _source_hash: Digest, _program: &Program) {}
+}
+
+impl Clone for ScriptCache {
+    fn clone(&self) -> Self {
+        Self {
+            registry: self.registry.clone(),
+            assembler_digest: self.assembler_digest,
+            capacity: self.capacity,
+            programs: RwLock::new(self.programs.read().clone()),
+            #[cfg(feature = "std")]
+            store_dir: self.store_dir.clone(),
+        }
+    }
+}
+
+impl Default for ScriptCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// PROGRAM CACHE
+// ================================================================================================
+
+/// The programs cached in memory, along with the order in which they were inserted.
+#[derive(Clone, Default)]
+struct ProgramCache {
+    programs: BTreeMap<Digest, Program>,
+    insertion_order: VecDeque<Digest>,
+}
+
+impl ProgramCache {
+    /// Inserts the provided program under the specified key, and evicts the least recently
+    /// inserted programs until at most `capacity` programs are cached.
+    fn insert(&mut self, key: Digest, program: Program, capacity: usize) {
+        if self.programs.insert(key, program).is_none() {
+            self.insertion_order.push_back(key);
+        }
+        self.evict(capacity);
+    }
+
+    /// Evicts the least recently inserted programs until at most `capacity` programs are cached.
+    fn evict(&mut self, capacity: usize) {
+        while self.programs.len() > capacity {
+            let key = self.insertion_order.pop_front().expect("every program has an insertion");
+            self.programs.remove(&key);
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the digest of the transaction kernel assembler loaded with the specified libraries.
+///
+/// The digest commits to the version of miden-lib and to the contents of the kernel and all loaded
+/// libraries, which change whenever the assembler producing them changes.
+fn compute_assembler_digest(libraries: &[&Library]) -> Digest {
+    let kernel = TransactionKernel::kernel();
+    let std_lib = StdLibrary::default();
+    let miden_lib = MidenLib::default();
+
+    let mut digest = Hasher::hash(env!("CARGO_PKG_VERSION").as_bytes());
+    let library_digests = [std_lib.as_ref(), miden_lib.as_ref()]
+        .into_iter()
+        .chain(libraries.iter().copied())
+        .map(Library::digest);
+    for &proc_digest in kernel.kernel().proc_hashes().iter().chain(library_digests) {
+        digest = Hasher::merge(&[digest, proc_digest]);
+    }
+
+    digest
+}
+
+/// Returns the name of the file a program is stored in under the specified key.
+#[cfg(feature = "std")]
+fn program_file_name(key: Digest) -> String {
+    format!("{}.masb", key.to_hex())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::String, sync::Arc, vec::Vec};
+    use std::{fs, thread};
+
+    use miden_objects::notes::NoteScript;
+    use tempfile::tempdir;
+
+    use super::ScriptCache;
+    use crate::{notes::NoteLibraryRegistry, transaction::TransactionKernel};
+
+    const NOTE_SCRIPT: &str = "begin push.1 drop end";
+
+    #[test]
+    fn test_script_cache() {
+        let cache = ScriptCache::new();
+        assert!(cache.is_empty());
+
+        // cached scripts match the scripts compiled by the transaction kernel assembler
+        let note_script = cache.compile_note_script(NOTE_SCRIPT).unwrap();
+        let expected = NoteScript::compile(NOTE_SCRIPT, TransactionKernel::assembler()).unwrap();
+        assert_eq!(note_script.hash(), expected.hash());
+        assert_eq!(cache.compile_note_script(NOTE_SCRIPT).unwrap().hash(), note_script.hash());
+        assert_eq!(cache.len(), 1);
+
+        // transaction scripts share the compiled programs with note scripts
+        let tx_script = cache.compile_tx_script(NOTE_SCRIPT, []).unwrap();
+        assert_eq!(tx_script.hash(), note_script.hash());
+        assert_eq!(cache.len(), 1);
+
+        // compilation errors are not cached
+        assert!(cache.compile_note_script("begin exec.missing end").is_err());
+        assert_eq!(cache.len(), 1);
+
+        // the assembler digest depends on the loaded libraries
+        let library =
+            NoteLibraryRegistry::compile_library("notes::common", "export.foo push.1 drop end");
+        let mut registry = NoteLibraryRegistry::new();
+        registry.register(library).unwrap();
+        let registry_cache = ScriptCache::from_registry(&registry);
+        assert_ne!(registry_cache.assembler_digest(), cache.assembler_digest());
+        assert_eq!(
+            ScriptCache::from_registry(&NoteLibraryRegistry::new()).assembler_digest(),
+            cache.assembler_digest()
+        );
+    }
+
+    #[test]
+    fn test_script_cache_store_dir() {
+        let temp_dir = tempdir().unwrap();
+        let store_dir = temp_dir.path().join("scripts");
+
+        let cache = ScriptCache::new().with_store_dir(&store_dir);
+        let note_script = cache.compile_note_script(NOTE_SCRIPT).unwrap();
+        assert_eq!(fs::read_dir(&store_dir).unwrap().count(), 1);
+
+        // a new cache loads the program from the store directory
+        let cache = ScriptCache::new().with_store_dir(&store_dir);
+        assert_eq!(cache.compile_note_script(NOTE_SCRIPT).unwrap().hash(), note_script.hash());
+        assert_eq!(fs::read_dir(&store_dir).unwrap().count(), 1);
+
+        // corrupted programs are compiled again
+        for entry in fs::read_dir(&store_dir).unwrap() {
+            fs::write(entry.unwrap().path(), [0u8; 4]).unwrap();
+        }
+        let cache = ScriptCache::new().with_store_dir(&store_dir);
+        assert_eq!(cache.compile_note_script(NOTE_SCRIPT).unwrap().hash(), note_script.hash());
+
+        // programs stored for a different source code are compiled again
+        let other_source = "begin push.2 drop end";
+        let other_script = cache.compile_note_script(other_source).unwrap();
+        let stored_programs: Vec<_> =
+            fs::read_dir(&store_dir).unwrap().map(|entry| entry.unwrap().path()).collect();
+        let contents: Vec<_> = stored_programs.iter().map(|path| fs::read(path).unwrap()).collect();
+        fs::write(&stored_programs[0], &contents[1]).unwrap();
+        fs::write(&stored_programs[1], &contents[0]).unwrap();
+        let cache = ScriptCache::new().with_store_dir(&store_dir);
+        assert_eq!(cache.compile_note_script(NOTE_SCRIPT).unwrap().hash(), note_script.hash());
+        assert_eq!(cache.compile_note_script(other_source).unwrap().hash(), other_script.hash());
+    }
+
+    #[test]
+    fn test_script_cache_capacity() {
+        let cache = ScriptCache::new().with_capacity(2);
+        let sources: Vec<String> =
+            (0..3).map(|value| format!("begin push.{value} drop end")).collect();
+
+        // the least recently inserted programs are evicted first
+        let scripts: Vec<NoteScript> = sources
+            .iter()
+            .map(|source| cache.compile_note_script(source).unwrap())
+            .collect();
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.compile_note_script(&sources[1]).unwrap().hash(), scripts[1].hash());
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.compile_note_script(&sources[0]).unwrap().hash(), scripts[0].hash());
+        assert_eq!(cache.len(), 2);
+
+        // reducing the capacity evicts the programs above it
+        let cache = cache.with_capacity(1);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_script_cache_shared_between_threads() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<ScriptCache>();
+
+        let cache = Arc::new(ScriptCache::new());
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let cache = cache.clone();
+                thread::spawn(move || cache.compile_note_script(NOTE_SCRIPT).unwrap().hash())
+            })
+            .collect();
+
+        let expected = NoteScript::compile(NOTE_SCRIPT, TransactionKernel::assembler()).unwrap();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), expected.hash());
+        }
+        assert_eq!(cache.len(), 1);
+    }
+}
@@ -45,7 +45,7 @@ pub trait TransactionAuthenticator {
 /// Represents a signer for [AuthSecretKey] keys.
 pub struct BasicAuthenticator<R> {
     /// pub_key |-> secret_key mapping
-    keys: BTreeMap<Digest, AuthSecretKey>,
+    keys: RefCell<BTreeMap<Digest, AuthSecretKey>>,
     rng: RefCell<R>,
 }
 
@@ -64,7 +64,55 @@ impl<R: Rng> BasicAuthenticator<R> {
             key_map.insert(word.into(), secret_key.clone());
         }
 
-        BasicAuthenticator { keys: key_map, rng: RefCell::new(rng) }
+        BasicAuthenticator {
+            keys: RefCell::new(key_map),
+            rng: RefCell::new(rng),
+        }
+    }
+
+    /// Returns true if the authenticator manages the secret key for the specified public key.
+    pub fn contains_key(&self, pub_key: Word) -> bool {
+        self.keys.borrow().contains_key(&pub_key.into())
+    }
+
+    /// Adds the provided key pair to the authenticator, replacing the secret key previously stored
+    /// for the same public key, if any.
+    pub fn add_key(&self, pub_key: Word, secret_key: AuthSecretKey) {
+        self.keys.borrow_mut().insert(pub_key.into(), secret_key);
+    }
+
+    /// Removes the key pair for the specified public key from the authenticator and returns the
+    /// removed secret key, if any.
+    pub fn remove_key(&self, pub_key: Word) -> Option<AuthSecretKey> {
+        self.keys.borrow_mut().remove(&pub_key.into())
+    }
+
+    /// Replaces the key pair for `old_pub_key` with the provided new key pair and returns the
+    /// removed secret key.
+    ///
+    /// This is intended to be used after a key rotation transaction has been executed, so that
+    /// subsequent transactions are signed with the new key. The authenticator can be shared with a
+    /// [TransactionExecutor](crate::TransactionExecutor), so the keys are swapped in place.
+    ///
+    /// # Errors
+    /// Returns an error if the authenticator does not manage a secret key for `old_pub_key`. In
+    /// this case the authenticator is left unchanged.
+    pub fn swap_key(
+        &self,
+        old_pub_key: Word,
+        new_pub_key: Word,
+        new_secret_key: AuthSecretKey,
+    ) -> Result<AuthSecretKey, AuthenticationError> {
+        let mut keys = self.keys.borrow_mut();
+        let old_secret_key = keys.remove(&old_pub_key.into()).ok_or_else(|| {
+            AuthenticationError::UnknownKey(format!(
+                "Public key {} is not contained in the authenticator's keys",
+                Digest::from(old_pub_key)
+            ))
+        })?;
+        keys.insert(new_pub_key.into(), new_secret_key);
+
+        Ok(old_secret_key)
     }
 }
 
@@ -87,7 +135,7 @@ impl<R: Rng> TransactionAuthenticator for BasicAuthenticator<R> {
         let _ = account_delta;
         let mut rng = self.rng.borrow_mut();
 
-        match self.keys.get(&pub_key.into()) {
+        match self.keys.borrow().get(&pub_key.into()) {
             Some(key) => match key {
                 AuthSecretKey::RpoFalcon512(falcon_key) => {
                     get_falcon_signature(falcon_key, message, &mut *rng)
@@ -120,7 +168,10 @@ impl TransactionAuthenticator for () {
 #[cfg(test)]
 mod test {
     use miden_lib::utils::{Deserializable, Serializable};
-    use miden_objects::{accounts::AuthSecretKey, crypto::dsa::rpo_falcon512::SecretKey};
+    use miden_objects::{accounts::AuthSecretKey, crypto::dsa::rpo_falcon512::SecretKey, Word};
+    use rand::rngs::StdRng;
+
+    use super::BasicAuthenticator;
 
     #[test]
     fn serialize_auth_key() {
@@ -133,4 +184,37 @@ mod test {
             AuthSecretKey::RpoFalcon512(key) => assert_eq!(secret_key.to_bytes(), key.to_bytes()),
         }
     }
+
+    #[test]
+    fn swap_authenticator_key() {
+        let old_key = SecretKey::new();
+        let old_pub_key: Word = old_key.public_key().into();
+        let new_key = SecretKey::new();
+        let new_pub_key: Word = new_key.public_key().into();
+
+        let authenticator = BasicAuthenticator::<StdRng>::new(&[(
+            old_pub_key,
+            AuthSecretKey::RpoFalcon512(old_key.clone()),
+        )]);
+
+        let removed = authenticator
+            .swap_key(old_pub_key, new_pub_key, AuthSecretKey::RpoFalcon512(new_key))
+            .unwrap();
+        match removed {
+            AuthSecretKey::RpoFalcon512(key) => assert_eq!(key.to_bytes(), old_key.to_bytes()),
+        }
+        assert!(!authenticator.contains_key(old_pub_key));
+        assert!(authenticator.contains_key(new_pub_key));
+
+        // swapping a key which is not managed by the authenticator fails
+        let other_key = SecretKey::new();
+        assert!(authenticator
+            .swap_key(
+                old_pub_key,
+                other_key.public_key().into(),
+                AuthSecretKey::RpoFalcon512(other_key)
+            )
+            .is_err());
+        assert!(authenticator.contains_key(new_pub_key));
+    }
 }
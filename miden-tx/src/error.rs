@@ -2,8 +2,10 @@ use alloc::string::String;
 use core::fmt::{self, Display};
 
 use miden_objects::{
-    accounts::AccountId, notes::NoteId, AccountError, Digest, Felt, NoteError,
-    ProvenTransactionError, TransactionInputError, TransactionOutputError, TransactionScriptError,
+    accounts::AccountId,
+    notes::{NoteExecutionHint, NoteId},
+    AccountError, Digest, Felt, NoteError, ProvenTransactionError, TransactionInputError,
+    TransactionOutputError, TransactionScriptError,
 };
 use miden_verifier::VerificationError;
 use vm_processor::ExecutionError;
@@ -48,6 +50,12 @@ pub enum TransactionExecutorError {
         expected: Option<Felt>,
         actual: Option<Felt>,
     },
+    InputNoteNotConsumable {
+        note_id: NoteId,
+        block_num: u32,
+        execution_hint: NoteExecutionHint,
+    },
+    InvalidTransactionInput(TransactionInputError),
     InvalidTransactionOutput(TransactionOutputError),
     LoadAccountFailed(TransactionCompilerError),
     TransactionHostCreationFailed(TransactionHostError),
@@ -69,6 +77,7 @@ impl std::error::Error for TransactionExecutorError {}
 pub enum TransactionProverError {
     ProveTransactionProgramFailed(ExecutionError),
     InvalidAccountDelta(AccountError),
+    InvalidTransactionInput(TransactionInputError),
     InvalidTransactionOutput(TransactionOutputError),
     ProvenTransactionError(ProvenTransactionError),
     TransactionHostCreationFailed(TransactionHostError),
@@ -83,6 +92,9 @@ impl Display for TransactionProverError {
             TransactionProverError::InvalidAccountDelta(account_error) => {
                 write!(f, "Applying account delta failed: {}", account_error)
             },
+            TransactionProverError::InvalidTransactionInput(inner) => {
+                write!(f, "Transaction input invalid: {}", inner)
+            },
             TransactionProverError::InvalidTransactionOutput(inner) => {
                 write!(f, "Transaction ouptut invalid: {}", inner)
             },
@@ -265,8 +277,38 @@ const ERR_PROC_NOT_PART_OF_ACCOUNT_CODE: u32 = 131146;
 const ERR_PROC_INDEX_OUT_OF_BOUNDS: u32 = 131147;
 const ERR_ACCT_CODE_HASH_MISMATCH: u32 = 131148;
 const ERR_ACCT_TOO_MANY_PROCEDURES: u32 = 131149;
-
-pub const KERNEL_ERRORS: [(u32, &str); 79] = [
+const ERR_BASIC_NON_FUNGIBLE_MAX_ITEMS_EXCEEDED: u32 = 131155;
+const ERR_BASIC_FUNGIBLE_MINTING_FROZEN: u32 = 131156;
+const ERR_BASIC_FUNGIBLE_MAX_SUPPLY_NOT_LOWERED: u32 = 131157;
+const ERR_BASIC_FUNGIBLE_MAX_SUPPLY_BELOW_TOTAL_ISSUANCE: u32 = 131158;
+const ERR_SOCIAL_RECOVERY_SENDER_NOT_GUARDIAN: u32 = 131159;
+const ERR_SOCIAL_RECOVERY_ALREADY_APPROVED: u32 = 131160;
+const ERR_SOCIAL_RECOVERY_THRESHOLD_NOT_REACHED: u32 = 131161;
+const ERR_SOCIAL_RECOVERY_DELAY_NOT_ELAPSED: u32 = 131162;
+const ERR_RECOVERY_WRONG_NUMBER_OF_INPUTS: u32 = 131163;
+const ERR_RECOVERY_TARGET_ACCT_MISMATCH: u32 = 131164;
+const ERR_SPENDING_LIMIT_NOT_FUNGIBLE_FAUCET: u32 = 131165;
+const ERR_SPENDING_LIMIT_INVALID_WINDOW: u32 = 131166;
+const ERR_PROLOGUE_FOREIGN_ACCT_HASH_MISMATCH: u32 = 131167;
+const ERR_PROLOGUE_FOREIGN_ACCT_IS_NATIVE: u32 = 131168;
+const ERR_PROLOGUE_TOO_MANY_FOREIGN_ACCOUNTS: u32 = 131169;
+const ERR_FOREIGN_ACCT_NOT_FOUND: u32 = 131170;
+const ERR_FOREIGN_CONTEXT_ALREADY_STARTED: u32 = 131171;
+const ERR_FOREIGN_CONTEXT_NOT_STARTED: u32 = 131172;
+const ERR_FOREIGN_PROC_NOT_PART_OF_ACCOUNT_CODE: u32 = 131173;
+const ERR_FOREIGN_ACCT_STATE_IS_READ_ONLY: u32 = 131174;
+const ERR_EPILOGUE_FOREIGN_CONTEXT_NOT_ENDED: u32 = 131175;
+const ERR_ACCOUNT_STORAGE_SLOT_INDEX_OUT_OF_BOUNDS: u32 = 131176;
+const ERR_NOTE_TOO_MANY_STREAMED_INPUTS: u32 = 131177;
+const ERR_NOTE_INVALID_EXECUTION_HINT_CONTEXT: u32 = 131178;
+const ERR_NOTE_EXECUTION_HINT_NOT_SATISFIED: u32 = 131179;
+const ERR_STEALTH_WRONG_NUMBER_OF_INPUTS: u32 = 131180;
+const ERR_STEALTH_RECIPIENT_COMMITMENT_MISMATCH: u32 = 131181;
+const ERR_PROLOGUE_DUPLICATE_FOREIGN_ACCT: u32 = 131182;
+const ERR_SOCIAL_RECOVERY_APPROVAL_NOT_FOUND: u32 = 131183;
+const ERR_SOCIAL_RECOVERY_UNEXPECTED_CHANGES: u32 = 131184;
+
+pub const KERNEL_ERRORS: [(u32, &str); 109] = [
     (ERR_FAUCET_RESERVED_DATA_SLOT, "For faucets, storage slot 254 is reserved and can not be used with set_account_item procedure"),
     (ERR_ACCT_MUST_BE_A_FAUCET, "Procedure can only be called from faucet accounts"),
     (ERR_P2ID_WRONG_NUMBER_OF_INPUTS, "P2ID scripts expect exactly 1 note input"),
@@ -345,5 +387,35 @@ pub const KERNEL_ERRORS: [(u32, &str); 79] = [
     (ERR_PROC_NOT_PART_OF_ACCOUNT_CODE, "Provided procedure is not part of account code"),
     (ERR_PROC_INDEX_OUT_OF_BOUNDS, "Provided procedure index is out of bounds"),
     (ERR_ACCT_CODE_HASH_MISMATCH, "Provided account hash does not match stored account hash"),
-    (ERR_ACCT_TOO_MANY_PROCEDURES, "Number of account procedures exceeded the maximum limit of 256")
+    (ERR_ACCT_TOO_MANY_PROCEDURES, "Number of account procedures exceeded the maximum limit of 256"),
+    (ERR_BASIC_NON_FUNGIBLE_MAX_ITEMS_EXCEEDED, "Distribute would cause the maximum number of items in the collection to be exceeded"),
+    (ERR_BASIC_FUNGIBLE_MINTING_FROZEN, "Minting has been frozen by the owner of the faucet"),
+    (ERR_BASIC_FUNGIBLE_MAX_SUPPLY_NOT_LOWERED, "New max supply must be lower than the current max supply"),
+    (ERR_BASIC_FUNGIBLE_MAX_SUPPLY_BELOW_TOTAL_ISSUANCE, "New max supply must not be lower than the total issuance of the faucet"),
+    (ERR_SOCIAL_RECOVERY_SENDER_NOT_GUARDIAN, "Sender of the recovery note is not a guardian of the account"),
+    (ERR_SOCIAL_RECOVERY_ALREADY_APPROVED, "Guardian has already approved the recovery to the provided public key"),
+    (ERR_SOCIAL_RECOVERY_THRESHOLD_NOT_REACHED, "Number of guardian approvals for the provided public key is below the threshold"),
+    (ERR_SOCIAL_RECOVERY_DELAY_NOT_ELAPSED, "Recovery delay has not elapsed yet"),
+    (ERR_RECOVERY_WRONG_NUMBER_OF_INPUTS, "RECOVERY scripts expect exactly 5 note inputs"),
+    (ERR_RECOVERY_TARGET_ACCT_MISMATCH, "RECOVERY's target account address and transaction address do not match"),
+    (ERR_SPENDING_LIMIT_NOT_FUNGIBLE_FAUCET, "Spending limits can only be set for fungible faucets"),
+    (ERR_SPENDING_LIMIT_INVALID_WINDOW, "Window length of a spending limit must be a non-zero u32 value"),
+    (ERR_PROLOGUE_FOREIGN_ACCT_HASH_MISMATCH, "Foreign account data does not match the account state in the account database of the reference block"),
+    (ERR_PROLOGUE_FOREIGN_ACCT_IS_NATIVE, "The native account of the transaction cannot be used as a foreign account"),
+    (ERR_PROLOGUE_TOO_MANY_FOREIGN_ACCOUNTS, "Number of foreign accounts exceeded the kernel's maximum limit of 64"),
+    (ERR_FOREIGN_ACCT_NOT_FOUND, "Foreign account with the provided ID is not part of the transaction inputs"),
+    (ERR_FOREIGN_CONTEXT_ALREADY_STARTED, "A foreign account is already loaded, foreign procedures cannot be nested"),
+    (ERR_FOREIGN_CONTEXT_NOT_STARTED, "No foreign account is loaded"),
+    (ERR_FOREIGN_PROC_NOT_PART_OF_ACCOUNT_CODE, "Procedure is not part of the code of the foreign account"),
+    (ERR_FOREIGN_ACCT_STATE_IS_READ_ONLY, "The state of a foreign account cannot be modified"),
+    (ERR_EPILOGUE_FOREIGN_CONTEXT_NOT_ENDED, "The transaction cannot be finalized while a foreign account is loaded"),
+    (ERR_ACCOUNT_STORAGE_SLOT_INDEX_OUT_OF_BOUNDS, "Storage slot index is outside of the storage slots accessible to the procedure"),
+    (ERR_NOTE_TOO_MANY_STREAMED_INPUTS, "Number of streamed note inputs exceeded the maximum limit of 65535"),
+    (ERR_NOTE_INVALID_EXECUTION_HINT_CONTEXT, "Trying to access note execution hint from incorrect context"),
+    (ERR_NOTE_EXECUTION_HINT_NOT_SATISFIED, "Execution hint of the note does not allow the note to be consumed at the reference block"),
+    (ERR_STEALTH_WRONG_NUMBER_OF_INPUTS, "STEALTH scripts expect exactly 12 note inputs"),
+    (ERR_STEALTH_RECIPIENT_COMMITMENT_MISMATCH, "STEALTH's recipient commitment does not open to the transaction account address"),
+    (ERR_PROLOGUE_DUPLICATE_FOREIGN_ACCT, "The same account cannot be provided as a foreign account more than once"),
+    (ERR_SOCIAL_RECOVERY_APPROVAL_NOT_FOUND, "Guardian did not approve the provided public key in the transaction"),
+    (ERR_SOCIAL_RECOVERY_UNEXPECTED_CHANGES, "Transaction recording recovery approvals changed the account or created notes")
 ];
@@ -1,4 +1,8 @@
-use miden_objects::{accounts::AccountId, notes::NoteId, transaction::TransactionInputs};
+use miden_objects::{
+    accounts::AccountId,
+    notes::NoteId,
+    transaction::{ForeignAccountInputs, TransactionInputs},
+};
 use winter_maybe_async::maybe_async;
 
 use crate::DataStoreError;
@@ -31,4 +35,26 @@ pub trait DataStore {
         block_ref: u32,
         notes: &[NoteId],
     ) -> Result<TransactionInputs, DataStoreError>;
+
+    /// Returns the state of the foreign account with the specified ID as of the block with the
+    /// specified number, together with the authentication path of the account state in the account
+    /// tree of that block.
+    ///
+    /// Foreign accounts are accounts the state of which is read by a transaction executed against
+    /// another account. The default implementation does not support foreign accounts.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account with the specified ID could not be found in the data store.
+    /// - The block with the specified number could not be found in the data store.
+    /// - The data store encountered some internal error
+    #[maybe_async]
+    fn get_foreign_account_inputs(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+    ) -> Result<ForeignAccountInputs, DataStoreError> {
+        let _ = block_ref;
+        Err(DataStoreError::AccountNotFound(account_id))
+    }
 }
@@ -1,12 +1,23 @@
-use alloc::{collections::BTreeMap, sync::Arc};
-use core::cell::RefCell;
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    sync::Arc,
+};
+#[cfg(feature = "std")]
+use std::{fs, io, path::PathBuf};
 
-use miden_lib::{transaction::TransactionKernel, MidenLib, StdLibrary};
+use miden_lib::{notes::NoteLibraryRegistry, transaction::TransactionKernel, MidenLib, StdLibrary};
 use miden_objects::{
-    assembly::mast::MastForest,
+    assembly::{mast::MastForest, Library},
     transaction::{TransactionArgs, TransactionInputs},
+    utils::sync::RwLock,
     Digest,
 };
+#[cfg(feature = "std")]
+use miden_objects::{
+    utils::{write_atomic, Deserializable, Serializable},
+    Hasher,
+};
 use vm_processor::MastForestStore;
 
 // TRANSACTION MAST STORE
@@ -19,35 +30,91 @@ use vm_processor::MastForestStore;
 /// a procedure which it doesn't have the code for. Thus, to execute a program which makes
 /// references to external procedures, the store must be loaded with [MastForest]s containing these
 /// procedures.
+///
+/// Procedures which are not loaded into the store are looked up in the [MastForestBackend] of the
+/// store, if any, and the forests containing them are kept in memory once loaded. The backend is
+/// queried without blocking requests for other procedures, and concurrent requests for the same
+/// procedure query the backend only once. Up to [TransactionMastStore::MAX_MISSING_PROCEDURES]
+/// procedures missing from the backend are remembered as well, the least recently missed ones
+/// being forgotten first, so that the backend is not queried repeatedly for the same missing
+/// procedure; they can still be loaded into the store directly.
+///
+/// A single store can be shared by several executors and provers, possibly running on different
+/// threads, via
+/// [TransactionExecutor::with_mast_store()](crate::TransactionExecutor::with_mast_store) and
+/// [TransactionProver::with_mast_store()](crate::TransactionProver::with_mast_store).
 pub struct TransactionMastStore {
-    mast_forests: RefCell<BTreeMap<Digest, Arc<MastForest>>>,
+    state: RwLock<MastStoreState>,
+    backend: Option<Box<dyn MastForestBackend>>,
+}
+
+/// The mutable state of a [TransactionMastStore].
+#[derive(Default)]
+struct MastStoreState {
+    mast_forests: BTreeMap<Digest, Arc<MastForest>>,
+    missing_procedures: BTreeSet<Digest>,
+    missing_order: VecDeque<Digest>,
+    pending_lookups: BTreeMap<Digest, Arc<RwLock<()>>>,
+    default_libraries_loaded: bool,
+}
+
+impl MastStoreState {
+    /// Registers all procedures local to the provided [MastForest].
+    fn insert(&mut self, mast_forest: Arc<MastForest>) {
+        for proc_digest in mast_forest.local_procedure_digests() {
+            self.mast_forests.insert(proc_digest, mast_forest.clone());
+        }
+    }
+
+    /// Returns the forest containing the specified procedure, or `Some(None)` if the procedure is
+    /// known to be missing from the backend, or `None` if the procedure is unknown.
+    fn lookup(&self, procedure_hash: &Digest) -> Option<Option<Arc<MastForest>>> {
+        if let Some(mast_forest) = self.mast_forests.get(procedure_hash) {
+            return Some(Some(mast_forest.clone()));
+        }
+        self.missing_procedures.contains(procedure_hash).then_some(None)
+    }
+
+    /// Remembers that the specified procedure is missing from the backend, forgetting the least
+    /// recently missed procedures above [TransactionMastStore::MAX_MISSING_PROCEDURES].
+    fn insert_missing(&mut self, procedure_hash: Digest) {
+        if self.missing_procedures.insert(procedure_hash) {
+            self.missing_order.push_back(procedure_hash);
+        }
+        while self.missing_procedures.len() > TransactionMastStore::MAX_MISSING_PROCEDURES {
+            let procedure_hash =
+                self.missing_order.pop_front().expect("every missing procedure has an order");
+            self.missing_procedures.remove(&procedure_hash);
+        }
+    }
 }
 
 #[allow(clippy::new_without_default)]
 impl TransactionMastStore {
+    /// The maximum number of procedures missing from the backend which are remembered.
+    pub const MAX_MISSING_PROCEDURES: usize = 1024;
+
     /// Returns a new [TransactionMastStore] instantiated with the default libraries.
     ///
     /// The default libraries include:
     /// - Miden standard library (miden-stdlib).
     /// - Miden rollup library (miden-lib).
     /// - Transaction kernel.
+    ///
+    /// The default libraries are loaded lazily, i.e., when a procedure is requested from the store
+    /// for the first time.
     pub fn new() -> Self {
-        let mast_forests = RefCell::new(BTreeMap::new());
-        let store = Self { mast_forests };
-
-        // load transaction kernel MAST forest
-        let kernels_forest = Arc::new(TransactionKernel::kernel().into());
-        store.insert(kernels_forest);
-
-        // load miden-stdlib MAST forest
-        let miden_stdlib_forest = Arc::new(StdLibrary::default().into());
-        store.insert(miden_stdlib_forest);
-
-        // load miden lib MAST forest
-        let miden_lib_forest = Arc::new(MidenLib::default().into());
-        store.insert(miden_lib_forest);
+        Self {
+            state: RwLock::new(MastStoreState::default()),
+            backend: None,
+        }
+    }
 
-        store
+    /// Sets the backend from which the procedures which are not loaded into this store are
+    /// loaded.
+    pub fn with_backend(mut self, backend: impl MastForestBackend + 'static) -> Self {
+        self.backend = Some(Box::new(backend));
+        self
     }
 
     /// Loads code required for executing a transaction with the specified inputs and args into
@@ -61,6 +128,11 @@ impl TransactionMastStore {
         // load account code
         self.insert(tx_inputs.account().code().mast().clone());
 
+        // load foreign account code
+        for foreign_account in tx_inputs.foreign_accounts() {
+            self.insert(foreign_account.account().code().mast().clone());
+        }
+
         // load note script MAST into the MAST store
         for note in tx_inputs.input_notes() {
             self.insert(note.note().script().mast().clone());
@@ -72,14 +144,51 @@ impl TransactionMastStore {
         }
     }
 
+    /// Loads the code of the provided library into this store.
+    ///
+    /// This is required for executing note scripts, account code or transaction scripts which
+    /// invoke procedures of libraries other than the default ones.
+    pub fn load_library(&self, library: &Library) {
+        self.insert(Arc::new(library.mast_forest().clone()));
+    }
+
+    /// Loads the code of all libraries of the provided [NoteLibraryRegistry] into this store.
+    ///
+    /// This is required for executing note scripts compiled via the registry.
+    pub fn load_note_libraries(&self, registry: &NoteLibraryRegistry) {
+        for library in registry.libraries() {
+            self.load_library(library);
+        }
+    }
+
     /// Registers all procedures of the provided [MastForest] with this store.
     pub fn insert(&self, mast_forest: Arc<MastForest>) {
-        let mut mast_forests = self.mast_forests.borrow_mut();
-
         // only register procedures that are local to this forest
-        for proc_digest in mast_forest.local_procedure_digests() {
-            mast_forests.insert(proc_digest, mast_forest.clone());
+        self.state.write().insert(mast_forest);
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Loads the default libraries into this store, unless they have already been loaded.
+    fn load_default_libraries(&self) {
+        let mut state = self.state.write();
+        if state.default_libraries_loaded {
+            return;
         }
+        state.default_libraries_loaded = true;
+
+        // load transaction kernel MAST forest
+        let kernels_forest = Arc::new(TransactionKernel::kernel().into());
+        state.insert(kernels_forest);
+
+        // load miden-stdlib MAST forest
+        let miden_stdlib_forest = Arc::new(StdLibrary::default().into());
+        state.insert(miden_stdlib_forest);
+
+        // load miden lib MAST forest
+        let miden_lib_forest = Arc::new(MidenLib::default().into());
+        state.insert(miden_lib_forest);
     }
 }
 
@@ -88,6 +197,148 @@ impl TransactionMastStore {
 
 impl MastForestStore for TransactionMastStore {
     fn get(&self, procedure_hash: &Digest) -> Option<Arc<MastForest>> {
-        self.mast_forests.borrow().get(procedure_hash).cloned()
+        let default_libraries_loaded = {
+            let state = self.state.read();
+            if let Some(mast_forest) = state.lookup(procedure_hash) {
+                return mast_forest;
+            }
+            state.default_libraries_loaded
+        };
+
+        if !default_libraries_loaded {
+            self.load_default_libraries();
+            if let Some(mast_forest) = self.state.read().mast_forests.get(procedure_hash) {
+                return Some(mast_forest.clone());
+            }
+        }
+
+        let backend = self.backend.as_ref()?;
+
+        // concurrent requests for the same procedure wait for the first one to query the backend,
+        // while the lock on the state of the store is not held during the query
+        let lookup_lock = {
+            let mut state = self.state.write();
+            if let Some(mast_forest) = state.lookup(procedure_hash) {
+                return mast_forest;
+            }
+            state.pending_lookups.entry(*procedure_hash).or_default().clone()
+        };
+        let _lookup_guard = lookup_lock.write();
+        if let Some(mast_forest) = self.state.read().lookup(procedure_hash) {
+            return mast_forest;
+        }
+
+        let mast_forest = backend.get(procedure_hash).map(Arc::new);
+
+        let mut state = self.state.write();
+        state.pending_lookups.remove(procedure_hash);
+        match mast_forest {
+            Some(mast_forest) => {
+                state.insert(mast_forest.clone());
+                Some(mast_forest)
+            },
+            None => {
+                state.insert_missing(*procedure_hash);
+                None
+            },
+        }
+    }
+}
+
+// MAST FOREST BACKEND
+// ================================================================================================
+
+/// A source of [MastForest]s from which a [TransactionMastStore] loads the procedures it does not
+/// have the code for.
+///
+/// Backends must be [Send] and [Sync], so that the stores using them can be shared between
+/// threads.
+pub trait MastForestBackend: Send + Sync {
+    /// Returns the [MastForest] containing the procedure with the specified MAST root, or `None`
+    /// if the backend does not contain such a forest.
+    ///
+    /// The returned forest must contain a procedure with the specified MAST root.
+    fn get(&self, procedure_root: &Digest) -> Option<MastForest>;
+}
+
+// DIRECTORY MAST BACKEND
+// ================================================================================================
+
+/// A [MastForestBackend] storing serialized [MastForest]s in a directory.
+///
+/// Each forest is stored once in the `forests` subdirectory, under the hash of its serialized
+/// bytes. The forests are indexed by the MAST roots of their procedures in the `roots`
+/// subdirectory, which holds a file with the hash of the containing forest for each procedure.
+/// Thus, a forest is read from the directory only when one of its procedures is requested.
+///
+/// All files are written atomically, so forests can be inserted while the directory is read by
+/// other stores. The contents of the directory are not trusted: the forests read from it are
+/// checked to contain the requested procedures.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct DirectoryMastBackend {
+    path: PathBuf,
+}
+
+#[cfg(feature = "std")]
+impl DirectoryMastBackend {
+    /// Returns a new [DirectoryMastBackend] storing forests in the specified directory.
+    ///
+    /// The directory is created when the first forest is inserted.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Returns the directory forests are stored in.
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Stores the provided [MastForest] in the directory and indexes it by the MAST roots of all
+    /// procedures local to the forest.
+    ///
+    /// # Errors
+    /// Returns an error if writing to the directory fails.
+    pub fn insert(&self, mast_forest: &MastForest) -> io::Result<()> {
+        let bytes = mast_forest.to_bytes();
+        let forest_hash = Hasher::hash(&bytes).to_hex();
+
+        let forests_dir = self.path.join("forests");
+        fs::create_dir_all(&forests_dir)?;
+        let forest_path = forests_dir.join(&forest_hash);
+        if !forest_path.exists() {
+            write_atomic(&forest_path, &bytes)?;
+        }
+
+        let roots_dir = self.path.join("roots");
+        fs::create_dir_all(&roots_dir)?;
+        for proc_digest in mast_forest.local_procedure_digests() {
+            write_atomic(&roots_dir.join(proc_digest.to_hex()), forest_hash.as_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    /// Stores the code of the provided library in the directory.
+    ///
+    /// # Errors
+    /// Returns an error if writing to the directory fails.
+    pub fn insert_library(&self, library: &Library) -> io::Result<()> {
+        self.insert(library.mast_forest())
+    }
+}
+
+#[cfg(feature = "std")]
+impl MastForestBackend for DirectoryMastBackend {
+    fn get(&self, procedure_root: &Digest) -> Option<MastForest> {
+        let forest_hash =
+            fs::read_to_string(self.path.join("roots").join(procedure_root.to_hex())).ok()?;
+
+        // the forest hash is parsed as a digest, so that it cannot point outside of the directory
+        let forest_hash = Digest::try_from(forest_hash.trim()).ok()?;
+        let bytes = fs::read(self.path.join("forests").join(forest_hash.to_hex())).ok()?;
+
+        let mast_forest = MastForest::read_from_bytes(&bytes).ok()?;
+        mast_forest.find_procedure_root(*procedure_root).map(|_| mast_forest)
     }
 }
@@ -1,8 +1,9 @@
-use alloc::rc::Rc;
+use alloc::{rc::Rc, sync::Arc, vec::Vec};
 
-use miden_lib::transaction::TransactionKernel;
+use miden_lib::{notes::NoteLibraryRegistry, transaction::TransactionKernel};
 use miden_objects::{
     accounts::AccountId,
+    assembly::Library,
     notes::NoteId,
     transaction::{ExecutedTransaction, TransactionArgs, TransactionInputs},
     vm::StackOutputs,
@@ -12,13 +13,15 @@ use vm_processor::{ExecutionOptions, RecAdviceProvider};
 use winter_maybe_async::{maybe_async, maybe_await};
 
 use super::{TransactionExecutorError, TransactionHost};
-use crate::auth::TransactionAuthenticator;
+use crate::{auth::TransactionAuthenticator, DataStoreError};
 
 mod data_store;
 pub use data_store::DataStore;
 
 mod mast_store;
-pub use mast_store::TransactionMastStore;
+#[cfg(feature = "std")]
+pub use mast_store::DirectoryMastBackend;
+pub use mast_store::{MastForestBackend, TransactionMastStore};
 
 // TRANSACTION EXECUTOR
 // ================================================================================================
@@ -26,7 +29,8 @@ pub use mast_store::TransactionMastStore;
 /// The transaction executor is responsible for executing Miden rollup transactions.
 ///
 /// Transaction execution consists of the following steps:
-/// - Fetch the data required to execute a transaction from the [DataStore].
+/// - Fetch the data required to execute a transaction from the [DataStore], including the data of
+///   the foreign accounts requested by the transaction arguments.
 /// - Load the code associated with the transaction into the [TransactionMastStore].
 /// - Execute the transaction program and create an [ExecutedTransaction].
 ///
@@ -38,7 +42,7 @@ pub use mast_store::TransactionMastStore;
 /// can then be used to by the prover to generate a proof transaction execution.
 pub struct TransactionExecutor<D, A> {
     data_store: D,
-    mast_store: Rc<TransactionMastStore>,
+    mast_store: Arc<TransactionMastStore>,
     authenticator: Option<Rc<A>>,
     exec_options: ExecutionOptions,
 }
@@ -52,7 +56,7 @@ impl<D: DataStore, A: TransactionAuthenticator> TransactionExecutor<D, A> {
     pub fn new(data_store: D, authenticator: Option<Rc<A>>) -> Self {
         Self {
             data_store,
-            mast_store: Rc::new(TransactionMastStore::new()),
+            mast_store: Arc::new(TransactionMastStore::new()),
             authenticator,
             exec_options: ExecutionOptions::default(),
         }
@@ -91,6 +95,36 @@ impl<D: DataStore, A: TransactionAuthenticator> TransactionExecutor<D, A> {
         self
     }
 
+    /// Replaces the MAST store of the created instance of [TransactionExecutor] with the provided
+    /// one.
+    ///
+    /// This allows several executors and provers to share a single [TransactionMastStore], so
+    /// that the code loaded into the store is loaded only once.
+    pub fn with_mast_store(mut self, mast_store: Arc<TransactionMastStore>) -> Self {
+        self.mast_store = mast_store;
+        self
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Loads the code of the provided library into the MAST store of this executor.
+    ///
+    /// This is required for executing transactions which consume notes whose scripts invoke
+    /// procedures of user libraries. For the libraries of a [NoteLibraryRegistry], see
+    /// [TransactionExecutor::load_note_libraries()].
+    pub fn load_library(&mut self, library: &Library) {
+        self.mast_store.load_library(library);
+    }
+
+    /// Loads the code of all libraries of the provided [NoteLibraryRegistry] into the MAST store
+    /// of this executor.
+    ///
+    /// This is required for executing transactions which consume notes compiled via the registry.
+    pub fn load_note_libraries(&mut self, registry: &NoteLibraryRegistry) {
+        self.mast_store.load_note_libraries(registry);
+    }
+
     // TRANSACTION EXECUTION
     // --------------------------------------------------------------------------------------------
 
@@ -101,9 +135,15 @@ impl<D: DataStore, A: TransactionAuthenticator> TransactionExecutor<D, A> {
     /// and compile the transaction into an executable program. Then, it executes the transaction
     /// program and creates an [ExecutedTransaction] object.
     ///
+    /// Foreign accounts specified in the transaction arguments which are not part of the inputs
+    /// returned by [DataStore::get_transaction_inputs()] are fetched via
+    /// [DataStore::get_foreign_account_inputs()].
+    ///
     /// # Errors:
     /// Returns an error if:
     /// - If required data can not be fetched from the [DataStore].
+    /// - If the execution hint of any of the input notes indicates that the note cannot be consumed
+    ///   at the reference block.
     #[maybe_async]
     pub fn execute_transaction(
         &self,
@@ -112,12 +152,34 @@ impl<D: DataStore, A: TransactionAuthenticator> TransactionExecutor<D, A> {
         notes: &[NoteId],
         tx_args: TransactionArgs,
     ) -> Result<ExecutedTransaction, TransactionExecutorError> {
-        let tx_inputs =
+        let mut tx_inputs =
             maybe_await!(self.data_store.get_transaction_inputs(account_id, block_ref, notes))
                 .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
 
+        // fetch the foreign accounts which are not yet part of the transaction inputs
+        let mut foreign_accounts = Vec::new();
+        for &foreign_account_id in tx_args.foreign_accounts() {
+            if tx_inputs.foreign_account(foreign_account_id).is_none() {
+                let foreign_account = maybe_await!(self
+                    .data_store
+                    .get_foreign_account_inputs(foreign_account_id, block_ref))
+                .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
+                foreign_accounts.push(foreign_account);
+            }
+        }
+        if !foreign_accounts.is_empty() {
+            tx_inputs = tx_inputs.with_foreign_accounts(foreign_accounts).map_err(|err| {
+                TransactionExecutorError::FetchTransactionInputsFailed(
+                    DataStoreError::InvalidTransactionInput(err),
+                )
+            })?;
+        }
+
+        validate_input_note_hints(&tx_inputs)?;
+
         let (stack_inputs, advice_inputs) =
-            TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, None);
+            TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, None)
+                .map_err(TransactionExecutorError::InvalidTransactionInput)?;
         let advice_recorder: RecAdviceProvider = advice_inputs.into();
 
         // load note script MAST into the MAST store
@@ -147,6 +209,29 @@ impl<D: DataStore, A: TransactionAuthenticator> TransactionExecutor<D, A> {
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Validates that the execution hints of the input notes allow them to be consumed at the
+/// reference block of the transaction.
+///
+/// Notes whose hint does not determine whether they can be consumed are not rejected.
+fn validate_input_note_hints(
+    tx_inputs: &TransactionInputs,
+) -> Result<(), TransactionExecutorError> {
+    let block_num = tx_inputs.block_header().block_num();
+    for input_note in tx_inputs.input_notes().iter() {
+        let note = input_note.note();
+        let execution_hint = note.metadata().execution_hint();
+        if execution_hint.can_be_consumed(block_num) == Some(false) {
+            return Err(TransactionExecutorError::InputNoteNotConsumable {
+                note_id: note.id(),
+                block_num,
+                execution_hint,
+            });
+        }
+    }
+
+    Ok(())
+}
+
 /// Creates a new [ExecutedTransaction] from the provided data.
 fn build_executed_transaction<A: TransactionAuthenticator>(
     tx_args: TransactionArgs,
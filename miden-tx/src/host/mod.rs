@@ -52,7 +52,7 @@ pub struct TransactionHost<A, T> {
     adv_provider: A,
 
     /// MAST store which contains the code required to execute the transaction.
-    mast_store: Rc<TransactionMastStore>,
+    mast_store: Arc<TransactionMastStore>,
 
     /// Account state changes accumulated during transaction execution.
     ///
@@ -94,7 +94,7 @@ impl<A: AdviceProvider, T: TransactionAuthenticator> TransactionHost<A, T> {
     pub fn new(
         account: AccountStub,
         adv_provider: A,
-        mast_store: Rc<TransactionMastStore>,
+        mast_store: Arc<TransactionMastStore>,
         authenticator: Option<Rc<T>>,
     ) -> Result<Self, TransactionHostError> {
         let proc_index_map =
@@ -9,7 +9,9 @@ extern crate std;
 pub use miden_objects::transaction::TransactionInputs;
 
 mod executor;
-pub use executor::{DataStore, TransactionExecutor, TransactionMastStore};
+#[cfg(feature = "std")]
+pub use executor::DirectoryMastBackend;
+pub use executor::{DataStore, MastForestBackend, TransactionExecutor, TransactionMastStore};
 
 pub mod host;
 pub use host::{TransactionHost, TransactionProgress};
@@ -1,8 +1,9 @@
-use alloc::{rc::Rc, vec::Vec};
+use alloc::{sync::Arc, vec::Vec};
 
-use miden_lib::transaction::TransactionKernel;
+use miden_lib::{notes::NoteLibraryRegistry, transaction::TransactionKernel};
 use miden_objects::{
     accounts::delta::AccountUpdateDetails,
+    assembly::Library,
     transaction::{OutputNote, ProvenTransaction, ProvenTransactionBuilder, TransactionWitness},
 };
 use miden_prover::prove;
@@ -18,7 +19,7 @@ use crate::executor::TransactionMastStore;
 /// or anything that can be converted into a [TransactionWitness], and returns a
 /// [ProvenTransaction].
 pub struct TransactionProver {
-    mast_store: Rc<TransactionMastStore>,
+    mast_store: Arc<TransactionMastStore>,
     proof_options: ProvingOptions,
 }
 
@@ -28,11 +29,39 @@ impl TransactionProver {
     /// Creates a new [TransactionProver] instance.
     pub fn new(proof_options: ProvingOptions) -> Self {
         Self {
-            mast_store: Rc::new(TransactionMastStore::new()),
+            mast_store: Arc::new(TransactionMastStore::new()),
             proof_options,
         }
     }
 
+    /// Replaces the MAST store of the created instance of [TransactionProver] with the provided
+    /// one.
+    ///
+    /// See [TransactionExecutor::with_mast_store()](crate::TransactionExecutor::with_mast_store).
+    pub fn with_mast_store(mut self, mast_store: Arc<TransactionMastStore>) -> Self {
+        self.mast_store = mast_store;
+        self
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Loads the code of the provided library into the MAST store of this prover.
+    ///
+    /// This is required for proving transactions which invoke procedures of user libraries, see
+    /// [TransactionExecutor::load_library()](crate::TransactionExecutor::load_library).
+    pub fn load_library(&mut self, library: &Library) {
+        self.mast_store.load_library(library);
+    }
+
+    /// Loads the code of all libraries of the provided [NoteLibraryRegistry] into the MAST store
+    /// of this prover.
+    ///
+    /// This is required for proving transactions which consume notes compiled via the registry.
+    pub fn load_note_libraries(&mut self, registry: &NoteLibraryRegistry) {
+        self.mast_store.load_note_libraries(registry);
+    }
+
     // TRANSACTION PROVER
     // --------------------------------------------------------------------------------------------
 
@@ -55,7 +84,8 @@ impl TransactionProver {
 
         // execute and prove
         let (stack_inputs, advice_inputs) =
-            TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, Some(advice_witness));
+            TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, Some(advice_witness))
+                .map_err(TransactionProverError::InvalidTransactionInput)?;
         let advice_provider: MemAdviceProvider = advice_inputs.into();
 
         // load the store with account/note/tx_script MASTs
@@ -8,13 +8,16 @@ use miden_objects::{
         SlotItem,
     },
     assets::{Asset, FungibleAsset, TokenSymbol},
-    block::{compute_tx_hash, Block, BlockAccountUpdate, BlockNoteIndex, BlockNoteTree, NoteBatch},
-    crypto::merkle::{Mmr, MmrError, PartialMmr, Smt},
+    block::{
+        compute_tx_hash, Block, BlockAccountUpdate, BlockNoteIndex, BlockNoteTree, NoteBatch,
+        NullifierTree,
+    },
+    crypto::merkle::{Mmr, MmrError, PartialMmr},
     notes::{Note, NoteId, NoteInclusionProof, NoteType, Nullifier},
     testing::account::AccountBuilder,
     transaction::{
-        ChainMmr, ExecutedTransaction, InputNote, InputNotes, OutputNote, ToInputNoteCommitments,
-        TransactionId, TransactionInputs,
+        ChainMmr, ExecutedTransaction, ForeignAccountInputs, InputNote, InputNotes, OutputNote,
+        ToInputNoteCommitments, TransactionId, TransactionInputs,
     },
     AccountError, BlockHeader, FieldElement, NoteError, ACCOUNT_TREE_DEPTH,
 };
@@ -184,8 +187,8 @@ pub struct MockChain {
     /// History of produced blocks.
     blocks: Vec<Block>,
 
-    /// Tree containing the latest `Nullifier`'s tree.
-    nullifiers: Smt,
+    /// Tree containing the nullifiers of all consumed notes.
+    nullifiers: NullifierTree,
 
     /// Tree containing the latest hash of each account.
     accounts: SimpleSmt<ACCOUNT_TREE_DEPTH>,
@@ -221,7 +224,7 @@ impl MockChain {
         Self {
             chain: Mmr::default(),
             blocks: vec![],
-            nullifiers: Smt::default(),
+            nullifiers: NullifierTree::default(),
             accounts: SimpleSmt::<ACCOUNT_TREE_DEPTH>::new().expect("depth too big for SimpleSmt"),
             pending_objects: PendingObjects::new(),
             available_notes: BTreeMap::new(),
@@ -452,6 +455,16 @@ impl MockChain {
         .unwrap()
     }
 
+    /// Returns the inputs required to read the state of the provided foreign account in a
+    /// transaction executed against the latest block of the chain.
+    ///
+    /// The account must have been added to the chain in one of the sealed blocks, and the provided
+    /// state must match the state committed to in the latest block.
+    pub fn get_foreign_account_inputs(&self, account: Account) -> ForeignAccountInputs {
+        let account_path = self.accounts.open(&account.id().into()).path;
+        ForeignAccountInputs::new(account, account_path)
+    }
+
     // MODIFIERS
     // =========================================================================================
 
@@ -493,9 +506,12 @@ impl MockChain {
         // - resetting the nullifier tree once defined at the protocol level.
         // - inserting only nullifier from transactions included in the batches, once the batch
         // kernel has been implemented.
-        for nullifier in self.pending_objects.created_nullifiers.iter() {
-            self.nullifiers.insert(nullifier.inner(), [block_num.into(), ZERO, ZERO, ZERO]);
-        }
+        self.nullifiers
+            .apply_block_nullifiers(
+                self.pending_objects.created_nullifiers.iter().copied(),
+                block_num,
+            )
+            .expect("failed to apply block nullifiers");
         let notes_tree = self.pending_objects.build_notes_tree();
 
         let version = 0;
@@ -591,7 +607,7 @@ impl MockChain {
     }
 
     /// Get a reference to the nullifier tree.
-    pub fn nullifiers(&self) -> &Smt {
+    pub fn nullifiers(&self) -> &NullifierTree {
         &self.nullifiers
     }
 
@@ -1,7 +1,7 @@
 // MOCK HOST
 // ================================================================================================
 
-use alloc::{rc::Rc, string::ToString, sync::Arc};
+use alloc::{string::ToString, sync::Arc};
 
 use miden_lib::transaction::TransactionEvent;
 use miden_objects::{
@@ -25,7 +25,7 @@ use crate::{host::AccountProcedureIndexMap, TransactionMastStore};
 pub struct MockHost {
     adv_provider: MemAdviceProvider,
     acct_procedure_index_map: AccountProcedureIndexMap,
-    mast_store: Rc<TransactionMastStore>,
+    mast_store: Arc<TransactionMastStore>,
 }
 
 impl MockHost {
@@ -33,7 +33,7 @@ impl MockHost {
     pub fn new(
         account: AccountStub,
         advice_inputs: AdviceInputs,
-        mast_store: Rc<TransactionMastStore>,
+        mast_store: Arc<TransactionMastStore>,
     ) -> Self {
         let adv_provider: MemAdviceProvider = advice_inputs.into();
         let proc_index_map =
@@ -26,7 +26,7 @@ use miden_objects::{
         prepare_word,
         storage::prepare_assets,
     },
-    transaction::{OutputNote, TransactionArgs, TransactionScript},
+    transaction::{ForeignAccountInputs, OutputNote, TransactionArgs, TransactionScript},
 };
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
@@ -48,6 +48,7 @@ pub struct TransactionContextBuilder {
     note_args: BTreeMap<NoteId, Word>,
     rng: ChaCha20Rng,
     mock_chain: Option<MockChain>,
+    foreign_accounts: Vec<Account>,
 }
 
 impl TransactionContextBuilder {
@@ -65,6 +66,7 @@ impl TransactionContextBuilder {
             advice_inputs: Default::default(),
             note_args: BTreeMap::new(),
             mock_chain: None,
+            foreign_accounts: Vec::new(),
         }
     }
 
@@ -89,6 +91,7 @@ impl TransactionContextBuilder {
             tx_script: None,
             note_args: BTreeMap::new(),
             mock_chain: None,
+            foreign_accounts: Vec::new(),
         }
     }
 
@@ -110,6 +113,7 @@ impl TransactionContextBuilder {
             tx_script: None,
             note_args: BTreeMap::new(),
             mock_chain: None,
+            foreign_accounts: Vec::new(),
         }
     }
 
@@ -135,6 +139,7 @@ impl TransactionContextBuilder {
             tx_script: None,
             note_args: BTreeMap::new(),
             mock_chain: None,
+            foreign_accounts: Vec::new(),
         }
     }
 
@@ -163,6 +168,15 @@ impl TransactionContextBuilder {
         self
     }
 
+    /// Sets the foreign accounts the state of which can be read by the transaction.
+    ///
+    /// If no mock chain is provided, the accounts are added to the genesis block of the default
+    /// mock chain. Otherwise, the accounts must already be part of the provided chain.
+    pub fn foreign_accounts(mut self, foreign_accounts: Vec<Account>) -> Self {
+        self.foreign_accounts = foreign_accounts;
+        self
+    }
+
     pub fn expected_notes(mut self, output_notes: Vec<OutputNote>) -> Self {
         let output_notes = output_notes.into_iter().filter_map(|n| match n {
             OutputNote::Full(note) => Some(note),
@@ -557,7 +571,10 @@ impl TransactionContextBuilder {
         let mut mock_chain = if let Some(mock_chain) = self.mock_chain {
             mock_chain
         } else {
-            MockChainBuilder::default().notes(self.input_notes.clone()).build()
+            MockChainBuilder::default()
+                .accounts(self.foreign_accounts.clone())
+                .notes(self.input_notes.clone())
+                .build()
         };
         for _ in 0..4 {
             mock_chain.seal_block(None);
@@ -578,6 +595,16 @@ impl TransactionContextBuilder {
             &input_note_ids,
         );
 
+        let foreign_accounts: Vec<ForeignAccountInputs> = self
+            .foreign_accounts
+            .into_iter()
+            .map(|account| mock_chain.get_foreign_account_inputs(account))
+            .collect();
+        tx_args.extend_foreign_accounts(foreign_accounts.iter().map(|f| f.account().id()));
+        let tx_inputs = tx_inputs
+            .with_foreign_accounts(foreign_accounts)
+            .expect("foreign accounts should be valid");
+
         tx_args.extend_expected_output_notes(self.expected_output_notes.clone());
 
         TransactionContext {
@@ -56,10 +56,11 @@ impl TransactionContext {
             &self.tx_inputs,
             &self.tx_args,
             Some(self.advice_inputs.clone()),
-        );
+        )
+        .expect("transaction inputs should be valid");
         advice_inputs.extend(self.advice_inputs.clone());
 
-        let mast_store = Rc::new(TransactionMastStore::new());
+        let mast_store = Arc::new(TransactionMastStore::new());
 
         let test_lib = TransactionKernel::kernel_as_library();
         mast_store.insert(Arc::new(test_lib.mast_forest().clone()));
@@ -1,4 +1,7 @@
-use miden_lib::transaction::memory::{ACCT_CODE_COMMITMENT_PTR, ACCT_NEW_CODE_COMMITMENT_PTR};
+use miden_lib::transaction::{
+    memory::{ACCT_CODE_COMMITMENT_PTR, ACCT_NEW_CODE_COMMITMENT_PTR},
+    TransactionKernel,
+};
 use miden_objects::{
     accounts::{
         account_id::testing::{
@@ -7,12 +10,13 @@ use miden_objects::{
             ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
             ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
         },
-        AccountId, AccountStorage, AccountType, StorageSlotType,
+        Account, AccountBuilder, AccountComponent, AccountId, AccountStorage, AccountType,
+        StorageSlot, StorageSlotType,
     },
     crypto::{hash::rpo::RpoDigest, merkle::LeafIndex},
     testing::{prepare_word, storage::STORAGE_LEAVES_2},
 };
-use vm_processor::{Felt, MemAdviceProvider};
+use vm_processor::{ExecutionError, Felt, MemAdviceProvider};
 
 use super::{ProcessState, StackInputs, Word, ONE, ZERO};
 use crate::{
@@ -341,6 +345,9 @@ fn test_get_storage_data_type() {
     }
 }
 
+/// Word placed below the inputs of storage map procedures to check that they preserve the stack.
+const PRESERVED_WORD: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+
 #[test]
 fn test_get_map_item() {
     let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
@@ -355,6 +362,9 @@ fn test_get_map_item() {
             begin
                 exec.prologue::prepare_transaction
 
+                # push a word which must be preserved below the result
+                push.{preserved_word}
+
                 # get the map item
                 push.{map_key}
                 push.{item_index}
@@ -363,6 +373,7 @@ fn test_get_map_item() {
             ",
             item_index = storage_item.index,
             map_key = prepare_word(&key),
+            preserved_word = prepare_word(&PRESERVED_WORD),
         );
         let process = tx_context.execute_code(&code).unwrap();
 
@@ -372,9 +383,9 @@ fn test_get_map_item() {
             "get_map_item result doesn't match the expected value",
         );
         assert_eq!(
-            Word::default(),
+            PRESERVED_WORD,
             process.get_stack_word(1),
-            "The rest of the stack must be cleared",
+            "The stack below the result must be preserved",
         );
         assert_eq!(
             Word::default(),
@@ -408,6 +419,9 @@ fn test_set_map_item() {
         begin
             exec.prologue::prepare_transaction
 
+            # push a word which must be preserved below the result
+            push.{preserved_word}
+
             # set the map item
             push.{new_value}
             push.{new_key}
@@ -422,6 +436,7 @@ fn test_set_map_item() {
         item_index = storage_item.index,
         new_key = prepare_word(&new_key),
         new_value = prepare_word(&new_value),
+        preserved_word = prepare_word(&PRESERVED_WORD),
     );
 
     let process = tx_context.execute_code(&code).unwrap();
@@ -439,6 +454,120 @@ fn test_set_map_item() {
         process.get_stack_word(1),
         "The original value stored in the map doesn't match the expected value",
     );
+    assert_eq!(
+        PRESERVED_WORD,
+        process.get_stack_word(3),
+        "The stack below the result must be preserved",
+    );
+}
+
+#[test]
+fn test_storage_offset_is_applied() {
+    let (account, proc_root) = account_with_offset_get_item();
+    let tx_context = TransactionContextBuilder::new(account).build();
+
+    // the procedure reads the first slot of its component, which is the third slot of the account
+    let code = format!(
+        "
+        use.kernel::prologue
+
+        begin
+            exec.prologue::prepare_transaction
+
+            push.0
+            call.{proc_root}
+
+            push.{item_value}
+            assert_eqw
+        end
+        ",
+        proc_root = proc_root,
+        item_value = prepare_word(&[ZERO, ZERO, ONE, ZERO]),
+    );
+
+    tx_context.execute_code(&code).unwrap();
+}
+
+#[test]
+fn test_storage_access_out_of_bounds() {
+    let (account, proc_root) = account_with_offset_get_item();
+    let tx_context = TransactionContextBuilder::new(account).build();
+
+    // the component of the procedure has a single slot
+    let code = format!(
+        "
+        use.kernel::prologue
+
+        begin
+            exec.prologue::prepare_transaction
+
+            push.1
+            call.{proc_root}
+        end
+        ",
+        proc_root = proc_root,
+    );
+
+    let process = tx_context.execute_code(&code);
+
+    // ERR_ACCOUNT_STORAGE_SLOT_INDEX_OUT_OF_BOUNDS
+    assert!(matches!(
+        process,
+        Err(ExecutionError::FailedAssertion { err_code: 0x00020068, .. })
+    ));
+}
+
+/// Returns an account assembled from two components, where the first component has two storage
+/// slots and the second one has a single slot, together with the root of the procedure of the
+/// second component which returns the value of the storage item with the provided index.
+fn account_with_offset_get_item() -> (Account, RpoDigest) {
+    let component_0 = AccountComponent::compile(
+        "export.foo push.1 drop end",
+        TransactionKernel::assembler_testing(),
+        vec![
+            StorageSlot::new_value([ONE, ZERO, ZERO, ZERO]),
+            StorageSlot::new_value([ZERO, ONE, ZERO, ZERO]),
+        ],
+    )
+    .unwrap()
+    .with_supports_all_types();
+
+    let component_1 = AccountComponent::compile(
+        "
+        use.miden::account
+
+        export.get_item_offset
+            exec.account::get_item
+            # => [VALUE, pad(15)]
+
+            # truncate the stack
+            movup.4 drop movup.4 drop movup.4 drop
+            # => [VALUE, pad(12)]
+        end
+        ",
+        TransactionKernel::assembler_testing(),
+        vec![StorageSlot::new_value([ZERO, ZERO, ONE, ZERO])],
+    )
+    .unwrap()
+    .with_supports_all_types();
+    let proc_root = component_1.procedure_roots().next().unwrap();
+
+    let (account, _) = AccountBuilder::new([5; 32])
+        .with_component(component_0)
+        .with_component(component_1)
+        .build()
+        .unwrap();
+
+    // turn the new account into an existing one
+    let account = Account::from_parts(
+        account.id(),
+        account.vault().clone(),
+        account.storage().clone(),
+        account.code().clone(),
+        ONE,
+    );
+
+    (account, proc_root)
 }
 
 // ACCOUNT VAULT TESTS
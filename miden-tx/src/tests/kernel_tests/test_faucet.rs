@@ -137,8 +137,6 @@ fn test_mint_fungible_asset_fails_saturate_max_amount() {
 // NON-FUNGIBLE FAUCET MINT TESTS
 // ================================================================================================
 
-// TODO: reenable once storage map support is implemented
-#[ignore]
 #[test]
 fn test_mint_non_fungible_asset_succeeds() {
     let tx_context = TransactionContextBuilder::with_non_fungible_faucet(
@@ -407,8 +405,6 @@ fn test_burn_fungible_asset_insufficient_input_amount() {
 // NON-FUNGIBLE FAUCET BURN TESTS
 // ================================================================================================
 
-// TODO: reenable once storage map support is implemented
-#[ignore]
 #[test]
 fn test_burn_non_fungible_asset_succeeds() {
     let tx_context = TransactionContextBuilder::with_non_fungible_faucet(
@@ -416,10 +412,13 @@ fn test_burn_non_fungible_asset_succeeds() {
         ONE,
         false,
     )
+    .with_mock_notes_preserved_with_account_vault_delta()
     .build();
 
-    let non_fungible_asset_burnt =
-        Asset::mock_non_fungible(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &[1, 2, 3]);
+    let non_fungible_asset_burnt = Asset::mock_non_fungible(
+        ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+        &NON_FUNGIBLE_ASSET_DATA_2,
+    );
 
     let code = format!(
         "
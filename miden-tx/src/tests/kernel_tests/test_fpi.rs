@@ -0,0 +1,392 @@
+use alloc::{collections::BTreeMap, sync::Arc};
+
+use miden_lib::transaction::{memory::ACCT_ID_PTR, TransactionKernel};
+use miden_objects::{
+    accounts::{
+        account_id::testing::{
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN_2,
+        },
+        Account, AccountCode, AccountId, AccountStorage, SlotItem,
+    },
+    assets::AssetVault,
+    testing::{prepare_word, storage::STORAGE_INDEX_0},
+    transaction::{TransactionArgs, TransactionInputs, TransactionScript},
+    Digest, TransactionInputError, Word,
+};
+use vm_processor::{AdviceMap, ExecutionError};
+
+use super::{Felt, ONE, ZERO};
+use crate::{
+    testing::{executor::CodeExecutor, MockHost, TransactionContextBuilder},
+    tests::kernel_tests::read_root_mem_value,
+    TransactionMastStore,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Value stored in the [STORAGE_INDEX_0] slot of the foreign accounts; differs from the value
+/// stored in the same slot of the native account.
+const FOREIGN_STORAGE_VALUE: Word = [Felt::new(9), Felt::new(8), Felt::new(7), Felt::new(6)];
+
+/// Foreign account code with a single procedure returning the value of the storage item with the
+/// provided index.
+const FOREIGN_GET_ITEM_CODE: &str = "
+    use.miden::account
+
+    export.get_item_foreign
+        # drop the root of this procedure
+        dropw
+        # => [index, pad(15)]
+
+        exec.account::get_item
+        # => [VALUE, pad(15)]
+
+        # truncate the stack
+        movup.4 drop movup.4 drop movup.4 drop
+        # => [VALUE, pad(12)]
+    end
+";
+
+/// Foreign account code with a single procedure attempting to update the storage item with the
+/// provided index.
+const FOREIGN_SET_ITEM_CODE: &str = "
+    use.miden::account
+
+    export.set_item_foreign
+        # drop the root of this procedure
+        dropw
+        # => [index, VALUE, pad(11)]
+
+        exec.account::set_item
+        # => [OLD_VALUE, pad(12)]
+    end
+";
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns an account with [FOREIGN_STORAGE_VALUE] stored in the [STORAGE_INDEX_0] slot and the
+/// provided code, together with the root of the single procedure exported by the code.
+fn build_foreign_account(account_id: u64, code: &str) -> (Account, Digest) {
+    let code = AccountCode::compile(code, TransactionKernel::assembler_testing()).unwrap();
+    let proc_root = *code.procedures()[0].mast_root();
+    let account = Account::from_parts(
+        AccountId::try_from(account_id).unwrap(),
+        AssetVault::default(),
+        AccountStorage::new(
+            vec![SlotItem::new_value(STORAGE_INDEX_0, 0, FOREIGN_STORAGE_VALUE)],
+            BTreeMap::new(),
+        )
+        .unwrap(),
+        code,
+        ONE,
+    );
+
+    (account, proc_root)
+}
+
+// FOREIGN PROCEDURE INVOCATION TESTS
+// ================================================================================================
+
+#[test]
+fn test_fpi_get_item() {
+    let (foreign_account, proc_root) = build_foreign_account(
+        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        FOREIGN_GET_ITEM_CODE,
+    );
+    let foreign_account_id = foreign_account.id();
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .foreign_accounts(vec![foreign_account])
+        .build();
+
+    let code = format!(
+        "
+        use.kernel::prologue
+        use.miden::tx
+
+        begin
+            exec.prologue::prepare_transaction
+
+            # push the inputs of the foreign procedure
+            padw padw push.0.0.0
+            push.{storage_index}
+            # => [storage_index, pad(11)]
+
+            # execute the foreign procedure
+            push.{proc_root}
+            push.{foreign_account_id}
+            exec.tx::execute_foreign_procedure
+            # => [VALUE, pad(12)]
+
+            # assert the value of the foreign storage item is correct
+            push.{storage_value}
+            assert_eqw
+        end
+        ",
+        storage_index = STORAGE_INDEX_0,
+        proc_root = prepare_word(&proc_root),
+        foreign_account_id = foreign_account_id,
+        storage_value = prepare_word(&FOREIGN_STORAGE_VALUE),
+    );
+
+    let process = tx_context.execute_code(&code).unwrap();
+
+    assert_eq!(
+        read_root_mem_value(&process, ACCT_ID_PTR),
+        [tx_context.account().id().into(), ZERO, ZERO, ZERO],
+        "the native account must be restored after the foreign procedure returns",
+    );
+}
+
+#[test]
+fn test_fpi_state_is_read_only() {
+    let (foreign_account, proc_root) = build_foreign_account(
+        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        FOREIGN_SET_ITEM_CODE,
+    );
+    let foreign_account_id = foreign_account.id();
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .foreign_accounts(vec![foreign_account])
+        .build();
+
+    let code = format!(
+        "
+        use.kernel::prologue
+        use.miden::tx
+
+        begin
+            exec.prologue::prepare_transaction
+
+            # push the inputs of the foreign procedure
+            padw push.0.0.0
+            push.1.2.3.4
+            push.{storage_index}
+            # => [storage_index, VALUE, pad(7)]
+
+            # execute the foreign procedure
+            push.{proc_root}
+            push.{foreign_account_id}
+            exec.tx::execute_foreign_procedure
+        end
+        ",
+        storage_index = STORAGE_INDEX_0,
+        proc_root = prepare_word(&proc_root),
+        foreign_account_id = foreign_account_id,
+    );
+
+    let process = tx_context.execute_code(&code);
+
+    // ERR_FOREIGN_ACCT_STATE_IS_READ_ONLY
+    assert!(matches!(
+        process,
+        Err(ExecutionError::FailedAssertion { err_code: 0x00020066, .. })
+    ));
+}
+
+#[test]
+fn test_fpi_procedure_not_in_foreign_account() {
+    let (foreign_account, _) = build_foreign_account(
+        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        FOREIGN_GET_ITEM_CODE,
+    );
+    let foreign_account_id = foreign_account.id();
+
+    // the procedure is part of another account which is also available to the transaction
+    let (other_account, other_proc_root) = build_foreign_account(
+        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN_2,
+        FOREIGN_SET_ITEM_CODE,
+    );
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .foreign_accounts(vec![foreign_account, other_account])
+        .build();
+
+    let code = format!(
+        "
+        use.kernel::prologue
+        use.miden::tx
+
+        begin
+            exec.prologue::prepare_transaction
+
+            padw padw padw
+            push.{proc_root}
+            push.{foreign_account_id}
+            exec.tx::execute_foreign_procedure
+        end
+        ",
+        proc_root = prepare_word(&other_proc_root),
+        foreign_account_id = foreign_account_id,
+    );
+
+    let process = tx_context.execute_code(&code);
+
+    // ERR_FOREIGN_PROC_NOT_PART_OF_ACCOUNT_CODE
+    assert!(matches!(
+        process,
+        Err(ExecutionError::FailedAssertion { err_code: 0x00020065, .. })
+    ));
+}
+
+#[test]
+fn test_fpi_unknown_foreign_account() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+
+    let code = format!(
+        "
+        use.kernel::prologue
+        use.miden::tx
+
+        begin
+            exec.prologue::prepare_transaction
+
+            padw padw padw
+            padw
+            push.{foreign_account_id}
+            exec.tx::execute_foreign_procedure
+        end
+        ",
+        foreign_account_id = Felt::new(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN),
+    );
+
+    let process = tx_context.execute_code(&code);
+
+    // ERR_FOREIGN_ACCT_NOT_FOUND
+    assert!(matches!(
+        process,
+        Err(ExecutionError::FailedAssertion { err_code: 0x00020062, .. })
+    ));
+}
+
+#[test]
+fn test_fpi_duplicate_foreign_account() {
+    let (foreign_account, _) = build_foreign_account(
+        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        FOREIGN_GET_ITEM_CODE,
+    );
+    let foreign_account_id = foreign_account.id();
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .foreign_accounts(vec![foreign_account])
+        .build();
+
+    // providing the same foreign account twice should be rejected when building the inputs
+    let foreign_account_inputs = tx_context.tx_inputs().foreign_accounts()[0].clone();
+    let (account, account_seed, block_header, block_chain, input_notes, _) =
+        tx_context.tx_inputs().clone().into_parts();
+    let result =
+        TransactionInputs::new(account, account_seed, block_header, block_chain, input_notes)
+            .unwrap()
+            .with_foreign_accounts([foreign_account_inputs.clone(), foreign_account_inputs]);
+    assert!(matches!(
+        result,
+        Err(TransactionInputError::DuplicateForeignAccount(account_id))
+            if account_id == foreign_account_id
+    ));
+
+    // bypass the validation of the inputs by duplicating the foreign account data on the advice
+    // stack, and make sure the prologue rejects it
+    let (stack_inputs, mut advice_inputs) =
+        TransactionKernel::prepare_inputs(tx_context.tx_inputs(), tx_context.tx_args(), None)
+            .unwrap();
+
+    let foreign_account = tx_context.tx_inputs().foreign_accounts()[0].account();
+    let foreign_account_header =
+        [ONE, foreign_account_id.into(), ZERO, ZERO, foreign_account.nonce()];
+    let num_foreign_accounts_idx = advice_inputs
+        .stack
+        .windows(foreign_account_header.len())
+        .position(|window| window == foreign_account_header)
+        .expect("foreign account data should be on the advice stack");
+    let foreign_account_data_idx = num_foreign_accounts_idx + 1;
+    let foreign_account_data =
+        advice_inputs.stack[foreign_account_data_idx..foreign_account_data_idx + 16].to_vec();
+    advice_inputs.stack[num_foreign_accounts_idx] = Felt::new(2);
+    advice_inputs
+        .stack
+        .splice(foreign_account_data_idx..foreign_account_data_idx, foreign_account_data);
+
+    let mast_store = Arc::new(TransactionMastStore::new());
+    mast_store.insert(Arc::new(TransactionKernel::kernel_as_library().mast_forest().clone()));
+
+    let program = TransactionKernel::assembler_testing()
+        .assemble_program(
+            "
+            use.kernel::prologue
+
+            begin
+                exec.prologue::prepare_transaction
+            end
+            ",
+        )
+        .unwrap();
+    mast_store.insert(Arc::new(program.mast_forest().clone()));
+
+    let process =
+        CodeExecutor::new(MockHost::new(tx_context.account().into(), advice_inputs, mast_store))
+            .stack_inputs(stack_inputs)
+            .execute_program(program);
+
+    // ERR_PROLOGUE_DUPLICATE_FOREIGN_ACCT
+    assert!(matches!(
+        process,
+        Err(ExecutionError::FailedAssertion { err_code: 0x0002006e, .. })
+    ));
+}
+
+#[test]
+fn test_fpi_execute_transaction() {
+    let (foreign_account, proc_root) = build_foreign_account(
+        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        FOREIGN_GET_ITEM_CODE,
+    );
+    let foreign_account_id = foreign_account.id();
+
+    let mut tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .foreign_accounts(vec![foreign_account])
+        .build();
+
+    let tx_script_src = format!(
+        "
+        use.miden::tx
+
+        begin
+            # execute the foreign procedure
+            padw padw push.0.0.0
+            push.{storage_index}
+            push.{proc_root}
+            push.{foreign_account_id}
+            exec.tx::execute_foreign_procedure
+            # => [VALUE, pad(12)]
+
+            # assert the value of the foreign storage item is correct
+            push.{storage_value}
+            assert_eqw
+            dropw dropw dropw
+        end
+        ",
+        storage_index = STORAGE_INDEX_0,
+        proc_root = prepare_word(&proc_root),
+        foreign_account_id = foreign_account_id,
+        storage_value = prepare_word(&FOREIGN_STORAGE_VALUE),
+    );
+    let tx_script =
+        TransactionScript::compile(tx_script_src, [], TransactionKernel::assembler_testing())
+            .unwrap();
+
+    let mut tx_args = TransactionArgs::new(Some(tx_script), None, AdviceMap::default());
+    tx_args.extend_foreign_accounts([foreign_account_id]);
+
+    tx_context.set_tx_args(tx_args);
+    let executed_transaction = tx_context.execute();
+
+    assert!(
+        executed_transaction.is_ok(),
+        "Transaction execution failed {:?}",
+        executed_transaction,
+    );
+}
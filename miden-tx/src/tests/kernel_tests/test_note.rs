@@ -1,10 +1,16 @@
-use alloc::{collections::BTreeMap, string::String};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
 
-use miden_lib::transaction::memory::CURRENT_INPUT_NOTE_PTR;
+use miden_lib::transaction::{memory::CURRENT_INPUT_NOTE_PTR, TransactionKernel};
 use miden_objects::{
-    notes::Note, testing::prepare_word, transaction::TransactionArgs, Hasher, WORD_SIZE,
+    accounts::{account_id::testing::ACCOUNT_ID_SENDER, AccountId},
+    notes::{Note, NoteExecutionHint},
+    testing::{notes::NoteBuilder, prepare_word},
+    transaction::TransactionArgs,
+    Hasher, WORD_SIZE,
 };
-use vm_processor::{ProcessState, EMPTY_WORD, ONE};
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use vm_processor::{ExecutionError, ProcessState, EMPTY_WORD, ONE};
 
 use super::{Felt, Process, ZERO};
 use crate::{
@@ -292,6 +298,123 @@ fn test_get_inputs() {
     tx_context.execute_code(&code).unwrap();
 }
 
+#[test]
+fn test_stream_inputs() {
+    let inputs: Vec<Felt> = (0..300).map(|i| Felt::new(i * 3 + 1)).collect();
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let note = NoteBuilder::new(sender, ChaCha20Rng::from_seed([0; 32]))
+        .note_inputs(inputs.clone())
+        .unwrap()
+        .build(&TransactionKernel::assembler_testing())
+        .unwrap();
+    assert!(note.inputs().is_streamed());
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .input_notes(vec![note])
+        .build();
+
+    let stream_code = |num_chunks: usize| {
+        format!(
+            "
+            use.kernel::prologue
+            use.kernel::note->note_internal
+            use.miden::note
+
+            begin
+                exec.prologue::prepare_transaction
+                exec.note_internal::prepare_note dropw dropw
+                # => []
+
+                push.{DEST_PTR} exec.note::start_inputs_stream
+                # => [C, B, A, dest_ptr, num_inputs]
+
+                movup.13 eq.{num_inputs} assert
+                # => [C, B, A, dest_ptr]
+
+                repeat.{num_chunks}
+                    exec.note::read_inputs_chunk
+                end
+                # => [C, B, A, dest_ptr']
+
+                movup.12 drop exec.note::finish_inputs_stream
+                # => []
+            end
+            ",
+            DEST_PTR = 100000000,
+            num_inputs = inputs.len(),
+        )
+    };
+
+    // all inputs are read into memory and verified against the inputs commitment
+    let process = tx_context.execute_code(&stream_code(inputs.len().div_ceil(8))).unwrap();
+    for (i, input_word) in inputs.chunks(WORD_SIZE).enumerate() {
+        let mut expected = EMPTY_WORD;
+        expected[..input_word.len()].copy_from_slice(input_word);
+        assert_eq!(read_root_mem_value(&process, 100000000 + i as u32), expected);
+    }
+
+    // the stream cannot be finished before all inputs are read
+    let process = tx_context.execute_code(&stream_code(inputs.len() / 8));
+    assert!(matches!(
+        process,
+        Err(ExecutionError::FailedAssertion { err_code: 0x00020040, .. })
+    ));
+}
+
+#[test]
+fn test_assert_execution_hint() {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let block_num = TransactionContextBuilder::with_standard_account(ONE)
+        .build()
+        .tx_inputs()
+        .block_header()
+        .block_num();
+    let slot_offset = ((block_num % 16) / 2) as u8;
+
+    let code = "
+        use.kernel::prologue
+        use.kernel::note->note_internal
+        use.miden::note
+
+        begin
+            exec.prologue::prepare_transaction
+            exec.note_internal::prepare_note
+            dropw dropw dropw dropw
+            exec.note::assert_execution_hint
+        end
+        ";
+
+    for (execution_hint, can_be_consumed) in [
+        (NoteExecutionHint::none(), true),
+        (NoteExecutionHint::always(), true),
+        (NoteExecutionHint::after_block(block_num), true),
+        (NoteExecutionHint::after_block(block_num + 1), false),
+        (NoteExecutionHint::on_block_slot(4, 1, slot_offset), true),
+        (NoteExecutionHint::on_block_slot(4, 1, (slot_offset + 1) % 8), false),
+        (NoteExecutionHint::on_block_slot(40, 32, 0), true),
+    ] {
+        assert_eq!(execution_hint.can_be_consumed(block_num).unwrap_or(true), can_be_consumed);
+
+        let note = NoteBuilder::new(sender, ChaCha20Rng::from_seed([0; 32]))
+            .note_execution_hint(execution_hint)
+            .build(&TransactionKernel::assembler_testing())
+            .unwrap();
+        let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+            .input_notes(vec![note])
+            .build();
+
+        let process = tx_context.execute_code(code);
+        if can_be_consumed {
+            process.unwrap();
+        } else {
+            assert!(matches!(
+                process,
+                Err(ExecutionError::FailedAssertion { err_code: 0x0002006b, .. })
+            ));
+        }
+    }
+}
+
 #[test]
 fn test_note_setup() {
     let tx_context = TransactionContextBuilder::with_standard_account(ONE)
@@ -1,15 +1,17 @@
-use alloc::{collections::BTreeMap, rc::Rc, string::String, vec::Vec};
+use alloc::{collections::BTreeMap, string::String, sync::Arc, vec::Vec};
+use core::sync::atomic::{AtomicUsize, Ordering};
 
-use miden_lib::transaction::TransactionKernel;
+use miden_lib::{notes::NoteLibraryRegistry, transaction::TransactionKernel};
 use miden_objects::{
     accounts::{
         account_id::testing::{
             ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
             ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
-            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
         },
-        AccountCode,
+        AccountCode, AccountId,
     },
+    assembly::{mast::MastForest, Library},
     assets::{Asset, FungibleAsset},
     notes::{
         Note, NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteHeader, NoteId, NoteInputs,
@@ -22,7 +24,7 @@ use miden_objects::{
             ACCOUNT_SET_MAP_ITEM_MAST_ROOT,
         },
         constants::{FUNGIBLE_ASSET_AMOUNT, NON_FUNGIBLE_ASSET_DATA},
-        notes::DEFAULT_NOTE_CODE,
+        notes::{NoteBuilder, DEFAULT_NOTE_CODE},
         prepare_word,
         storage::{STORAGE_INDEX_0, STORAGE_INDEX_2},
     },
@@ -30,13 +32,22 @@ use miden_objects::{
     Felt, Word, MIN_PROOF_SECURITY_LEVEL,
 };
 use miden_prover::ProvingOptions;
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use tempfile::tempdir;
 use vm_processor::{
     utils::{Deserializable, Serializable},
-    Digest, MemAdviceProvider, ONE,
+    Digest, MastForestStore, MemAdviceProvider, ONE, ZERO,
 };
 
-use super::{TransactionExecutor, TransactionHost, TransactionProver, TransactionVerifier};
-use crate::{testing::TransactionContextBuilder, TransactionMastStore};
+use super::{
+    TransactionExecutor, TransactionExecutorError, TransactionHost, TransactionProver,
+    TransactionVerifier,
+};
+use crate::{
+    testing::TransactionContextBuilder, DirectoryMastBackend, MastForestBackend,
+    TransactionMastStore,
+};
 
 mod kernel_tests;
 
@@ -73,11 +84,12 @@ fn transaction_executor_witness() {
         tx_inputs,
         tx_args,
         Some(executed_transaction.advice_witness().clone()),
-    );
+    )
+    .unwrap();
     let mem_advice_provider: MemAdviceProvider = advice_inputs.into();
 
     // load account/note/tx_script MAST to the mast_store
-    let mast_store = Rc::new(TransactionMastStore::new());
+    let mast_store = Arc::new(TransactionMastStore::new());
     mast_store.load_transaction_code(tx_inputs, tx_args);
 
     let mut host: TransactionHost<MemAdviceProvider, ()> =
@@ -973,3 +985,244 @@ fn test_tx_script() {
         executed_transaction,
     );
 }
+
+#[test]
+fn test_note_script_library() {
+    let library = NoteLibraryRegistry::compile_library(
+        "notes::common",
+        "
+        use.miden::note
+
+        export.assert_single_input
+            push.0 exec.note::get_inputs
+            eq.1 assert drop
+        end
+        ",
+    );
+
+    let mut registry = NoteLibraryRegistry::new();
+    registry.register(library).unwrap();
+
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let note = NoteBuilder::new(sender, ChaCha20Rng::from_seed([0; 32]))
+        .note_inputs([ONE])
+        .unwrap()
+        .code("use.notes::common begin exec.common::assert_single_input end")
+        .build(&registry.assembler())
+        .unwrap();
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .input_notes(vec![note])
+        .build();
+    let account_id = tx_context.tx_inputs().account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    // the note cannot be consumed until the library is loaded into the executor
+    let mut executor: TransactionExecutor<_, ()> =
+        TransactionExecutor::new(tx_context.clone(), None);
+    let result = executor.execute_transaction(
+        account_id,
+        block_ref,
+        &note_ids,
+        tx_context.tx_args().clone(),
+    );
+    assert!(result.is_err());
+
+    executor.load_note_libraries(&registry);
+    let result = executor.execute_transaction(
+        account_id,
+        block_ref,
+        &note_ids,
+        tx_context.tx_args().clone(),
+    );
+    assert!(result.is_ok(), "Transaction execution failed {:?}", result);
+}
+
+#[test]
+fn test_mast_store_backend() {
+    let library =
+        NoteLibraryRegistry::compile_library("notes::common", "export.foo push.1 drop end");
+
+    // the library is only available via the backend of the store
+    let backend_dir = tempdir().unwrap();
+    let backend = DirectoryMastBackend::new(backend_dir.path());
+    backend.insert_library(&library).unwrap();
+    let mast_store = Arc::new(TransactionMastStore::new().with_backend(backend));
+
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let note = NoteBuilder::new(sender, ChaCha20Rng::from_seed([0; 32]))
+        .code("use.notes::common begin exec.common::foo end")
+        .build(&TransactionKernel::assembler().with_library(&library).unwrap())
+        .unwrap();
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .input_notes(vec![note])
+        .build();
+    let account_id = tx_context.tx_inputs().account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    // executors sharing the store load the library lazily from the backend
+    for _ in 0..2 {
+        let executor: TransactionExecutor<_, ()> =
+            TransactionExecutor::new(tx_context.clone(), None).with_mast_store(mast_store.clone());
+        let result = executor.execute_transaction(
+            account_id,
+            block_ref,
+            &note_ids,
+            tx_context.tx_args().clone(),
+        );
+        assert!(result.is_ok(), "Transaction execution failed {:?}", result);
+    }
+    let _prover = TransactionProver::new(ProvingOptions::default()).with_mast_store(mast_store);
+
+    // the note cannot be consumed with a store which does not have the library
+    let executor: TransactionExecutor<_, ()> = TransactionExecutor::new(tx_context.clone(), None);
+    let result = executor.execute_transaction(
+        account_id,
+        block_ref,
+        &note_ids,
+        tx_context.tx_args().clone(),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_directory_mast_backend() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<TransactionMastStore>();
+
+    let foo = NoteLibraryRegistry::compile_library("notes::common", "export.foo push.1 drop end");
+    let bar = NoteLibraryRegistry::compile_library("notes::common", "export.bar push.2 drop end");
+    let foo_root = foo.mast_forest().local_procedure_digests().next().unwrap();
+    let bar_root = bar.mast_forest().local_procedure_digests().next().unwrap();
+
+    let temp_dir = tempdir().unwrap();
+    let backend_dir = temp_dir.path();
+    let backend = DirectoryMastBackend::new(backend_dir);
+    backend.insert_library(&foo).unwrap();
+    backend.insert_library(&bar).unwrap();
+    assert!(backend.get(&foo_root).is_some());
+
+    // temporary files are renamed once written
+    let forests = std::fs::read_dir(backend_dir.join("forests")).unwrap();
+    assert!(forests
+        .map(|entry| entry.unwrap().path())
+        .all(|path| path.extension().is_none()));
+
+    // the index cannot point outside of the forests directory
+    let foo_index = backend_dir.join("roots").join(foo_root.to_hex());
+    let foo_forest_hash = std::fs::read_to_string(&foo_index).unwrap();
+    std::fs::copy(backend_dir.join("forests").join(&foo_forest_hash), backend_dir.join("outside"))
+        .unwrap();
+    std::fs::write(&foo_index, "../outside").unwrap();
+    assert!(backend.get(&foo_root).is_none());
+
+    // forests which do not contain the requested procedure are rejected
+    let bar_forest_hash = std::fs::read(backend_dir.join("roots").join(bar_root.to_hex())).unwrap();
+    std::fs::write(&foo_index, bar_forest_hash).unwrap();
+    assert!(backend.get(&foo_root).is_none());
+}
+
+#[test]
+fn test_mast_store_caches_backend_misses() {
+    struct CountingBackend(Library, Arc<AtomicUsize>);
+
+    impl MastForestBackend for CountingBackend {
+        fn get(&self, procedure_root: &Digest) -> Option<MastForest> {
+            self.1.fetch_add(1, Ordering::Relaxed);
+            let mast_forest = self.0.mast_forest();
+            mast_forest.find_procedure_root(*procedure_root).map(|_| mast_forest.clone())
+        }
+    }
+
+    let library =
+        NoteLibraryRegistry::compile_library("notes::common", "export.foo push.1 drop end");
+    let root = library.mast_forest().local_procedure_digests().next().unwrap();
+    let queries = Arc::new(AtomicUsize::new(0));
+    let mast_store =
+        TransactionMastStore::new().with_backend(CountingBackend(library.clone(), queries.clone()));
+
+    // procedures found in the backend are kept in memory
+    assert!(mast_store.get(&root).is_some());
+    assert!(mast_store.get(&root).is_some());
+    assert_eq!(queries.load(Ordering::Relaxed), 1);
+
+    // procedures missing from the backend are queried only once
+    let missing = Digest::default();
+    assert!(mast_store.get(&missing).is_none());
+    assert!(mast_store.get(&missing).is_none());
+    assert_eq!(queries.load(Ordering::Relaxed), 2);
+
+    // procedures of the default libraries are never looked up in the backend
+    let kernel_root = TransactionKernel::kernel().mast_forest().local_procedure_digests().next();
+    assert!(mast_store.get(&kernel_root.unwrap()).is_some());
+    assert_eq!(queries.load(Ordering::Relaxed), 2);
+
+    // the least recently missed procedures are forgotten once the limit is exceeded
+    for value in 1..=TransactionMastStore::MAX_MISSING_PROCEDURES as u64 {
+        assert!(mast_store.get(&Digest::from([Felt::new(value), ZERO, ZERO, ZERO])).is_none());
+    }
+    let num_queries = queries.load(Ordering::Relaxed);
+    assert!(mast_store.get(&missing).is_none());
+    assert_eq!(queries.load(Ordering::Relaxed), num_queries + 1);
+
+    // concurrent requests for the same procedure query the backend only once
+    let queries = Arc::new(AtomicUsize::new(0));
+    let mast_store = Arc::new(
+        TransactionMastStore::new().with_backend(CountingBackend(library, queries.clone())),
+    );
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let mast_store = mast_store.clone();
+            std::thread::spawn(move || mast_store.get(&root).is_some())
+        })
+        .collect();
+    assert!(handles.into_iter().all(|handle| handle.join().unwrap()));
+    assert_eq!(queries.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn test_input_note_execution_hint_is_enforced() {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let block_num = TransactionContextBuilder::with_standard_account(ONE)
+        .build()
+        .tx_inputs()
+        .block_header()
+        .block_num();
+
+    let build_tx_context = |execution_hint: NoteExecutionHint| {
+        let note = NoteBuilder::new(sender, ChaCha20Rng::from_seed([0; 32]))
+            .note_execution_hint(execution_hint)
+            .code("use.miden::note begin exec.note::assert_execution_hint end")
+            .build(&TransactionKernel::assembler_testing())
+            .unwrap();
+
+        TransactionContextBuilder::with_standard_account(ONE)
+            .input_notes(vec![note])
+            .build()
+    };
+
+    // notes which can be consumed at the reference block are executed
+    let result = build_tx_context(NoteExecutionHint::after_block(block_num)).execute();
+    assert!(result.is_ok(), "Transaction execution failed {:?}", result);
+
+    // notes which cannot be consumed at the reference block are rejected before execution
+    let execution_hint = NoteExecutionHint::after_block(block_num + 1);
+    let tx_context = build_tx_context(execution_hint);
+    let note_id = tx_context.input_notes().get_note(0).id();
+    assert_eq!(
+        tx_context.execute().unwrap_err(),
+        TransactionExecutorError::InputNoteNotConsumable { note_id, block_num, execution_hint }
+    );
+}
@@ -15,9 +15,18 @@ use miden_objects::{
     Felt, Word, ZERO,
 };
 use miden_prover::ProvingOptions;
-use miden_tx::{TransactionProver, TransactionVerifier, TransactionVerifierError};
+use miden_tx::{
+    TransactionExecutorError, TransactionProver, TransactionVerifier, TransactionVerifierError,
+};
 use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
-use vm_processor::utils::Deserializable;
+use vm_processor::{utils::Deserializable, ExecutionError};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Error code of the transaction epilogue for transactions which change the state of the account
+/// without incrementing its nonce, see `kernels/transaction/lib/epilogue.masm`.
+pub const ERR_NONCE_DID_NOT_INCREASE: u32 = 0x00020009;
 
 // HELPER FUNCTIONS
 // ================================================================================================
@@ -123,3 +132,28 @@ pub fn build_tx_args_from_script(script_source: &str) -> TransactionArgs {
         TransactionScript::compile(script_source, [], TransactionKernel::assembler()).unwrap();
     TransactionArgs::with_tx_script(tx_script)
 }
+
+#[cfg(test)]
+pub fn assert_execution_failed(
+    result: Result<ExecutedTransaction, TransactionExecutorError>,
+    expected_err_code: u32,
+) {
+    match result {
+        Err(TransactionExecutorError::ExecuteTransactionProgramFailed(
+            ExecutionError::FailedAssertion { err_code, .. },
+        )) => assert_eq!(err_code, expected_err_code),
+        _ => panic!("expected the transaction to fail with error code {expected_err_code:#x}"),
+    }
+}
+
+/// Asserts that the transaction failed because the authenticator could not provide a signature
+/// for one of the keys authenticating it.
+#[cfg(test)]
+pub fn assert_signature_missing(result: Result<ExecutedTransaction, TransactionExecutorError>) {
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::ExecuteTransactionProgramFailed(
+            ExecutionError::FailedSignatureGeneration(_)
+        ))
+    ));
+}
@@ -1,28 +1,68 @@
 extern crate alloc;
 
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, rc::Rc};
 
-use miden_lib::transaction::{memory::FAUCET_STORAGE_DATA_SLOT, TransactionKernel};
+use miden_lib::{
+    accounts::faucets::{
+        build_freeze_minting_tx_script, build_lower_max_supply_tx_script,
+        build_rotate_owner_key_tx_script,
+    },
+    transaction::{memory::FAUCET_STORAGE_DATA_SLOT, TransactionKernel},
+    AuthScheme,
+};
 use miden_objects::{
     accounts::{
-        account_id::testing::ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, Account, AccountCode, AccountId,
-        AccountStorage, SlotItem,
+        account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN,
+            ACCOUNT_ID_SENDER,
+        },
+        Account, AccountCode, AccountId, AccountStorage, AuthSecretKey, SlotItem, StorageMap,
+    },
+    assets::{Asset, AssetVault, FungibleAsset, NonFungibleAsset},
+    crypto::dsa::rpo_falcon512::SecretKey,
+    notes::{
+        Note, NoteAssets, NoteExecutionHint, NoteId, NoteInputs, NoteMetadata, NoteRecipient,
+        NoteScript, NoteTag, NoteType,
     },
-    assets::{Asset, AssetVault, FungibleAsset},
-    notes::{NoteAssets, NoteExecutionHint, NoteId, NoteMetadata, NoteTag, NoteType},
     testing::prepare_word,
-    Felt, Word,
+    transaction::TransactionArgs,
+    Digest, Felt, Word, EMPTY_WORD, ONE, ZERO,
 };
-use miden_tx::{testing::TransactionContextBuilder, TransactionExecutor};
+use miden_tx::{
+    auth::BasicAuthenticator, testing::TransactionContextBuilder, TransactionExecutor,
+    TransactionExecutorError,
+};
+use rand::rngs::StdRng;
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+use vm_processor::ExecutionError;
 
 use crate::{
+    assert_execution_failed, assert_signature_missing, build_default_auth_script,
     build_tx_args_from_script, get_new_pk_and_authenticator,
     get_note_with_fungible_asset_and_script, prove_and_verify_transaction,
+    ERR_NONCE_DID_NOT_INCREASE,
 };
 
+// Error codes of the basic fungible faucet, see `faucets/basic_fungible.masm`.
+const ERR_BASIC_FUNGIBLE_MINTING_FROZEN: u32 = 0x00020054;
+const ERR_BASIC_FUNGIBLE_MAX_SUPPLY_NOT_LOWERED: u32 = 0x00020055;
+const ERR_BASIC_FUNGIBLE_MAX_SUPPLY_BELOW_TOTAL_ISSUANCE: u32 = 0x00020056;
+
+// Error code of the basic non-fungible faucet, see `faucets/basic_non_fungible.masm`.
+const ERR_BASIC_NON_FUNGIBLE_MAX_ITEMS_EXCEEDED: u32 = 0x00020053;
+
 const FUNGIBLE_FAUCET_SOURCE: &str = "
 export.::miden::contracts::faucets::basic_fungible::distribute
 export.::miden::contracts::faucets::basic_fungible::burn
+export.::miden::contracts::faucets::basic_fungible::rotate_owner_key
+export.::miden::contracts::faucets::basic_fungible::lower_max_supply
+export.::miden::contracts::faucets::basic_fungible::freeze_minting
+export.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+";
+
+const NON_FUNGIBLE_FAUCET_SOURCE: &str = "
+export.::miden::contracts::faucets::basic_non_fungible::distribute_nft
+export.::miden::contracts::faucets::basic_non_fungible::burn_nft
 export.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
 ";
 
@@ -228,9 +268,470 @@ fn prove_faucet_contract_burn_fungible_asset_succeeds() {
     assert_eq!(executed_transaction.input_notes().get_note(0).id(), note.id());
 }
 
+// TESTS FAUCET OWNER OPERATIONS
+// ================================================================================================
+
+#[test]
+fn faucet_contract_rotate_owner_key_succeeds() {
+    let (faucet_pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let mut faucet_account =
+        get_faucet_account_with_max_supply_and_total_issuance(faucet_pub_key, 200, None);
+
+    let new_sec_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([1_u8; 32]));
+    let new_pub_key: Word = new_sec_key.public_key().into();
+    let new_falcon_auth = Rc::new(BasicAuthenticator::<StdRng>::new(&[(
+        new_pub_key,
+        AuthSecretKey::RpoFalcon512(new_sec_key.clone()),
+    )]));
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let tx_context = TransactionContextBuilder::new(faucet_account.clone()).build();
+    let executor = TransactionExecutor::new(tx_context.clone(), Some(falcon_auth.clone()));
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let tx_script = build_rotate_owner_key_tx_script(AuthScheme::RpoFalcon512 {
+        pub_key: new_sec_key.public_key(),
+    })
+    .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction = executor
+        .execute_transaction(faucet_account.id(), block_ref, &[], tx_args)
+        .unwrap();
+
+    // check that the owner key (slot 0) was replaced
+    assert_eq!(
+        executed_transaction.account_delta().storage().slots().get(&0),
+        Some(&new_pub_key)
+    );
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+
+    faucet_account.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Failure - old key can no longer mint)
+    // --------------------------------------------------------------------------------------------
+    let tx_context = TransactionContextBuilder::new(faucet_account.clone()).build();
+    let tx_args = build_tx_args_from_script(&get_distribute_tx_script_code(Felt::new(100)));
+
+    let executor = TransactionExecutor::new(tx_context.clone(), Some(falcon_auth));
+    let executed_transaction =
+        executor.execute_transaction(faucet_account.id(), block_ref, &[], tx_args.clone());
+    assert_signature_missing(executed_transaction);
+
+    // CONSTRUCT AND EXECUTE TX (Success - new key can mint)
+    // --------------------------------------------------------------------------------------------
+    let executor = TransactionExecutor::new(tx_context.clone(), Some(new_falcon_auth));
+    let executed_transaction =
+        executor.execute_transaction(faucet_account.id(), block_ref, &[], tx_args);
+    assert!(executed_transaction.is_ok());
+}
+
+#[test]
+fn faucet_contract_rotate_owner_key_fails_without_owner_signature() {
+    let (faucet_pub_key, _) = get_new_pk_and_authenticator();
+    let faucet_account =
+        get_faucet_account_with_max_supply_and_total_issuance(faucet_pub_key, 200, None);
+
+    // the transaction is signed by a key which is not the owner key
+    let other_sec_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([1_u8; 32]));
+    let other_falcon_auth = Rc::new(BasicAuthenticator::<StdRng>::new(&[(
+        other_sec_key.public_key().into(),
+        AuthSecretKey::RpoFalcon512(other_sec_key.clone()),
+    )]));
+
+    // CONSTRUCT AND EXECUTE TX (Failure)
+    // --------------------------------------------------------------------------------------------
+    let tx_context = TransactionContextBuilder::new(faucet_account.clone()).build();
+    let executor = TransactionExecutor::new(tx_context.clone(), Some(other_falcon_auth));
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let tx_script = build_rotate_owner_key_tx_script(AuthScheme::RpoFalcon512 {
+        pub_key: other_sec_key.public_key(),
+    })
+    .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction =
+        executor.execute_transaction(faucet_account.id(), block_ref, &[], tx_args);
+
+    assert_signature_missing(executed_transaction);
+}
+
+#[test]
+fn faucet_contract_owner_operation_fails_without_transaction_authentication() {
+    let (faucet_pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let faucet_account =
+        get_faucet_account_with_max_supply_and_total_issuance(faucet_pub_key, 200, Some(100));
+
+    let tx_context = TransactionContextBuilder::new(faucet_account.clone()).build();
+    let executor = TransactionExecutor::new(tx_context.clone(), Some(falcon_auth));
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    // the operation is signed with the owner key, but the signature does not authenticate the
+    // transaction, and thus it cannot increment the nonce of the faucet
+    let tx_script_code = "
+        begin
+            push.150
+            call.::miden::contracts::faucets::basic_fungible::lower_max_supply
+            drop
+        end
+    ";
+    let tx_args = build_tx_args_from_script(tx_script_code);
+
+    let executed_transaction =
+        executor.execute_transaction(faucet_account.id(), block_ref, &[], tx_args);
+
+    assert_execution_failed(executed_transaction, ERR_NONCE_DID_NOT_INCREASE);
+}
+
+#[test]
+fn faucet_contract_lower_max_supply_succeeds() {
+    let (faucet_pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let faucet_account =
+        get_faucet_account_with_max_supply_and_total_issuance(faucet_pub_key, 200, Some(100));
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let tx_context = TransactionContextBuilder::new(faucet_account.clone()).build();
+    let executor = TransactionExecutor::new(tx_context.clone(), Some(falcon_auth));
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let tx_script = build_lower_max_supply_tx_script(Felt::new(150)).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction = executor
+        .execute_transaction(faucet_account.id(), block_ref, &[], tx_args)
+        .unwrap();
+
+    // check that the max supply (slot 1) was lowered
+    assert_eq!(
+        executed_transaction.account_delta().storage().slots().get(&1),
+        Some(&[Felt::new(150), ZERO, ZERO, ZERO])
+    );
+}
+
+#[test]
+fn faucet_contract_lower_max_supply_fails_on_invalid_max_supply() {
+    let (faucet_pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let faucet_account =
+        get_faucet_account_with_max_supply_and_total_issuance(faucet_pub_key, 200, Some(100));
+
+    let tx_context = TransactionContextBuilder::new(faucet_account.clone()).build();
+    let executor = TransactionExecutor::new(tx_context.clone(), Some(falcon_auth));
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    // the new max supply must be lower than the current one and not lower than the total issuance
+    for (new_max_supply, expected_err_code) in [
+        (200, ERR_BASIC_FUNGIBLE_MAX_SUPPLY_NOT_LOWERED),
+        (250, ERR_BASIC_FUNGIBLE_MAX_SUPPLY_NOT_LOWERED),
+        (99, ERR_BASIC_FUNGIBLE_MAX_SUPPLY_BELOW_TOTAL_ISSUANCE),
+    ] {
+        let tx_script = build_lower_max_supply_tx_script(Felt::new(new_max_supply)).unwrap();
+        let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+        let executed_transaction =
+            executor.execute_transaction(faucet_account.id(), block_ref, &[], tx_args);
+
+        assert_execution_failed(executed_transaction, expected_err_code);
+    }
+}
+
+#[test]
+fn faucet_contract_freeze_minting_succeeds() {
+    let (faucet_pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let mut faucet_account =
+        get_faucet_account_with_max_supply_and_total_issuance(faucet_pub_key, 200, None);
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let tx_context = TransactionContextBuilder::new(faucet_account.clone()).build();
+    let executor = TransactionExecutor::new(tx_context.clone(), Some(falcon_auth.clone()));
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let tx_script = build_freeze_minting_tx_script().unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction = executor
+        .execute_transaction(faucet_account.id(), block_ref, &[], tx_args)
+        .unwrap();
+
+    // check that the frozen flag (slot 1) was set
+    assert_eq!(
+        executed_transaction.account_delta().storage().slots().get(&1),
+        Some(&[Felt::new(200), ZERO, ZERO, Felt::new(1)])
+    );
+
+    faucet_account.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Failure - minting is frozen)
+    // --------------------------------------------------------------------------------------------
+    let tx_context = TransactionContextBuilder::new(faucet_account.clone()).build();
+    let executor = TransactionExecutor::new(tx_context.clone(), Some(falcon_auth));
+    let tx_args = build_tx_args_from_script(&get_distribute_tx_script_code(Felt::new(100)));
+
+    let executed_transaction =
+        executor.execute_transaction(faucet_account.id(), block_ref, &[], tx_args);
+
+    assert_execution_failed(executed_transaction, ERR_BASIC_FUNGIBLE_MINTING_FROZEN);
+}
+
+// TESTS MINT NON-FUNGIBLE ASSET
+// ================================================================================================
+
+#[test]
+fn faucet_contract_mint_non_fungible_asset_succeeds() {
+    let (faucet_pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let faucet_account = get_non_fungible_faucet_account_with_max_items(faucet_pub_key, 10, 0);
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let tx_context = TransactionContextBuilder::new(faucet_account.clone()).build();
+
+    let executor = TransactionExecutor::new(tx_context.clone(), Some(falcon_auth.clone()));
+
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+    let tag = NoteTag::for_local_use_case(0, 0).unwrap();
+    let aux = Felt::new(27);
+    let note_execution_hint = NoteExecutionHint::always();
+    let note_type = NoteType::Private;
+    let data_hash = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+
+    let tx_script_code = format!(
+        "
+            begin
+
+                push.{recipient}
+                push.{note_execution_hint}
+                push.{note_type}
+                push.{aux}
+                push.{tag}
+                push.{data_hash}
+                call.::miden::contracts::faucets::basic_non_fungible::distribute_nft
+
+                call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+                dropw dropw dropw
+
+            end
+            ",
+        note_type = note_type as u8,
+        recipient = prepare_word(&recipient),
+        aux = aux,
+        tag = u32::from(tag),
+        note_execution_hint = Felt::from(note_execution_hint),
+        data_hash = prepare_word(&data_hash),
+    );
+
+    let tx_args = build_tx_args_from_script(&tx_script_code);
+
+    let executed_transaction = executor
+        .execute_transaction(faucet_account.id(), block_ref, &[], tx_args)
+        .unwrap();
+
+    let non_fungible_asset: Asset =
+        NonFungibleAsset::from_parts(faucet_account.id(), data_hash).unwrap().into();
+
+    let output_note = executed_transaction.output_notes().get_note(0).clone();
+
+    let assets = NoteAssets::new(vec![non_fungible_asset]).unwrap();
+    let id = NoteId::new(recipient.into(), assets.commitment());
+    assert_eq!(output_note.id(), id);
+
+    // check that the number of issued items (slot 2) was incremented
+    assert_eq!(
+        executed_transaction.account_delta().storage().slots().get(&2),
+        Some(&[Felt::new(10), ZERO, ZERO, Felt::new(1)])
+    );
+}
+
+#[test]
+fn faucet_contract_mint_non_fungible_asset_fails_exceeds_max_items() {
+    let (faucet_pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let faucet_account = get_non_fungible_faucet_account_with_max_items(faucet_pub_key, 10, 10);
+
+    // CONSTRUCT AND EXECUTE TX (Failure)
+    // --------------------------------------------------------------------------------------------
+    let tx_context = TransactionContextBuilder::new(faucet_account.clone()).build();
+
+    let executor = TransactionExecutor::new(tx_context.clone(), Some(falcon_auth.clone()));
+
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+    let data_hash = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+
+    let tx_script_code = format!(
+        "
+            begin
+
+                push.{recipient}
+                push.{note_execution_hint}
+                push.{note_type}
+                push.0
+                push.{tag}
+                push.{data_hash}
+                call.::miden::contracts::faucets::basic_non_fungible::distribute_nft
+
+                call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+                dropw dropw dropw
+
+            end
+            ",
+        note_type = NoteType::Private as u8,
+        recipient = prepare_word(&recipient),
+        tag = u32::from(NoteTag::for_local_use_case(0, 0).unwrap()),
+        note_execution_hint = Felt::from(NoteExecutionHint::always()),
+        data_hash = prepare_word(&data_hash),
+    );
+
+    let tx_args = build_tx_args_from_script(&tx_script_code);
+
+    let executed_transaction =
+        executor.execute_transaction(faucet_account.id(), block_ref, &[], tx_args);
+
+    assert!(matches!(
+        executed_transaction,
+        Err(TransactionExecutorError::ExecuteTransactionProgramFailed(
+            ExecutionError::FailedAssertion {
+                err_code: ERR_BASIC_NON_FUNGIBLE_MAX_ITEMS_EXCEEDED,
+                ..
+            }
+        ))
+    ));
+}
+
+// TESTS BURN NON-FUNGIBLE ASSET
+// ================================================================================================
+
+#[test]
+fn faucet_contract_burn_non_fungible_asset_requires_owner_authentication() {
+    let (faucet_pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let faucet_account = get_non_fungible_faucet_account_with_max_items(faucet_pub_key, 10, 1);
+
+    // the faucet tracks the asset to be burned as issued
+    let non_fungible_asset =
+        NonFungibleAsset::from_parts(faucet_account.id(), [ONE, Felt::new(2), Felt::new(3), ZERO])
+            .unwrap();
+    let asset: Word = Asset::from(non_fungible_asset).into();
+    let mut storage = faucet_account.storage().clone();
+    storage.set_map_item(FAUCET_STORAGE_DATA_SLOT, asset, asset).unwrap();
+    let faucet_account = Account::from_parts(
+        faucet_account.id(),
+        faucet_account.vault().clone(),
+        storage,
+        faucet_account.code().clone(),
+        faucet_account.nonce(),
+    );
+
+    let note_script = "
+        # burn the asset
+        begin
+            dropw
+            exec.::miden::note::get_assets drop
+            mem_loadw
+            call.::miden::contracts::faucets::basic_non_fungible::burn_nft
+            dropw
+        end
+        ";
+    let note = get_note_with_non_fungible_asset_and_script(non_fungible_asset, note_script);
+
+    let tx_context = TransactionContextBuilder::new(faucet_account.clone())
+        .input_notes(vec![note.clone()])
+        .build();
+    let executor = TransactionExecutor::new(tx_context.clone(), Some(falcon_auth));
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    // CONSTRUCT AND EXECUTE TX (Failure - burning does not authenticate the transaction)
+    // --------------------------------------------------------------------------------------------
+    let executed_transaction = executor.execute_transaction(
+        faucet_account.id(),
+        block_ref,
+        &[note.id()],
+        TransactionArgs::default(),
+    );
+    assert_execution_failed(executed_transaction, ERR_NONCE_DID_NOT_INCREASE);
+
+    // CONSTRUCT AND EXECUTE TX (Failure - burning does not allow to mint without authentication)
+    // --------------------------------------------------------------------------------------------
+    let tx_script_code = format!(
+        "
+            begin
+                push.{recipient}
+                push.{note_execution_hint}
+                push.{note_type}
+                push.0
+                push.{tag}
+                push.{data_hash}
+                call.::miden::contracts::faucets::basic_non_fungible::distribute_nft
+                dropw dropw dropw
+            end
+            ",
+        note_type = NoteType::Private as u8,
+        recipient = prepare_word(&[ZERO, ONE, Felt::new(2), Felt::new(3)]),
+        tag = u32::from(NoteTag::for_local_use_case(0, 0).unwrap()),
+        note_execution_hint = Felt::from(NoteExecutionHint::always()),
+        data_hash = prepare_word(&[Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)]),
+    );
+    let executed_transaction = executor.execute_transaction(
+        faucet_account.id(),
+        block_ref,
+        &[note.id()],
+        build_tx_args_from_script(&tx_script_code),
+    );
+    assert_execution_failed(executed_transaction, ERR_NONCE_DID_NOT_INCREASE);
+
+    // CONSTRUCT AND EXECUTE TX (Success - the owner authenticates the transaction)
+    // --------------------------------------------------------------------------------------------
+    let tx_args = TransactionArgs::with_tx_script(build_default_auth_script());
+    let executed_transaction = executor
+        .execute_transaction(faucet_account.id(), block_ref, &[note.id()], tx_args)
+        .unwrap();
+
+    // check that the asset is no longer tracked as issued
+    assert_eq!(
+        executed_transaction
+            .account_delta()
+            .storage()
+            .maps()
+            .get(&FAUCET_STORAGE_DATA_SLOT)
+            .unwrap()
+            .leaves()
+            .get(&Digest::from(asset)),
+        Some(&EMPTY_WORD)
+    );
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
+fn get_distribute_tx_script_code(amount: Felt) -> String {
+    format!(
+        "
+            begin
+
+                push.{recipient}
+                push.{note_execution_hint}
+                push.{note_type}
+                push.0
+                push.{tag}
+                push.{amount}
+                call.::miden::contracts::faucets::basic_fungible::distribute
+
+                call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+                dropw dropw drop
+
+            end
+            ",
+        note_type = NoteType::Private as u8,
+        recipient = prepare_word(&[Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)]),
+        tag = u32::from(NoteTag::for_local_use_case(0, 0).unwrap()),
+        note_execution_hint = Felt::from(NoteExecutionHint::always()),
+    )
+}
+
 fn get_faucet_account_with_max_supply_and_total_issuance(
     public_key: Word,
     max_supply: u64,
@@ -267,3 +768,52 @@ fn get_faucet_account_with_max_supply_and_total_issuance(
         Felt::new(1),
     )
 }
+
+fn get_non_fungible_faucet_account_with_max_items(
+    public_key: Word,
+    max_items: u64,
+    issued_items: u64,
+) -> Account {
+    let faucet_account_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+
+    let assembler = TransactionKernel::assembler();
+    let faucet_account_code = AccountCode::compile(NON_FUNGIBLE_FAUCET_SOURCE, assembler).unwrap();
+
+    let collection_info = [Felt::new(max_items), ZERO, ZERO, Felt::new(issued_items)];
+    let nft_map = StorageMap::new();
+    let faucet_account_storage = AccountStorage::new(
+        vec![
+            SlotItem::new_value(0, 0, public_key),
+            SlotItem::new_value(2, 0, collection_info),
+            SlotItem::new_map(FAUCET_STORAGE_DATA_SLOT, 0, nft_map.root().into()),
+        ],
+        BTreeMap::from([(FAUCET_STORAGE_DATA_SLOT, nft_map)]),
+    )
+    .unwrap();
+
+    Account::from_parts(
+        faucet_account_id,
+        AssetVault::new(&[]).unwrap(),
+        faucet_account_storage,
+        faucet_account_code,
+        Felt::new(1),
+    )
+}
+
+fn get_note_with_non_fungible_asset_and_script(
+    non_fungible_asset: NonFungibleAsset,
+    note_script: &str,
+) -> Note {
+    let assembler = TransactionKernel::assembler().with_debug_mode(true);
+    let note_script = NoteScript::compile(note_script, assembler).unwrap();
+    let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let sender_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    let assets = NoteAssets::new(vec![non_fungible_asset.into()]).unwrap();
+    let metadata =
+        NoteMetadata::new(sender_id, NoteType::Public, 1.into(), NoteExecutionHint::Always, ZERO)
+            .unwrap();
+    let recipient = NoteRecipient::new(serial_num, note_script, NoteInputs::new(vec![]).unwrap());
+
+    Note::new(assets, metadata, recipient)
+}
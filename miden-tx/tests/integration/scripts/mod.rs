@@ -1,4 +1,6 @@
 mod faucet;
 mod p2id;
 mod p2idr;
+mod recovery;
+mod stealth;
 mod swap;
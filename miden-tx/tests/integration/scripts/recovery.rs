@@ -0,0 +1,349 @@
+use std::rc::Rc;
+
+use miden_lib::{
+    accounts::wallets::{
+        build_approve_recovery_tx_script, build_cancel_recovery_tx_script,
+        build_finalize_recovery_tx_script, create_recoverable_wallet,
+    },
+    notes::create_recovery_note,
+    transaction::TransactionKernel,
+    AuthScheme,
+};
+use miden_objects::{
+    accounts::{
+        account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_OFF_CHAIN_SENDER,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+        },
+        Account, AccountId, AccountStorageType, AccountType, AuthSecretKey,
+    },
+    assets::{Asset, AssetVault, FungibleAsset},
+    crypto::{
+        dsa::rpo_falcon512::{PublicKey, SecretKey},
+        rand::RpoRandomCoin,
+    },
+    notes::{Note, NoteExecutionHint, NoteTag, NoteType},
+    testing::prepare_word,
+    transaction::{ExecutedTransaction, TransactionArgs, TransactionScript},
+    Felt, Word, ONE, ZERO,
+};
+use miden_tx::{
+    auth::BasicAuthenticator, testing::TransactionContextBuilder, TransactionExecutor,
+    TransactionExecutorError,
+};
+use rand::rngs::StdRng;
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+use crate::{
+    assert_execution_failed, assert_signature_missing, get_new_pk_and_authenticator,
+    prove_and_verify_transaction, ERR_NONCE_DID_NOT_INCREASE,
+};
+
+// Error codes of the social recovery contract, see `miden::contracts::recovery::social`.
+const ERR_SOCIAL_RECOVERY_SENDER_NOT_GUARDIAN: u32 = 0x00020057;
+const ERR_SOCIAL_RECOVERY_ALREADY_APPROVED: u32 = 0x00020058;
+const ERR_SOCIAL_RECOVERY_THRESHOLD_NOT_REACHED: u32 = 0x00020059;
+const ERR_SOCIAL_RECOVERY_DELAY_NOT_ELAPSED: u32 = 0x0002005a;
+const ERR_SOCIAL_RECOVERY_APPROVAL_NOT_FOUND: u32 = 0x0002006f;
+const ERR_SOCIAL_RECOVERY_UNEXPECTED_CHANGES: u32 = 0x00020070;
+
+// RECOVERY TESTS
+// ===============================================================================================
+
+#[test]
+fn prove_recovery_script() {
+    let (mut account, falcon_auth) = get_recoverable_wallet(0);
+    let new_pub_key = get_new_pub_key();
+
+    // both guardians approve the new key
+    let notes = vec![
+        get_recovery_note(ACCOUNT_ID_SENDER, account.id(), new_pub_key, 1),
+        get_recovery_note(ACCOUNT_ID_OFF_CHAIN_SENDER, account.id(), new_pub_key, 2),
+    ];
+    let tx_args =
+        get_approve_tx_args(new_pub_key, &[ACCOUNT_ID_SENDER, ACCOUNT_ID_OFF_CHAIN_SENDER]);
+    let executed_transaction = execute_transaction(&account, notes, tx_args, None).unwrap();
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction).is_ok());
+
+    // the recovery can be finalized once the threshold is reached, but only with the new key
+    let tx_args = TransactionArgs::with_tx_script(
+        build_finalize_recovery_tx_script(AuthScheme::RpoFalcon512 { pub_key: new_pub_key })
+            .unwrap(),
+    );
+    let result = execute_transaction(&account, vec![], tx_args.clone(), Some(falcon_auth));
+    assert_signature_missing(result);
+
+    let executed_transaction =
+        execute_transaction(&account, vec![], tx_args, Some(get_new_key_authenticator())).unwrap();
+
+    assert_eq!(
+        executed_transaction.account_delta().storage().slots().get(&0),
+        Some(&Word::from(new_pub_key))
+    );
+}
+
+#[test]
+fn recovery_script_fails_below_threshold() {
+    let (mut account, _) = get_recoverable_wallet(0);
+    let new_pub_key = get_new_pub_key();
+
+    // a single guardian approves the new key
+    let notes = vec![get_recovery_note(ACCOUNT_ID_SENDER, account.id(), new_pub_key, 1)];
+    let tx_args = get_approve_tx_args(new_pub_key, &[ACCOUNT_ID_SENDER]);
+    let executed_transaction = execute_transaction(&account, notes, tx_args, None).unwrap();
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    let tx_args = TransactionArgs::with_tx_script(
+        build_finalize_recovery_tx_script(AuthScheme::RpoFalcon512 { pub_key: new_pub_key })
+            .unwrap(),
+    );
+    let result = execute_transaction(&account, vec![], tx_args, Some(get_new_key_authenticator()));
+    assert_execution_failed(result, ERR_SOCIAL_RECOVERY_THRESHOLD_NOT_REACHED);
+}
+
+#[test]
+fn recovery_script_fails_before_delay() {
+    let (mut account, _) = get_recoverable_wallet(100);
+    let new_pub_key = get_new_pub_key();
+
+    let notes = vec![
+        get_recovery_note(ACCOUNT_ID_SENDER, account.id(), new_pub_key, 1),
+        get_recovery_note(ACCOUNT_ID_OFF_CHAIN_SENDER, account.id(), new_pub_key, 2),
+    ];
+    let tx_args =
+        get_approve_tx_args(new_pub_key, &[ACCOUNT_ID_SENDER, ACCOUNT_ID_OFF_CHAIN_SENDER]);
+    let executed_transaction = execute_transaction(&account, notes, tx_args, None).unwrap();
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    let tx_args = TransactionArgs::with_tx_script(
+        build_finalize_recovery_tx_script(AuthScheme::RpoFalcon512 { pub_key: new_pub_key })
+            .unwrap(),
+    );
+    let result = execute_transaction(&account, vec![], tx_args, Some(get_new_key_authenticator()));
+    assert_execution_failed(result, ERR_SOCIAL_RECOVERY_DELAY_NOT_ELAPSED);
+}
+
+#[test]
+fn recovery_script_rejects_invalid_approvals() {
+    let (account, _) = get_recoverable_wallet(0);
+    let new_pub_key = get_new_pub_key();
+
+    // the sender of the note is not a guardian
+    let non_guardian_id = ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN;
+    let notes = vec![get_recovery_note(non_guardian_id, account.id(), new_pub_key, 1)];
+    let result = execute_transaction(&account, notes, TransactionArgs::default(), None);
+    assert_execution_failed(result, ERR_SOCIAL_RECOVERY_SENDER_NOT_GUARDIAN);
+
+    // a guardian approves the same key twice
+    let notes = vec![
+        get_recovery_note(ACCOUNT_ID_SENDER, account.id(), new_pub_key, 1),
+        get_recovery_note(ACCOUNT_ID_SENDER, account.id(), new_pub_key, 2),
+    ];
+    let result = execute_transaction(&account, notes, TransactionArgs::default(), None);
+    assert_execution_failed(result, ERR_SOCIAL_RECOVERY_ALREADY_APPROVED);
+}
+
+#[test]
+fn recovery_approvals_require_authentication() {
+    let (account, _) = get_recoverable_wallet(0);
+    let new_pub_key = get_new_pub_key();
+    let notes = vec![
+        get_recovery_note(ACCOUNT_ID_SENDER, account.id(), new_pub_key, 1),
+        get_recovery_note(ACCOUNT_ID_OFF_CHAIN_SENDER, account.id(), new_pub_key, 2),
+    ];
+
+    // approvals do not increment the nonce on their own
+    let result = execute_transaction(&account, notes.clone(), TransactionArgs::default(), None);
+    assert_execution_failed(result, ERR_NONCE_DID_NOT_INCREASE);
+
+    // all approvals of the transaction must be listed
+    let tx_args = get_approve_tx_args(new_pub_key, &[ACCOUNT_ID_SENDER]);
+    let result = execute_transaction(&account, notes.clone(), tx_args, None);
+    assert_execution_failed(result, ERR_SOCIAL_RECOVERY_UNEXPECTED_CHANGES);
+
+    // only guardians which approved the key within the transaction can be listed
+    let tx_args = get_approve_tx_args(
+        new_pub_key,
+        &[ACCOUNT_ID_SENDER, ACCOUNT_ID_OFF_CHAIN_SENDER, ACCOUNT_ID_SENDER],
+    );
+    let result = execute_transaction(&account, notes, tx_args, None);
+    assert_execution_failed(result, ERR_SOCIAL_RECOVERY_APPROVAL_NOT_FOUND);
+}
+
+#[test]
+fn recovery_approvals_cannot_drain_wallet() {
+    let (account, _) = get_recoverable_wallet(0);
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+    let account = Account::from_parts(
+        account.id(),
+        AssetVault::new(&[asset]).unwrap(),
+        account.storage().clone(),
+        account.code().clone(),
+        account.nonce(),
+    );
+    let new_pub_key = get_new_pub_key();
+
+    // a guardian approves the new key and sends the assets of the wallet in the same transaction
+    let notes = vec![get_recovery_note(ACCOUNT_ID_SENDER, account.id(), new_pub_key, 1)];
+    let tx_script_src = format!(
+        "
+        begin
+            push.{recipient}
+            push.{note_execution_hint}
+            push.{note_type}
+            push.0
+            push.{tag}
+            push.{asset}
+            call.::miden::contracts::wallets::basic::send_asset
+            dropw dropw dropw dropw
+
+            push.{new_pub_key}
+            call.::miden::contracts::recovery::social::auth_tx_recovery
+            dropw
+        end
+        ",
+        recipient = prepare_word(&[ZERO, ONE, Felt::new(2), Felt::new(3)]),
+        note_execution_hint = Felt::from(NoteExecutionHint::always()),
+        note_type = NoteType::Private as u8,
+        tag = NoteTag::for_local_use_case(0, 0).unwrap(),
+        asset = prepare_word(&asset.into()),
+        new_pub_key = prepare_word(&Word::from(new_pub_key)),
+    );
+    let tx_script = TransactionScript::compile(
+        tx_script_src,
+        [(
+            Word::from(new_pub_key),
+            vec![Felt::from(AccountId::try_from(ACCOUNT_ID_SENDER).unwrap())],
+        )],
+        TransactionKernel::assembler(),
+    )
+    .unwrap();
+    let result =
+        execute_transaction(&account, notes, TransactionArgs::with_tx_script(tx_script), None);
+    assert_execution_failed(result, ERR_SOCIAL_RECOVERY_UNEXPECTED_CHANGES);
+}
+
+#[test]
+fn cancel_recovery_invalidates_approvals() {
+    let (mut account, falcon_auth) = get_recoverable_wallet(0);
+    let new_pub_key = get_new_pub_key();
+
+    let notes = vec![
+        get_recovery_note(ACCOUNT_ID_SENDER, account.id(), new_pub_key, 1),
+        get_recovery_note(ACCOUNT_ID_OFF_CHAIN_SENDER, account.id(), new_pub_key, 2),
+    ];
+    let tx_args =
+        get_approve_tx_args(new_pub_key, &[ACCOUNT_ID_SENDER, ACCOUNT_ID_OFF_CHAIN_SENDER]);
+    let executed_transaction = execute_transaction(&account, notes, tx_args, None).unwrap();
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    // cancelling the recovery requires a signature of the current key
+    let tx_args = TransactionArgs::with_tx_script(build_cancel_recovery_tx_script().unwrap());
+    let result = execute_transaction(&account, vec![], tx_args.clone(), None);
+    assert_signature_missing(result);
+
+    let executed_transaction =
+        execute_transaction(&account, vec![], tx_args, Some(falcon_auth)).unwrap();
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    // the recovery cannot be finalized anymore
+    let tx_args = TransactionArgs::with_tx_script(
+        build_finalize_recovery_tx_script(AuthScheme::RpoFalcon512 { pub_key: new_pub_key })
+            .unwrap(),
+    );
+    let result = execute_transaction(&account, vec![], tx_args, Some(get_new_key_authenticator()));
+    assert_execution_failed(result, ERR_SOCIAL_RECOVERY_THRESHOLD_NOT_REACHED);
+}
+
+// HELPER FUNCTIONS
+// ===============================================================================================
+
+/// Returns an existing recoverable wallet guarded by two accounts with a threshold of 2, together
+/// with the authenticator of its current key.
+fn get_recoverable_wallet(recovery_delay: u32) -> (Account, Rc<BasicAuthenticator<StdRng>>) {
+    let (pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let guardians = [
+        AccountId::try_from(ACCOUNT_ID_SENDER).unwrap(),
+        AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap(),
+    ];
+
+    let (account, _) = create_recoverable_wallet(
+        [7; 32],
+        AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(pub_key) },
+        &guardians,
+        2,
+        recovery_delay,
+        AccountType::RegularAccountUpdatableCode,
+        AccountStorageType::OffChain,
+    )
+    .unwrap();
+
+    let account = Account::from_parts(
+        account.id(),
+        AssetVault::new(&[]).unwrap(),
+        account.storage().clone(),
+        account.code().clone(),
+        ONE,
+    );
+
+    (account, falcon_auth)
+}
+
+fn get_new_pub_key() -> PublicKey {
+    SecretKey::with_rng(&mut ChaCha20Rng::from_seed([1_u8; 32])).public_key()
+}
+
+fn get_new_key_authenticator() -> Rc<BasicAuthenticator<StdRng>> {
+    let sec_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([1_u8; 32]));
+    Rc::new(BasicAuthenticator::<StdRng>::new(&[(
+        sec_key.public_key().into(),
+        AuthSecretKey::RpoFalcon512(sec_key),
+    )]))
+}
+
+fn get_approve_tx_args(new_pub_key: PublicKey, guardians: &[u64]) -> TransactionArgs {
+    let guardians =
+        guardians.iter().map(|id| AccountId::try_from(*id).unwrap()).collect::<Vec<_>>();
+    TransactionArgs::with_tx_script(
+        build_approve_recovery_tx_script(
+            AuthScheme::RpoFalcon512 { pub_key: new_pub_key },
+            &guardians,
+        )
+        .unwrap(),
+    )
+}
+
+fn get_recovery_note(sender: u64, target: AccountId, new_pub_key: PublicKey, seed: u64) -> Note {
+    create_recovery_note(
+        AccountId::try_from(sender).unwrap(),
+        target,
+        AuthScheme::RpoFalcon512 { pub_key: new_pub_key },
+        NoteType::Public,
+        &mut RpoRandomCoin::new([Felt::new(seed), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap()
+}
+
+fn execute_transaction(
+    account: &Account,
+    notes: Vec<Note>,
+    tx_args: TransactionArgs,
+    authenticator: Option<Rc<BasicAuthenticator<StdRng>>>,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let tx_context = TransactionContextBuilder::new(account.clone()).input_notes(notes).build();
+    let executor = TransactionExecutor::new(tx_context.clone(), authenticator);
+
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    executor.execute_transaction(account.id(), block_ref, &note_ids, tx_args)
+}
@@ -0,0 +1,162 @@
+use std::{collections::BTreeMap, rc::Rc};
+
+use miden_lib::notes::{create_p2id_note, create_stealth_note, StealthNoteScanner};
+use miden_objects::{
+    accounts::{
+        account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN_2, ACCOUNT_ID_SENDER,
+        },
+        Account, AccountId,
+    },
+    assets::{Asset, AssetVault, FungibleAsset},
+    crypto::rand::RpoRandomCoin,
+    notes::{Note, NoteDecryptionKey, NoteMetadata, NoteType},
+    transaction::{ExecutedTransaction, TransactionArgs},
+    Felt, Word,
+};
+use miden_tx::{
+    auth::BasicAuthenticator, testing::TransactionContextBuilder, TransactionExecutor,
+    TransactionExecutorError,
+};
+use rand::{rngs::StdRng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use vm_processor::ExecutionError;
+
+use crate::{
+    build_default_auth_script, get_account_with_default_account_code, get_new_pk_and_authenticator,
+};
+
+// Error code of the STEALTH script, see `note_scripts/STEALTH.masm`.
+const ERR_STEALTH_RECIPIENT_COMMITMENT_MISMATCH: u32 = 0x0002006d;
+
+// STEALTH TESTS
+// ===============================================================================================
+
+#[test]
+fn stealth_note_script() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let other_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN_2).unwrap();
+
+    // the target account publishes the stealth address of its viewing key
+    let viewing_key = NoteDecryptionKey::new(&mut ChaCha20Rng::from_seed([0; 32]));
+    let scanner = StealthNoteScanner::new(target_account_id, viewing_key);
+    let address = scanner.address();
+
+    // notes sent to the same address do not share any inputs
+    let mut rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let note =
+        create_stealth_note(sender_account_id, &address, vec![fungible_asset], &mut rng).unwrap();
+    let other_note =
+        create_stealth_note(sender_account_id, &address, vec![fungible_asset], &mut rng).unwrap();
+    assert_eq!(note.metadata().note_type(), NoteType::Public);
+    assert_eq!(note.metadata().tag(), other_note.metadata().tag());
+    assert!(note
+        .inputs()
+        .values()
+        .iter()
+        .all(|value| !other_note.inputs().values().contains(value)));
+
+    // the target account detects the notes sent to it
+    assert!(scanner.is_candidate(note.header(), note.inputs()));
+    let recipient = scanner.scan(&note).unwrap();
+    assert_eq!(note.metadata().aux(), Felt::from(recipient.view_tag()));
+    assert!(scanner.scan(&other_note).is_some());
+
+    // the view tag filters out the notes sent to other addresses
+    let other_scanner = StealthNoteScanner::new(
+        other_account_id,
+        NoteDecryptionKey::new(&mut ChaCha20Rng::from_seed([1; 32])),
+    );
+    assert!(!other_scanner.is_candidate(note.header(), note.inputs()));
+    assert!(other_scanner.scan(&note).is_none());
+
+    // notes with a wrong view tag are filtered out
+    let metadata = note.metadata();
+    let tampered_metadata = NoteMetadata::new(
+        metadata.sender(),
+        metadata.note_type(),
+        metadata.tag(),
+        metadata.execution_hint(),
+        Felt::from(recipient.view_tag() ^ 1),
+    )
+    .unwrap();
+    let tampered_note =
+        Note::new(note.assets().clone(), tampered_metadata, note.recipient().clone());
+    assert!(!scanner.is_candidate(tampered_note.header(), tampered_note.inputs()));
+    assert!(scanner.scan(&tampered_note).is_none());
+
+    let p2id_note = create_p2id_note(
+        sender_account_id,
+        target_account_id,
+        vec![fungible_asset],
+        NoteType::Public,
+        Felt::new(0),
+        &mut rng,
+    )
+    .unwrap();
+    assert!(!scanner.is_candidate(p2id_note.header(), p2id_note.inputs()));
+    assert!(scanner.scan(&p2id_note).is_none());
+
+    // the target account consumes the note with the blinding factor of the one-time recipient
+    let target_account = get_account(target_account_id);
+    let executed_transaction =
+        execute_transaction(&target_account, note.clone(), recipient.blinding()).unwrap();
+    let target_account = target_account.0;
+
+    let target_account_after = Account::from_parts(
+        target_account.id(),
+        AssetVault::new(&[fungible_asset]).unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
+
+    // other accounts cannot consume the note, even with the blinding factor
+    let result = execute_transaction(&get_account(other_account_id), note, recipient.blinding());
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::ExecuteTransactionProgramFailed(
+            ExecutionError::FailedAssertion {
+                err_code: ERR_STEALTH_RECIPIENT_COMMITMENT_MISMATCH,
+                ..
+            }
+        ))
+    ));
+}
+
+// HELPER FUNCTIONS
+// ===============================================================================================
+
+fn get_account(account_id: AccountId) -> (Account, Rc<BasicAuthenticator<StdRng>>) {
+    let (pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    (get_account_with_default_account_code(account_id, pub_key, None), falcon_auth)
+}
+
+fn execute_transaction(
+    (account, falcon_auth): &(Account, Rc<BasicAuthenticator<StdRng>>),
+    note: Note,
+    blinding: Word,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let tx_context = TransactionContextBuilder::new(account.clone())
+        .input_notes(vec![note.clone()])
+        .build();
+    let executor = TransactionExecutor::new(tx_context.clone(), Some(falcon_auth.clone()));
+
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_args = BTreeMap::from([(note.id(), blinding)]);
+    let tx_args = TransactionArgs::new(
+        Some(build_default_auth_script()),
+        Some(note_args),
+        Default::default(),
+    );
+
+    executor.execute_transaction(account.id(), block_ref, &[note.id()], tx_args)
+}
@@ -1,13 +1,18 @@
+mod spending_limits;
+
 use std::collections::BTreeMap;
 
-use miden_lib::{accounts::wallets::create_basic_wallet, AuthScheme};
+use miden_lib::{
+    accounts::wallets::{build_rotate_key_tx_script, create_basic_wallet},
+    AuthScheme,
+};
 use miden_objects::{
     accounts::{
         account_id::testing::{
             ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_OFF_CHAIN_SENDER,
             ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
         },
-        Account, AccountId, AccountStorage, SlotItem,
+        Account, AccountId, AccountStorage, AuthSecretKey, SlotItem,
     },
     assets::{Asset, AssetVault, FungibleAsset},
     crypto::dsa::rpo_falcon512::SecretKey,
@@ -20,9 +25,10 @@ use miden_tx::{testing::TransactionContextBuilder, TransactionExecutor};
 use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
 
 use crate::{
-    build_default_auth_script, build_tx_args_from_script, get_account_with_default_account_code,
-    get_new_pk_and_authenticator, get_note_with_fungible_asset_and_script,
-    prove_and_verify_transaction,
+    assert_execution_failed, assert_signature_missing, build_default_auth_script,
+    build_tx_args_from_script, get_account_with_default_account_code, get_new_pk_and_authenticator,
+    get_note_with_fungible_asset_and_script, prove_and_verify_transaction,
+    ERR_NONCE_DID_NOT_INCREASE,
 };
 
 #[test]
@@ -178,11 +184,132 @@ fn prove_send_asset_via_wallet() {
     assert_eq!(executed_transaction.final_account().hash(), sender_account_after.hash());
 }
 
+#[test]
+/// Testing the basic Miden wallet - rotating the key
+fn wallet_rotate_key() {
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset_1: Asset = FungibleAsset::new(faucet_id_1, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+    let (sender_pub_key, sender_falcon_auth) = get_new_pk_and_authenticator();
+    let mut sender_account = get_account_with_default_account_code(
+        sender_account_id,
+        sender_pub_key,
+        fungible_asset_1.into(),
+    );
+
+    let new_sec_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([1_u8; 32]));
+    let new_pub_key: Word = new_sec_key.public_key().into();
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let tx_context = TransactionContextBuilder::new(sender_account.clone()).build();
+    let executor = TransactionExecutor::new(tx_context.clone(), Some(sender_falcon_auth.clone()));
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let tx_script =
+        build_rotate_key_tx_script(AuthScheme::RpoFalcon512 { pub_key: new_sec_key.public_key() })
+            .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction = executor
+        .execute_transaction(sender_account.id(), block_ref, &[], tx_args)
+        .unwrap();
+
+    // check that the key (slot 0) was replaced
+    assert_eq!(
+        executed_transaction.account_delta().storage().slots().get(&0),
+        Some(&new_pub_key)
+    );
+    sender_account.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Failure - the old key cannot authenticate the account anymore)
+    // --------------------------------------------------------------------------------------------
+    let tx_context = TransactionContextBuilder::new(sender_account.clone()).build();
+    let executor = TransactionExecutor::new(tx_context.clone(), Some(sender_falcon_auth.clone()));
+    let tx_args = TransactionArgs::with_tx_script(build_default_auth_script());
+
+    let executed_transaction =
+        executor.execute_transaction(sender_account.id(), block_ref, &[], tx_args.clone());
+    assert_signature_missing(executed_transaction);
+
+    // CONSTRUCT AND EXECUTE TX (Success - the new key authenticates the account after the swap)
+    // --------------------------------------------------------------------------------------------
+    sender_falcon_auth
+        .swap_key(sender_pub_key, new_pub_key, AuthSecretKey::RpoFalcon512(new_sec_key))
+        .unwrap();
+
+    let executed_transaction =
+        executor.execute_transaction(sender_account.id(), block_ref, &[], tx_args);
+    assert!(executed_transaction.is_ok());
+}
+
+#[test]
+/// Testing the basic Miden wallet - the key rotation does not authenticate the transaction
+fn wallet_rotate_key_cannot_move_assets() {
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset_1: Asset = FungibleAsset::new(faucet_id_1, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+    let (sender_pub_key, sender_falcon_auth) = get_new_pk_and_authenticator();
+    let sender_account = get_account_with_default_account_code(
+        sender_account_id,
+        sender_pub_key,
+        fungible_asset_1.into(),
+    );
+
+    let new_sec_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([1_u8; 32]));
+    let new_pub_key: Word = new_sec_key.public_key().into();
+
+    // CONSTRUCT AND EXECUTE TX (Failure - the key rotation is signed, but the transaction sending
+    // the asset out of the wallet is not)
+    // --------------------------------------------------------------------------------------------
+    let tx_context = TransactionContextBuilder::new(sender_account.clone()).build();
+    let executor = TransactionExecutor::new(tx_context.clone(), Some(sender_falcon_auth));
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+    let tx_script_src = format!(
+        "
+        begin
+            push.{new_pub_key}
+            call.::miden::contracts::auth::basic::rotate_key_rpo_falcon512
+            dropw
+
+            push.{recipient}
+            push.{note_execution_hint}
+            push.{note_type}
+            push.0
+            push.{tag}
+            push.{asset}
+            call.::miden::contracts::wallets::basic::send_asset
+            dropw dropw dropw dropw
+        end
+        ",
+        new_pub_key = prepare_word(&new_pub_key),
+        recipient = prepare_word(&recipient),
+        note_execution_hint = Felt::from(NoteExecutionHint::always()),
+        note_type = NoteType::Private as u8,
+        tag = NoteTag::for_local_use_case(0, 0).unwrap(),
+        asset = prepare_word(&fungible_asset_1.into()),
+    );
+    let tx_args = build_tx_args_from_script(&tx_script_src);
+
+    let executed_transaction =
+        executor.execute_transaction(sender_account.id(), block_ref, &[], tx_args);
+    assert_execution_failed(executed_transaction, ERR_NONCE_DID_NOT_INCREASE);
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 #[test]
 fn wallet_creation() {
-    use miden_objects::accounts::{
-        account_id::testing::ACCOUNT_ID_SENDER, AccountStorageType, AccountType,
+    use miden_lib::accounts::{auth::RpoFalcon512, wallets::BasicWallet};
+    use miden_objects::{
+        accounts::{
+            account_id::testing::ACCOUNT_ID_SENDER, AccountCode, AccountComponent,
+            AccountProcedureInfo, AccountStorageType, AccountType,
+        },
+        Digest,
     };
 
     // we need a Falcon Public Key to create the wallet account
@@ -207,10 +334,29 @@ fn wallet_creation() {
 
     // sender_account_id not relevant here, just to create a default account code
     let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
-    let expected_code_commitment =
+    let default_code =
         get_account_with_default_account_code(sender_account_id, pub_key.into(), None)
             .code()
-            .commitment();
+            .clone();
+
+    // the wallet exposes the procedures of the default account code, the procedures of the
+    // authentication component can access the public key slot only, and the procedures of the
+    // basic wallet component cannot access any slots
+    let auth_roots: Vec<Digest> =
+        AccountComponent::from(RpoFalcon512::new(pub_key)).procedure_roots().collect();
+    let wallet_roots: Vec<Digest> = AccountComponent::from(BasicWallet).procedure_roots().collect();
+    let expected_procedures: Vec<AccountProcedureInfo> = auth_roots
+        .into_iter()
+        .map(|root| AccountProcedureInfo::new(root, 0, 1).unwrap())
+        .chain(
+            wallet_roots
+                .into_iter()
+                .map(|root| AccountProcedureInfo::new(root, 1, 0).unwrap()),
+        )
+        .collect();
+    assert_eq!(expected_procedures.len(), default_code.num_procedures());
+    let expected_code_commitment =
+        AccountCode::from_parts(default_code.mast(), expected_procedures).commitment();
 
     assert!(wallet.is_regular_account());
     assert_eq!(wallet.code().commitment(), expected_code_commitment);
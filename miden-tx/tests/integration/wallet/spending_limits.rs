@@ -0,0 +1,394 @@
+use std::rc::Rc;
+
+use miden_lib::{
+    accounts::wallets::{
+        build_approve_recovery_tx_script, build_finalize_recovery_tx_script,
+        build_set_spending_limit_tx_script, create_wallet_with_spending_limits, SocialRecovery,
+        SpendingLimit, SpendingLimits,
+    },
+    notes::create_recovery_note,
+    AuthScheme,
+};
+use miden_objects::{
+    accounts::{
+        account_id::testing::{ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_SENDER},
+        Account, AccountBuilder, AccountId, AccountStorageType, AccountType, AuthSecretKey,
+    },
+    assets::{Asset, AssetVault, FungibleAsset},
+    crypto::{
+        dsa::rpo_falcon512::{PublicKey, SecretKey},
+        rand::RpoRandomCoin,
+    },
+    notes::{NoteExecutionHint, NoteTag, NoteType},
+    testing::prepare_word,
+    transaction::{ExecutedTransaction, TransactionArgs},
+    Felt, Word, ONE, ZERO,
+};
+use miden_tx::{
+    auth::BasicAuthenticator, testing::TransactionContextBuilder, TransactionExecutor,
+    TransactionExecutorError,
+};
+use rand::rngs::StdRng;
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+use crate::{
+    assert_execution_failed, assert_signature_missing, build_tx_args_from_script,
+    get_new_pk_and_authenticator, ERR_NONCE_DID_NOT_INCREASE,
+};
+
+const LIMIT: u64 = 100;
+const WINDOW_LENGTH: u32 = 10;
+
+// SPENDING LIMITS TESTS
+// ================================================================================================
+
+#[test]
+fn wallet_spending_limit_within_window() {
+    let (mut account, falcon_auth, (cosigner_pub_key, cosigner_sec_key)) =
+        get_wallet_with_spending_limits();
+    let block_num = get_block_num(&account);
+
+    // CONSTRUCT AND EXECUTE TX (Success - the amount is within the limit)
+    // --------------------------------------------------------------------------------------------
+    let executed_transaction =
+        execute_transaction(&account, build_send_asset_tx_args(60), falcon_auth.clone()).unwrap();
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    assert_eq!(
+        get_spent_amount(&account),
+        [Felt::new(60), Felt::from(block_num / WINDOW_LENGTH), ZERO, ZERO]
+    );
+
+    // CONSTRUCT AND EXECUTE TX (Failure - the amount exceeds the remaining limit)
+    // --------------------------------------------------------------------------------------------
+    let executed_transaction =
+        execute_transaction(&account, build_send_asset_tx_args(60), falcon_auth.clone());
+    assert_signature_missing(executed_transaction);
+
+    // CONSTRUCT AND EXECUTE TX (Success - the transfer above the limit is co-signed)
+    // --------------------------------------------------------------------------------------------
+    falcon_auth.add_key(cosigner_pub_key, AuthSecretKey::RpoFalcon512(cosigner_sec_key));
+
+    let executed_transaction =
+        execute_transaction(&account, build_send_asset_tx_args(60), falcon_auth).unwrap();
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    // co-signed transfers do not count towards the limit
+    assert_eq!(
+        get_spent_amount(&account),
+        [Felt::new(60), Felt::from(block_num / WINDOW_LENGTH), ZERO, ZERO]
+    );
+}
+
+#[test]
+fn wallet_spending_limit_resets_in_new_window() {
+    let (account, falcon_auth, _) = get_wallet_with_spending_limits();
+    let block_num = get_block_num(&account);
+
+    // the whole limit was spent within a past window
+    let past_window_idx = block_num / WINDOW_LENGTH + 1;
+    let mut storage = account.storage().clone();
+    storage
+        .set_map_item(
+            3,
+            get_faucet_map_key(),
+            [Felt::new(LIMIT), Felt::from(past_window_idx), ZERO, ZERO],
+        )
+        .unwrap();
+    let mut account = Account::from_parts(
+        account.id(),
+        account.vault().clone(),
+        storage,
+        account.code().clone(),
+        account.nonce(),
+    );
+
+    let executed_transaction =
+        execute_transaction(&account, build_send_asset_tx_args(LIMIT), falcon_auth).unwrap();
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    assert_eq!(
+        get_spent_amount(&account),
+        [Felt::new(LIMIT), Felt::from(block_num / WINDOW_LENGTH), ZERO, ZERO]
+    );
+}
+
+#[test]
+fn wallet_set_spending_limit() {
+    let (mut account, falcon_auth, (cosigner_pub_key, cosigner_sec_key)) =
+        get_wallet_with_spending_limits();
+
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let new_limit = SpendingLimit::new(faucet_id, 500, 20).unwrap();
+    let tx_args =
+        TransactionArgs::with_tx_script(build_set_spending_limit_tx_script(new_limit).unwrap());
+
+    // CONSTRUCT AND EXECUTE TX (Failure - the co-signer did not sign)
+    // --------------------------------------------------------------------------------------------
+    let executed_transaction = execute_transaction(&account, tx_args.clone(), falcon_auth.clone());
+    assert_signature_missing(executed_transaction);
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    falcon_auth.add_key(cosigner_pub_key, AuthSecretKey::RpoFalcon512(cosigner_sec_key));
+
+    let executed_transaction = execute_transaction(&account, tx_args, falcon_auth.clone()).unwrap();
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    assert_eq!(
+        account.storage().get_map_item(2, get_faucet_map_key()).unwrap(),
+        [Felt::new(500), Felt::new(20), ZERO, ZERO]
+    );
+
+    // the new limit allows to send more without co-signing
+    falcon_auth.remove_key(cosigner_pub_key);
+    let executed_transaction =
+        execute_transaction(&account, build_send_asset_tx_args(400), falcon_auth);
+    assert!(executed_transaction.is_ok());
+}
+
+#[test]
+fn wallet_spending_limit_cosigner_cannot_authenticate_transaction() {
+    let (account, _, (cosigner_pub_key, cosigner_sec_key)) = get_wallet_with_spending_limits();
+    let cosigner_auth = Rc::new(BasicAuthenticator::<StdRng>::new(&[(
+        cosigner_pub_key,
+        AuthSecretKey::RpoFalcon512(cosigner_sec_key),
+    )]));
+
+    // CONSTRUCT AND EXECUTE TX (Failure - only the co-signer signed the transfer above the limit)
+    // --------------------------------------------------------------------------------------------
+    let executed_transaction =
+        execute_transaction(&account, build_send_asset_tx_args(200), cosigner_auth.clone());
+    assert_signature_missing(executed_transaction);
+
+    // CONSTRUCT AND EXECUTE TX (Failure - the co-signature does not authenticate the transaction)
+    // --------------------------------------------------------------------------------------------
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let asset: Asset = FungibleAsset::new(faucet_id, 200).unwrap().into();
+    let tx_script_src = format!(
+        "
+        begin
+            push.{recipient}
+            push.{note_execution_hint}
+            push.{note_type}
+            push.0
+            push.{tag}
+            push.{asset}
+            call.::miden::contracts::limits::spending::send_asset
+            dropw dropw dropw dropw
+        end
+        ",
+        recipient = prepare_word(&[ZERO, ONE, Felt::new(2), Felt::new(3)]),
+        note_execution_hint = Felt::from(NoteExecutionHint::always()),
+        note_type = NoteType::Private as u8,
+        tag = NoteTag::for_local_use_case(0, 0).unwrap(),
+        asset = prepare_word(&asset.into()),
+    );
+    let executed_transaction = execute_transaction(
+        &account,
+        build_tx_args_from_script(&tx_script_src),
+        cosigner_auth.clone(),
+    );
+    assert_execution_failed(executed_transaction, ERR_NONCE_DID_NOT_INCREASE);
+
+    // CONSTRUCT AND EXECUTE TX (Failure - only the co-signer signed the new spending limit)
+    // --------------------------------------------------------------------------------------------
+    let new_limit = SpendingLimit::new(faucet_id, 500, 20).unwrap();
+    let tx_args =
+        TransactionArgs::with_tx_script(build_set_spending_limit_tx_script(new_limit).unwrap());
+    let executed_transaction = execute_transaction(&account, tx_args, cosigner_auth);
+    assert_signature_missing(executed_transaction);
+}
+
+#[test]
+fn wallet_spending_limits_with_social_recovery() {
+    let (pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let cosigner_pub_key =
+        SecretKey::with_rng(&mut ChaCha20Rng::from_seed([1_u8; 32])).public_key();
+    let new_sec_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([2_u8; 32]));
+    let new_pub_key = new_sec_key.public_key();
+    let guardian_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+    // the slots of the spending limits follow the 5 slots of the social recovery
+    let (account, _) = AccountBuilder::new([3; 32])
+        .with_component(
+            SocialRecovery::new(
+                AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(pub_key) },
+                &[guardian_id],
+                1,
+                0,
+            )
+            .unwrap(),
+        )
+        .with_component(
+            SpendingLimits::new(
+                AuthScheme::RpoFalcon512 { pub_key: cosigner_pub_key },
+                &[SpendingLimit::new(faucet_id, LIMIT, WINDOW_LENGTH).unwrap()],
+            )
+            .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let asset: Asset = FungibleAsset::new(faucet_id, 500).unwrap().into();
+    let mut account = Account::from_parts(
+        account.id(),
+        AssetVault::new(&[asset]).unwrap(),
+        account.storage().clone(),
+        account.code().clone(),
+        ONE,
+    );
+    let block_num = get_block_num(&account);
+
+    // CONSTRUCT AND EXECUTE TX (Success - the spending is recorded in the slots of the limits)
+    // --------------------------------------------------------------------------------------------
+    let executed_transaction =
+        execute_transaction(&account, build_send_asset_tx_args(60), falcon_auth.clone()).unwrap();
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    assert_eq!(
+        account.storage().get_map_item(7, get_faucet_map_key()).unwrap(),
+        [Felt::new(60), Felt::from(block_num / WINDOW_LENGTH), ZERO, ZERO]
+    );
+
+    // CONSTRUCT AND EXECUTE TX (Failure - the limit still applies)
+    // --------------------------------------------------------------------------------------------
+    let executed_transaction =
+        execute_transaction(&account, build_send_asset_tx_args(60), falcon_auth.clone());
+    assert_signature_missing(executed_transaction);
+
+    // CONSTRUCT AND EXECUTE TX (Success - the recovery replaces the key in the slot of the
+    // recovery)
+    // --------------------------------------------------------------------------------------------
+    let note = create_recovery_note(
+        guardian_id,
+        account.id(),
+        AuthScheme::RpoFalcon512 { pub_key: new_pub_key },
+        NoteType::Public,
+        &mut RpoRandomCoin::new([ONE, Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(
+        build_approve_recovery_tx_script(
+            AuthScheme::RpoFalcon512 { pub_key: new_pub_key },
+            &[guardian_id],
+        )
+        .unwrap(),
+    );
+    let tx_context = TransactionContextBuilder::new(account.clone())
+        .input_notes(vec![note.clone()])
+        .build();
+    let executor = TransactionExecutor::new(tx_context.clone(), Some(falcon_auth));
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let executed_transaction = executor
+        .execute_transaction(account.id(), block_ref, &[note.id()], tx_args)
+        .unwrap();
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    let new_auth = Rc::new(BasicAuthenticator::<StdRng>::new(&[(
+        new_pub_key.into(),
+        AuthSecretKey::RpoFalcon512(new_sec_key),
+    )]));
+    let tx_args = TransactionArgs::with_tx_script(
+        build_finalize_recovery_tx_script(AuthScheme::RpoFalcon512 { pub_key: new_pub_key })
+            .unwrap(),
+    );
+    let executed_transaction = execute_transaction(&account, tx_args, new_auth).unwrap();
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    assert_eq!(account.storage().get_item(0), Word::from(new_pub_key).into());
+    assert_eq!(account.storage().get_item(5), Word::from(cosigner_pub_key).into());
+    assert_eq!(
+        account.storage().get_map_item(7, get_faucet_map_key()).unwrap(),
+        [Felt::new(60), Felt::from(block_num / WINDOW_LENGTH), ZERO, ZERO]
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns an existing wallet holding 500 units of the fungible faucet limited to 100 units per 10
+/// blocks, together with the authenticator of its key and the co-signer key pair.
+fn get_wallet_with_spending_limits() -> (Account, Rc<BasicAuthenticator<StdRng>>, (Word, SecretKey))
+{
+    let (pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let cosigner_sec_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([1_u8; 32]));
+    let cosigner_pub_key = cosigner_sec_key.public_key();
+
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let (account, _) = create_wallet_with_spending_limits(
+        [3; 32],
+        AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(pub_key) },
+        AuthScheme::RpoFalcon512 { pub_key: cosigner_pub_key },
+        &[SpendingLimit::new(faucet_id, LIMIT, WINDOW_LENGTH).unwrap()],
+        AccountType::RegularAccountUpdatableCode,
+        AccountStorageType::OffChain,
+    )
+    .unwrap();
+
+    let asset: Asset = FungibleAsset::new(faucet_id, 500).unwrap().into();
+    let account = Account::from_parts(
+        account.id(),
+        AssetVault::new(&[asset]).unwrap(),
+        account.storage().clone(),
+        account.code().clone(),
+        ONE,
+    );
+
+    (account, falcon_auth, (cosigner_pub_key.into(), cosigner_sec_key))
+}
+
+fn get_block_num(account: &Account) -> u32 {
+    let tx_context = TransactionContextBuilder::new(account.clone()).build();
+    tx_context.tx_inputs().block_header().block_num()
+}
+
+fn get_faucet_map_key() -> Word {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    [ZERO, ZERO, ZERO, faucet_id.into()]
+}
+
+fn get_spent_amount(account: &Account) -> Word {
+    account.storage().get_map_item(3, get_faucet_map_key()).unwrap()
+}
+
+fn build_send_asset_tx_args(amount: u64) -> TransactionArgs {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let asset: Asset = FungibleAsset::new(faucet_id, amount).unwrap().into();
+    let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+
+    let tx_script_src = format!(
+        "
+        begin
+            push.{recipient}
+            push.{note_execution_hint}
+            push.{note_type}
+            push.0
+            push.{tag}
+            push.{asset}
+            call.::miden::contracts::limits::spending::send_asset
+            dropw dropw dropw dropw
+            call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+        end
+        ",
+        recipient = prepare_word(&recipient),
+        note_execution_hint = Felt::from(NoteExecutionHint::always()),
+        note_type = NoteType::Private as u8,
+        tag = NoteTag::for_local_use_case(0, 0).unwrap(),
+        asset = prepare_word(&asset.into()),
+    );
+
+    build_tx_args_from_script(&tx_script_src)
+}
+
+fn execute_transaction(
+    account: &Account,
+    tx_args: TransactionArgs,
+    authenticator: Rc<BasicAuthenticator<StdRng>>,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let tx_context = TransactionContextBuilder::new(account.clone()).build();
+    let executor = TransactionExecutor::new(tx_context.clone(), Some(authenticator));
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    executor.execute_transaction(account.id(), block_ref, &[], tx_args)
+}
@@ -31,7 +31,7 @@ pub const REGULAR_ACCOUNT_IMMUTABLE_CODE: u64 = 0b00;
 pub const REGULAR_ACCOUNT_UPDATABLE_CODE: u64 = 0b01;
 
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(u64)]
 pub enum AccountType {
     FungibleFaucet = FUNGIBLE_FAUCET,
@@ -0,0 +1,359 @@
+use alloc::{collections::BTreeMap, string::ToString, sync::Arc, vec::Vec};
+
+use vm_core::mast::{MastForest, MastForestError, MastNode, MastNodeId};
+
+use super::{
+    Account, AccountCode, AccountComponent, AccountError, AccountId, AccountProcedureInfo,
+    AccountStorage, AccountStorageType, AccountType, SlotItem, StorageMap, Word,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Index of the storage slot which is reserved by the transaction kernel in faucet accounts.
+const FAUCET_RESERVED_SLOT: u8 = 254;
+
+// ACCOUNT BUILDER
+// ================================================================================================
+
+/// A builder assembling a new [Account] from a set of [AccountComponent]s.
+///
+/// Components are laid out in the order in which they are added to the builder:
+/// - The storage slots of each component are placed right after the slots of the previously added
+///   components. The index of the first slot of a component is used as the storage offset of all
+///   procedures of the component, and the number of slots of the component as their storage size.
+///   Thus, procedures of a component can only access the slots of the component.
+/// - The procedures of all components are merged into the public interface of the account.
+///
+/// Slot 254 is reserved by the transaction kernel in faucet accounts. In non-fungible faucets,
+/// the builder initializes it with the empty map tracking the issued assets.
+///
+/// Once the code and storage of the account are assembled, the builder grinds a seed for the
+/// account ID of the configured type and storage mode.
+///
+/// By default, the builder creates an off-chain regular account with updatable code.
+#[derive(Debug, Clone)]
+pub struct AccountBuilder {
+    init_seed: [u8; 32],
+    account_type: AccountType,
+    storage_type: AccountStorageType,
+    components: Vec<AccountComponent>,
+}
+
+impl AccountBuilder {
+    /// Returns a new [AccountBuilder] which uses the provided initial seed to grind the seed of
+    /// the account ID.
+    pub fn new(init_seed: [u8; 32]) -> Self {
+        Self {
+            init_seed,
+            account_type: AccountType::RegularAccountUpdatableCode,
+            storage_type: AccountStorageType::OffChain,
+            components: Vec::new(),
+        }
+    }
+
+    /// Sets the type of the account.
+    pub fn account_type(mut self, account_type: AccountType) -> Self {
+        self.account_type = account_type;
+        self
+    }
+
+    /// Sets the storage mode of the account.
+    pub fn storage_type(mut self, storage_type: AccountStorageType) -> Self {
+        self.storage_type = storage_type;
+        self
+    }
+
+    /// Adds a component to the account.
+    pub fn with_component(mut self, component: impl Into<AccountComponent>) -> Self {
+        self.components.push(component.into());
+        self
+    }
+
+    /// Builds the account and returns it together with the seed of its ID.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - No components were added to the builder.
+    /// - Any of the components does not support the configured account type.
+    /// - Two components export the same procedure.
+    /// - The components use more storage slots than available to an account of the configured type,
+    ///   or more than 256 procedures in total.
+    /// - Storage of the account could not be instantiated from the storage slots of the components.
+    /// - A seed for the account ID could not be found.
+    pub fn build(self) -> Result<(Account, Word), AccountError> {
+        if self.components.is_empty() {
+            return Err(AccountError::AccountCodeNoProcedures);
+        }
+
+        // faucets have one slot less available as it is used by the transaction kernel to store
+        // faucet-specific data
+        let max_slots = match self.account_type {
+            AccountType::FungibleFaucet | AccountType::NonFungibleFaucet => FAUCET_RESERVED_SLOT,
+            _ => AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX,
+        } as usize;
+        let num_slots = self.components.iter().map(|c| c.storage_slots().len()).sum::<usize>();
+        if num_slots > max_slots {
+            return Err(AccountError::AccountComponentTooManyStorageSlots {
+                max: max_slots,
+                actual: num_slots,
+            });
+        }
+
+        let mut procedures: Vec<AccountProcedureInfo> = Vec::new();
+        let mut storage_items = Vec::new();
+        let mut storage_maps = BTreeMap::new();
+        let mut storage_offset = 0_u8;
+        for component in self.components.iter() {
+            if !component.supports_type(self.account_type) {
+                return Err(AccountError::AccountComponentUnsupportedAccountType(
+                    self.account_type,
+                ));
+            }
+
+            for proc_root in component.procedure_roots() {
+                if procedures.iter().any(|procedure| procedure.mast_root() == &proc_root) {
+                    return Err(AccountError::AccountComponentDuplicateProcedure(proc_root));
+                }
+                procedures.push(AccountProcedureInfo::new(
+                    proc_root,
+                    storage_offset,
+                    component.storage_size(),
+                )?);
+            }
+
+            for (index, slot) in component.storage_slots().iter().enumerate() {
+                storage_items.push(SlotItem {
+                    index: storage_offset + index as u8,
+                    slot: slot.clone(),
+                });
+            }
+            for (index, map) in component.storage_maps() {
+                storage_maps.insert(storage_offset + index, map.clone());
+            }
+
+            storage_offset += component.storage_size();
+        }
+
+        if self.account_type == AccountType::NonFungibleFaucet {
+            let nft_map = StorageMap::new();
+            storage_items.push(SlotItem::new_map(FAUCET_RESERVED_SLOT, 0, nft_map.root().into()));
+            storage_maps.insert(FAUCET_RESERVED_SLOT, nft_map);
+        }
+
+        if procedures.len() > AccountCode::MAX_NUM_PROCEDURES {
+            return Err(AccountError::AccountCodeTooManyProcedures {
+                max: AccountCode::MAX_NUM_PROCEDURES,
+                actual: procedures.len(),
+            });
+        }
+
+        let mast = merge_mast_forests(
+            self.components.iter().map(|component| component.library().mast_forest()),
+        )?;
+        let code = AccountCode::from_parts(Arc::new(mast), procedures);
+        let storage = AccountStorage::new(storage_items, storage_maps)?;
+
+        let seed = AccountId::get_account_seed(
+            self.init_seed,
+            self.account_type,
+            self.storage_type,
+            code.commitment(),
+            storage.root(),
+        )?;
+
+        Ok((Account::new(seed, code, storage)?, seed))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Merges the provided MAST forests into a single forest containing all nodes and procedure roots
+/// of the provided forests.
+///
+/// The assembler links compiled libraries by reference only, i.e., procedures of a library linked
+/// into an assembler are compiled into external nodes. Thus, the forests of the components are
+/// merged by re-adding their nodes via the [MastForest] constructors, which recompute the digest
+/// of each node from its children. Nodes of each forest are assumed to be ordered such that
+/// children precede their parents, which is the case for all forests produced by the assembler.
+fn merge_mast_forests<'a>(
+    forests: impl IntoIterator<Item = &'a MastForest>,
+) -> Result<MastForest, AccountError> {
+    let mut merged = MastForest::new();
+    for forest in forests {
+        let mut id_map: Vec<MastNodeId> = Vec::with_capacity(forest.num_nodes() as usize);
+        for node in forest.nodes() {
+            let new_id = add_mast_node(&mut merged, node, &id_map)
+                .map_err(|err| AccountError::AccountCodeAssemblyError(err.to_string()))?;
+            if merged[new_id].digest() != node.digest() {
+                return Err(AccountError::AccountCodeAssemblyError(
+                    "merged MAST node digest does not match the digest of the original node"
+                        .to_string(),
+                ));
+            }
+            id_map.push(new_id);
+        }
+
+        for root in forest.procedure_roots() {
+            merged.make_root(id_map[root.as_usize()]);
+        }
+    }
+
+    Ok(merged)
+}
+
+/// Adds the provided node to the forest, mapping the IDs of its children via the provided map.
+fn add_mast_node(
+    forest: &mut MastForest,
+    node: &MastNode,
+    id_map: &[MastNodeId],
+) -> Result<MastNodeId, MastForestError> {
+    match node {
+        MastNode::Join(join) => {
+            forest.add_join(id_map[join.first().as_usize()], id_map[join.second().as_usize()])
+        },
+        MastNode::Split(split) => forest
+            .add_split(id_map[split.on_true().as_usize()], id_map[split.on_false().as_usize()]),
+        MastNode::Loop(loop_node) => forest.add_loop(id_map[loop_node.body().as_usize()]),
+        MastNode::Call(call) if call.is_syscall() => {
+            forest.add_syscall(id_map[call.callee().as_usize()])
+        },
+        MastNode::Call(call) => forest.add_call(id_map[call.callee().as_usize()]),
+        MastNode::Dyn => forest.add_dyn(),
+        MastNode::External(external) => forest.add_external(external.digest()),
+        MastNode::Block(_) => forest.add_node(node.clone()),
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use assembly::Assembler;
+
+    use super::{
+        AccountBuilder, AccountComponent, AccountError, AccountType, FAUCET_RESERVED_SLOT,
+    };
+    use crate::{
+        accounts::{StorageMap, StorageSlot},
+        Felt, Word, ONE, ZERO,
+    };
+
+    const VALUE_0: Word = [ONE, ZERO, ZERO, ZERO];
+    const VALUE_1: Word = [ZERO, ONE, ZERO, ZERO];
+    const VALUE_2: Word = [ZERO, ZERO, ONE, ZERO];
+
+    fn component(source_code: &str, storage_slots: Vec<StorageSlot>) -> AccountComponent {
+        AccountComponent::compile(source_code, Assembler::default(), storage_slots)
+            .unwrap()
+            .with_supported_type(AccountType::RegularAccountUpdatableCode)
+    }
+
+    #[test]
+    fn test_account_builder_assigns_storage_offsets() {
+        let component_0 = component(
+            "export.foo push.1 drop end",
+            vec![StorageSlot::new_value(VALUE_0), StorageSlot::new_value(VALUE_1)],
+        );
+        let component_1 =
+            component("export.bar push.2 drop end", vec![StorageSlot::new_value(VALUE_2)]);
+        let foo_root = component_0.procedure_roots().next().unwrap();
+        let bar_root = component_1.procedure_roots().next().unwrap();
+
+        let (account, seed) = AccountBuilder::new([7; 32])
+            .with_component(component_0)
+            .with_component(component_1)
+            .build()
+            .unwrap();
+
+        assert_eq!(account.id().account_type(), AccountType::RegularAccountUpdatableCode);
+        assert!(account.is_new());
+        assert_ne!(seed, [ZERO; 4]);
+
+        // procedures of the second component are offset by the slots of the first one
+        let code = account.code();
+        assert_eq!(code.num_procedures(), 2);
+        let foo_index = code.get_procedure_index_by_root(foo_root).unwrap();
+        let bar_index = code.get_procedure_index_by_root(bar_root).unwrap();
+        assert_eq!(code.get_procedure_by_index(foo_index).storage_offset(), 0);
+        assert_eq!(code.get_procedure_by_index(foo_index).storage_size(), 2);
+        assert_eq!(code.get_procedure_by_index(bar_index).storage_offset(), 2);
+        assert_eq!(code.get_procedure_by_index(bar_index).storage_size(), 1);
+        assert!(code.mast().find_procedure_root(foo_root).is_some());
+        assert!(code.mast().find_procedure_root(bar_root).is_some());
+
+        assert_eq!(account.storage().get_item(0), VALUE_0.into());
+        assert_eq!(account.storage().get_item(1), VALUE_1.into());
+        assert_eq!(account.storage().get_item(2), VALUE_2.into());
+    }
+
+    #[test]
+    fn test_account_builder_reserves_faucet_slot() {
+        let faucet_component = |account_type| {
+            AccountComponent::compile(
+                "export.foo push.1 drop end",
+                Assembler::default(),
+                vec![StorageSlot::new_value(VALUE_0)],
+            )
+            .unwrap()
+            .with_supported_type(account_type)
+        };
+
+        // non-fungible faucets track the issued assets in a map in the reserved slot
+        let (account, _) = AccountBuilder::new([7; 32])
+            .account_type(AccountType::NonFungibleFaucet)
+            .with_component(faucet_component(AccountType::NonFungibleFaucet))
+            .build()
+            .unwrap();
+        let nft_map = account.storage().maps().get(&FAUCET_RESERVED_SLOT).unwrap();
+        assert_eq!(nft_map.root(), StorageMap::new().root());
+        assert_eq!(account.storage().get_item(FAUCET_RESERVED_SLOT), nft_map.root());
+
+        // fungible faucets track the total issuance in the reserved slot
+        let (account, _) = AccountBuilder::new([7; 32])
+            .account_type(AccountType::FungibleFaucet)
+            .with_component(faucet_component(AccountType::FungibleFaucet))
+            .build()
+            .unwrap();
+        assert!(account.storage().maps().is_empty());
+        assert_eq!(account.storage().get_item(FAUCET_RESERVED_SLOT), [ZERO; 4].into());
+    }
+
+    #[test]
+    fn test_account_builder_rejects_invalid_components() {
+        let component_0 = component("export.foo push.1 drop end", vec![]);
+
+        // the component does not support faucets
+        let result = AccountBuilder::new([7; 32])
+            .account_type(AccountType::FungibleFaucet)
+            .with_component(component_0.clone())
+            .build();
+        assert_eq!(
+            result.unwrap_err(),
+            AccountError::AccountComponentUnsupportedAccountType(AccountType::FungibleFaucet)
+        );
+
+        // the same procedure cannot be part of two components
+        let foo_root = component_0.procedure_roots().next().unwrap();
+        let result = AccountBuilder::new([7; 32])
+            .with_component(component_0.clone())
+            .with_component(component_0)
+            .build();
+        assert_eq!(result.unwrap_err(), AccountError::AccountComponentDuplicateProcedure(foo_root));
+
+        // the components cannot use more slots than available
+        let slots = vec![StorageSlot::new_value([Felt::new(3), ZERO, ZERO, ZERO]); 200];
+        let result = AccountBuilder::new([7; 32])
+            .with_component(component("export.foo push.1 drop end", slots.clone()))
+            .with_component(component("export.bar push.2 drop end", slots))
+            .build();
+        assert_eq!(
+            result.unwrap_err(),
+            AccountError::AccountComponentTooManyStorageSlots { max: 255, actual: 400 }
+        );
+    }
+}
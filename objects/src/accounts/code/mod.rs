@@ -1,16 +1,25 @@
-use alloc::{string::ToString, sync::Arc, vec::Vec};
+use alloc::{collections::BTreeSet, string::ToString, sync::Arc, vec::Vec};
 
 use assembly::{Assembler, Compile, Library};
 use vm_core::mast::MastForest;
 
 use super::{
-    AccountError, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt,
-    Hasher, Serializable,
+    AccountError, AccountStorage, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    Digest, Felt, Hasher, Serializable,
 };
 
 pub mod procedure;
 use procedure::AccountProcedureInfo;
 
+// CONSTANTS
+// ================================================================================================
+
+/// Marker at the start of versioned serialized account code.
+///
+/// Account code serialized before the format was versioned starts with the magic value of the
+/// [MastForest] instead, which never begins with this byte.
+const VERSIONED_FORMAT_MARKER: u8 = 0xff;
+
 // ACCOUNT CODE
 // ================================================================================================
 
@@ -19,17 +28,20 @@ use procedure::AccountProcedureInfo;
 /// Account's public interface consists of a set of account procedures, each procedure being a
 /// Miden VM program. Thus, MAST root of each procedure commits to the underlying program.
 ///
-/// Each exported procedure is associated with a storage offset. This offset is applied to any
-/// accesses made from within the procedure to the associated account's storage. For example, if
-/// storage offset for a procedure is set ot 1, a call to the account::get_item(storage_slot=4)
-/// made from this procedure would actually access storage slot with index 5.
+/// Each exported procedure is associated with a storage offset and a storage size. The offset is
+/// applied to any accesses made from within the procedure to the associated account's storage,
+/// and the size limits the number of slots the procedure can access. For example, if storage
+/// offset for a procedure is set ot 1, a call to the account::get_item(storage_slot=4) made from
+/// this procedure would actually access storage slot with index 5, and would fail if the storage
+/// size of the procedure is smaller than 5.
 ///
 /// We commit to the entire account interface by building a sequential hash of all procedure MAST
-/// roots and associated storage_offset's. Specifically, each procedure contributes exactly 8 field
-/// elements to the sequence of elements to be hashed. These elements are defined as follows:
+/// roots and associated storage_offset's and storage_size's. Specifically, each procedure
+/// contributes exactly 8 field elements to the sequence of elements to be hashed. These elements
+/// are defined as follows:
 ///
 /// ```text
-/// [PROCEDURE_MAST_ROOT, storage_offset, 0, 0, 0]
+/// [PROCEDURE_MAST_ROOT, storage_offset, storage_size, 0, 0]
 /// ```
 #[derive(Debug, Clone)]
 pub struct AccountCode {
@@ -45,25 +57,39 @@ impl AccountCode {
     /// The maximum number of account interface procedures.
     pub const MAX_NUM_PROCEDURES: usize = 256;
 
+    /// The current version of the account code serialization format.
+    ///
+    /// Version 1 replaced the `u16` storage offset of each procedure with a `u8` storage offset
+    /// followed by a `u8` storage size. Account code serialized in the unversioned format is
+    /// rejected, as reading it with the new layout would silently assign a storage size of 0 to
+    /// every procedure.
+    pub const SERIALIZATION_VERSION: u8 = 1;
+
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
 
     /// Returns a new [AccountCode] instantiated from the provided [Library].
     ///
     /// All procedures exported from the provided library will become members of the account's
-    /// public interface.
+    /// public interface. The procedures have access to all storage slots of the account, i.e.,
+    /// their storage offset is set to 0 and their storage size to 255. To restrict the storage
+    /// accessible to a set of procedures, use [AccountBuilder](super::AccountBuilder).
     ///
     /// # Errors
-    /// Returns an error if the number of procedures exported from the provided library is smaller
-    /// than 1 or greater than 256.
+    /// Returns an error if:
+    /// - The number of procedures exported from the provided library is smaller than 1 or greater
+    ///   than 256.
+    /// - Two procedures exported from the provided library have the same MAST root.
     pub fn new(library: Library) -> Result<Self, AccountError> {
         // extract procedure information from the library exports
-        // TODO: currently, offsets for all procedures are set to 0; instead they should be read
-        // from the Library metadata
         let mut procedures: Vec<AccountProcedureInfo> = Vec::new();
         for module in library.module_infos() {
             for proc_mast_root in module.procedure_digests() {
-                procedures.push(AccountProcedureInfo::new(proc_mast_root, 0));
+                procedures.push(AccountProcedureInfo::new(
+                    proc_mast_root,
+                    0,
+                    AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX,
+                )?);
             }
         }
 
@@ -77,6 +103,10 @@ impl AccountCode {
             });
         }
 
+        if let Some(mast_root) = find_duplicate_procedure_root(&procedures) {
+            return Err(AccountError::AccountCodeDuplicateProcedureRoot(mast_root));
+        }
+
         Ok(Self {
             commitment: build_procedure_commitment(&procedures),
             procedures,
@@ -117,11 +147,16 @@ impl AccountCode {
     /// # Panics
     /// Panics if:
     /// - The number of procedures is smaller than 1 or greater than 256.
+    /// - Two of the provided procedures have the same MAST root.
     /// - If some any of the provided procedures does not have a corresponding root in the provided
     ///   MAST forest.
     pub fn from_parts(mast: Arc<MastForest>, procedures: Vec<AccountProcedureInfo>) -> Self {
         assert!(!procedures.is_empty(), "no account procedures");
         assert!(procedures.len() <= Self::MAX_NUM_PROCEDURES, "too many account procedures");
+        assert!(
+            find_duplicate_procedure_root(&procedures).is_none(),
+            "duplicate account procedure roots"
+        );
 
         Self {
             commitment: build_procedure_commitment(&procedures),
@@ -160,7 +195,7 @@ impl AccountCode {
 
     /// Returns true if a procedure with the specified MAST root is defined in this account code.
     pub fn has_procedure(&self, mast_root: Digest) -> bool {
-        self.procedures.iter().any(|procedure| procedure.mast_root() == &mast_root)
+        self.get_procedure_index_by_root(mast_root).is_some()
     }
 
     /// Returns information about the procedure at the specified index.
@@ -184,7 +219,7 @@ impl AccountCode {
     ///
     /// This is done by first converting each procedure into exactly 8 elements as follows:
     /// ```text
-    /// [PROCEDURE_MAST_ROOT, storage_offset, 0, 0, 0]
+    /// [PROCEDURE_MAST_ROOT, storage_offset, storage_size, 0, 0]
     /// ```
     /// And then concatenating the resulting elements into a single vector.
     pub fn as_elements(&self) -> Vec<Felt> {
@@ -209,6 +244,8 @@ impl Eq for AccountCode {}
 
 impl Serializable for AccountCode {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(VERSIONED_FORMAT_MARKER);
+        target.write_u8(Self::SERIALIZATION_VERSION);
         self.mast.write_into(target);
         // since the number of procedures is guaranteed to be between 1 and 256, we can store the
         // number as a single byte - but we do have to subtract 1 to store 256 as 255.
@@ -219,9 +256,27 @@ impl Serializable for AccountCode {
 
 impl Deserializable for AccountCode {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        if source.read_u8()? != VERSIONED_FORMAT_MARKER {
+            return Err(DeserializationError::InvalidValue(
+                "account code serialized in the unversioned format is not supported".to_string(),
+            ));
+        }
+        let version = source.read_u8()?;
+        if version != Self::SERIALIZATION_VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported account code version {version}, the supported version is {}",
+                Self::SERIALIZATION_VERSION
+            )));
+        }
+
         let module = Arc::new(MastForest::read_from(source)?);
         let num_procedures = (source.read_u8()? as usize) + 1;
         let procedures = source.read_many::<AccountProcedureInfo>(num_procedures)?;
+        if let Some(mast_root) = find_duplicate_procedure_root(&procedures) {
+            return Err(DeserializationError::InvalidValue(format!(
+                "duplicate account procedure root {mast_root}"
+            )));
+        }
 
         Ok(Self::from_parts(module, procedures))
     }
@@ -238,6 +293,15 @@ fn procedures_as_elements(procedures: &[AccountProcedureInfo]) -> Vec<Felt> {
         .collect()
 }
 
+/// Returns the first MAST root shared by two of the given procedures, if any.
+fn find_duplicate_procedure_root(procedures: &[AccountProcedureInfo]) -> Option<Digest> {
+    let mut mast_roots = BTreeSet::new();
+    procedures
+        .iter()
+        .map(|procedure| *procedure.mast_root())
+        .find(|mast_root| !mast_roots.insert(*mast_root))
+}
+
 /// Computes the commitment to the given procedures
 fn build_procedure_commitment(procedures: &[AccountProcedureInfo]) -> Digest {
     let elements = procedures_as_elements(procedures);
@@ -250,8 +314,15 @@ fn build_procedure_commitment(procedures: &[AccountProcedureInfo]) -> Digest {
 #[cfg(test)]
 mod tests {
 
-    use super::{AccountCode, Deserializable, Serializable};
-    use crate::accounts::code::build_procedure_commitment;
+    use alloc::{vec, vec::Vec};
+
+    use assembly::Assembler;
+
+    use super::{
+        AccountCode, AccountProcedureInfo, ByteWriter, Deserializable, Serializable,
+        VERSIONED_FORMAT_MARKER,
+    };
+    use crate::{accounts::code::build_procedure_commitment, AccountError};
 
     #[test]
     fn test_serde() {
@@ -261,10 +332,49 @@ mod tests {
         assert_eq!(deserialized, code)
     }
 
+    #[test]
+    fn test_serde_rejects_unsupported_versions() {
+        let code = AccountCode::mock();
+
+        // account code serialized before the format was versioned stored a u16 storage offset and
+        // no storage size for each procedure
+        let mut legacy_bytes = Vec::new();
+        code.mast().write_into(&mut legacy_bytes);
+        legacy_bytes.write_u8((code.num_procedures() - 1) as u8);
+        for procedure in code.procedures() {
+            procedure.mast_root().write_into(&mut legacy_bytes);
+            legacy_bytes.write_u16(procedure.storage_offset() as u16);
+        }
+        assert!(AccountCode::read_from_bytes(&legacy_bytes).is_err());
+
+        let mut future_bytes = code.to_bytes();
+        future_bytes[1] = AccountCode::SERIALIZATION_VERSION + 1;
+        assert!(AccountCode::read_from_bytes(&future_bytes).is_err());
+    }
+
     #[test]
     fn test_account_code_procedure_commitment() {
         let code = AccountCode::mock();
         let procedure_commitment = build_procedure_commitment(code.procedures());
         assert_eq!(procedure_commitment, code.commitment())
     }
+
+    #[test]
+    fn test_account_code_rejects_duplicate_procedure_roots() {
+        let source_code = "export.foo push.1 drop end export.bar push.1 drop end";
+        let result = AccountCode::compile(source_code, Assembler::default());
+        assert!(matches!(result, Err(AccountError::AccountCodeDuplicateProcedureRoot(_))));
+
+        // the same procedure with a different storage offset is rejected on deserialization too
+        let code = AccountCode::mock();
+        let procedure = code.procedures()[0].clone();
+        let mut bytes = vec![VERSIONED_FORMAT_MARKER, AccountCode::SERIALIZATION_VERSION];
+        code.mast().write_into(&mut bytes);
+        bytes.push(1);
+        procedure.write_into(&mut bytes);
+        AccountProcedureInfo::new(*procedure.mast_root(), 1, 1)
+            .unwrap()
+            .write_into(&mut bytes);
+        assert!(AccountCode::read_from_bytes(&bytes).is_err());
+    }
 }
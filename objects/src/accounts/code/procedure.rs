@@ -1,3 +1,5 @@
+use alloc::string::ToString;
+
 use vm_core::{
     utils::{ByteReader, ByteWriter, Deserializable, Serializable},
     FieldElement,
@@ -5,21 +7,24 @@ use vm_core::{
 use vm_processor::DeserializationError;
 
 use super::{Digest, Felt};
-use crate::AccountError;
+use crate::{accounts::AccountStorage, AccountError};
 
 // ACCOUNT PROCEDURE INFO
 // ================================================================================================
 
 /// Information about a procedure exposed in a public account interface.
 ///
-/// The info included the MAST root of the procedure and the storage offset applied to all account
-/// storage-related accesses made by this procedure. For example, if storage offset is set ot 1, a
-/// call to the account::get_item(storage_slot=4) made from this procedure would actually access
-/// storage slot with index 5.
+/// The info included the MAST root of the procedure, the storage offset applied to all account
+/// storage-related accesses made by this procedure and the storage size, i.e., the number of slots
+/// the procedure can access. For example, if storage offset is set ot 1, a call to the
+/// account::get_item(storage_slot=4) made from this procedure would actually access storage slot
+/// with index 5. The transaction kernel rejects the access if the storage size of the procedure is
+/// not greater than 4.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct AccountProcedureInfo {
     mast_root: Digest,
-    storage_offset: u16,
+    storage_offset: u8,
+    storage_size: u8,
 }
 
 impl AccountProcedureInfo {
@@ -27,8 +32,25 @@ impl AccountProcedureInfo {
     pub const NUM_ELEMENTS_PER_PROC: usize = 8;
 
     /// Returns a new instance of an [AccountProcedureInfo].
-    pub fn new(mast_root: Digest, storage_offset: u16) -> Self {
-        Self { mast_root, storage_offset }
+    ///
+    /// # Errors
+    /// Returns an error if the storage slots accessible to the procedure extend beyond the last
+    /// storage slot available to account procedures.
+    pub fn new(
+        mast_root: Digest,
+        storage_offset: u8,
+        storage_size: u8,
+    ) -> Result<Self, AccountError> {
+        if storage_offset as u16 + storage_size as u16
+            > AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX as u16
+        {
+            return Err(AccountError::AccountCodeProcedureStorageOutOfBounds {
+                storage_offset,
+                storage_size,
+            });
+        }
+
+        Ok(Self { mast_root, storage_offset, storage_size })
     }
 
     /// Returns a reference to the procedure's mast_root.
@@ -36,10 +58,15 @@ impl AccountProcedureInfo {
         &self.mast_root
     }
 
-    /// Returns a reference to the procedure's storage_offset.
-    pub fn storage_offset(&self) -> u16 {
+    /// Returns the procedure's storage_offset.
+    pub fn storage_offset(&self) -> u8 {
         self.storage_offset
     }
+
+    /// Returns the number of storage slots accessible to the procedure.
+    pub fn storage_size(&self) -> u8 {
+        self.storage_size
+    }
 }
 
 impl From<AccountProcedureInfo> for [Felt; 8] {
@@ -52,6 +79,9 @@ impl From<AccountProcedureInfo> for [Felt; 8] {
         // copy the storage offset into value[4]
         result[4] = Felt::from(value.storage_offset());
 
+        // copy the storage size into value[5]
+        result[5] = Felt::from(value.storage_size());
+
         result
     }
 }
@@ -64,32 +94,42 @@ impl TryFrom<[Felt; 8]> for AccountProcedureInfo {
         let mast_root = Digest::from(<[Felt; 4]>::try_from(&value[0..4]).unwrap());
 
         // get storage_offset form value[4]
-        let storage_offset: u16 = value[4]
+        let storage_offset: u8 = value[4]
             .try_into()
             .map_err(|_| AccountError::AccountCodeProcedureInvalidStorageOffset)?;
 
-        // Check if the last three elements are zero
-        if value[5..].iter().any(|&x| x != Felt::ZERO) {
+        // get storage_size form value[5]
+        let storage_size: u8 = value[5]
+            .try_into()
+            .map_err(|_| AccountError::AccountCodeProcedureInvalidStorageSize)?;
+
+        // Check if the last two elements are zero
+        if value[6..].iter().any(|&x| x != Felt::ZERO) {
             return Err(AccountError::AccountCodeProcedureInvalidPadding);
         }
 
-        Ok(Self { mast_root, storage_offset })
+        Self::new(mast_root, storage_offset, storage_size)
     }
 }
 
+/// Procedure infos are not versioned on their own; the layout of serialized procedures is covered
+/// by the version of the [AccountCode](super::AccountCode) serialization format.
 impl Serializable for AccountProcedureInfo {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         target.write(self.mast_root());
-        target.write_u16(self.storage_offset());
+        target.write_u8(self.storage_offset());
+        target.write_u8(self.storage_size());
     }
 }
 
 impl Deserializable for AccountProcedureInfo {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let mast_root: Digest = source.read()?;
-        let storage_offset = source.read_u16()?;
+        let storage_offset = source.read_u8()?;
+        let storage_size = source.read_u8()?;
 
-        Ok(Self::new(mast_root, storage_offset))
+        Self::new(mast_root, storage_offset, storage_size)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
     }
 }
 
@@ -0,0 +1,163 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::ToString,
+    vec::Vec,
+};
+
+use assembly::{Assembler, Compile, Library};
+
+use super::{
+    AccountError, AccountStorage, AccountType, Digest, StorageMap, StorageSlot, StorageSlotType,
+};
+
+// ACCOUNT COMPONENT
+// ================================================================================================
+
+/// A building block of an account, consisting of a set of procedures and the storage slots these
+/// procedures operate on.
+///
+/// Storage slots of a component are addressed by their position in the component, starting at
+/// zero. When an account is assembled from multiple components via
+/// [AccountBuilder](super::AccountBuilder), the slots of each component are placed after the slots
+/// of the previously added components, and the index of the first slot of the component is
+/// recorded as the storage offset of each of the component's procedures (see
+/// [AccountProcedureInfo](super::AccountProcedureInfo)).
+///
+/// A component also declares the types of accounts it can be part of. By default, a component
+/// does not support any account type.
+#[derive(Debug, Clone)]
+pub struct AccountComponent {
+    library: Library,
+    storage_slots: Vec<StorageSlot>,
+    storage_maps: BTreeMap<u8, StorageMap>,
+    supported_types: BTreeSet<AccountType>,
+}
+
+impl AccountComponent {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [AccountComponent] instantiated from the provided library and storage slots.
+    ///
+    /// All procedures exported from the provided library become part of the component. The slots
+    /// are assigned to the component in the order in which they are provided.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The library does not export any procedures.
+    /// - The number of storage slots is greater than the number of slots available to accounts.
+    pub fn new(library: Library, storage_slots: Vec<StorageSlot>) -> Result<Self, AccountError> {
+        if library.module_infos().all(|module| module.procedure_digests().next().is_none()) {
+            return Err(AccountError::AccountCodeNoProcedures);
+        }
+
+        let max_slots = AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX as usize;
+        if storage_slots.len() > max_slots {
+            return Err(AccountError::AccountComponentTooManyStorageSlots {
+                max: max_slots,
+                actual: storage_slots.len(),
+            });
+        }
+
+        Ok(Self {
+            library,
+            storage_slots,
+            storage_maps: BTreeMap::new(),
+            supported_types: BTreeSet::new(),
+        })
+    }
+
+    /// Returns a new [AccountComponent] compiled from the provided source code using the specified
+    /// assembler and instantiated with the provided storage slots.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Compilation of the provided source code fails.
+    /// - Any of the conditions listed for [AccountComponent::new()] is violated.
+    pub fn compile(
+        source_code: impl Compile,
+        assembler: Assembler,
+        storage_slots: Vec<StorageSlot>,
+    ) -> Result<Self, AccountError> {
+        let library = assembler
+            .assemble_library([source_code])
+            .map_err(|report| AccountError::AccountCodeAssemblyError(report.to_string()))?;
+
+        Self::new(library, storage_slots)
+    }
+
+    /// Attaches the provided storage map to the map slot at the specified position of this
+    /// component.
+    ///
+    /// # Errors
+    /// Returns an error if the component does not have a map slot at the specified position.
+    pub fn with_storage_map(mut self, index: u8, map: StorageMap) -> Result<Self, AccountError> {
+        match self.storage_slots.get(index as usize) {
+            Some(StorageSlot {
+                slot_type: StorageSlotType::Map { .. }, ..
+            }) => {
+                self.storage_maps.insert(index, map);
+                Ok(self)
+            },
+            _ => Err(AccountError::StorageMapNotFound(index)),
+        }
+    }
+
+    /// Marks the provided account type as supported by this component.
+    pub fn with_supported_type(mut self, account_type: AccountType) -> Self {
+        self.supported_types.insert(account_type);
+        self
+    }
+
+    /// Marks all account types as supported by this component.
+    pub fn with_supports_all_types(mut self) -> Self {
+        self.supported_types.extend([
+            AccountType::RegularAccountImmutableCode,
+            AccountType::RegularAccountUpdatableCode,
+            AccountType::FungibleFaucet,
+            AccountType::NonFungibleFaucet,
+        ]);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a reference to the library backing this component.
+    pub fn library(&self) -> &Library {
+        &self.library
+    }
+
+    /// Returns an iterator over the MAST roots of the procedures exported by this component.
+    pub fn procedure_roots(&self) -> impl Iterator<Item = Digest> + '_ {
+        self.library
+            .module_infos()
+            .flat_map(|module| module.procedure_digests().collect::<Vec<_>>())
+    }
+
+    /// Returns the storage slots of this component.
+    pub fn storage_slots(&self) -> &[StorageSlot] {
+        &self.storage_slots
+    }
+
+    /// Returns the storage maps of this component keyed by the position of their slot in the
+    /// component.
+    pub fn storage_maps(&self) -> &BTreeMap<u8, StorageMap> {
+        &self.storage_maps
+    }
+
+    /// Returns the number of storage slots used by this component.
+    pub fn storage_size(&self) -> u8 {
+        self.storage_slots.len() as u8
+    }
+
+    /// Returns the account types supported by this component.
+    pub fn supported_types(&self) -> &BTreeSet<AccountType> {
+        &self.supported_types
+    }
+
+    /// Returns true if this component can be part of an account of the provided type.
+    pub fn supports_type(&self, account_type: AccountType) -> bool {
+        self.supported_types.contains(&account_type)
+    }
+}
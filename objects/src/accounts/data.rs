@@ -12,14 +12,15 @@ use super::{
     super::utils::serde::{
         ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
     },
-    Account, AuthSecretKey, Word,
+    Account, AccountError, AuthSecretKey, StorageSchema, Word,
 };
 
 // ACCOUNT DATA
 // ================================================================================================
 
 /// Account data contains a complete description of an account, including the [Account] struct as
-/// well as account seed and account authentication info.
+/// well as account seed, account authentication info and an optional [StorageSchema] describing the
+/// storage of the account.
 ///
 /// The intent of this struct is to provide an easy way to serialize and deserialize all
 /// account-related data as a single unit (e.g., to/from files).
@@ -28,6 +29,7 @@ pub struct AccountData {
     pub account: Account,
     pub account_seed: Option<Word>,
     pub auth_secret_key: AuthSecretKey,
+    pub storage_schema: Option<StorageSchema>,
 }
 
 impl AccountData {
@@ -36,9 +38,20 @@ impl AccountData {
             account,
             account_seed,
             auth_secret_key: auth,
+            storage_schema: None,
         }
     }
 
+    /// Attaches the provided storage schema to this account data.
+    ///
+    /// # Errors
+    /// Returns an error if the schema does not match the storage layout of the account.
+    pub fn with_storage_schema(mut self, schema: StorageSchema) -> Result<Self, AccountError> {
+        schema.validate(self.account.storage())?;
+        self.storage_schema = Some(schema);
+        Ok(self)
+    }
+
     #[cfg(feature = "std")]
     /// Serialises and writes binary AccountData to specified file
     pub fn write(&self, filepath: impl AsRef<Path>) -> io::Result<()> {
@@ -67,11 +80,13 @@ impl Serializable for AccountData {
             account,
             account_seed,
             auth_secret_key: auth,
+            storage_schema,
         } = self;
 
         account.write_into(target);
         account_seed.write_into(target);
         auth.write_into(target);
+        storage_schema.write_into(target);
     }
 }
 
@@ -80,8 +95,19 @@ impl Deserializable for AccountData {
         let account = Account::read_from(source)?;
         let account_seed = <Option<Word>>::read_from(source)?;
         let auth_secret_key = AuthSecretKey::read_from(source)?;
-
-        Ok(Self::new(account, account_seed, auth_secret_key))
+        // account data serialized before storage schemas were introduced ends with the secret key
+        let storage_schema = if source.has_more_bytes() {
+            <Option<StorageSchema>>::read_from(source)?
+        } else {
+            None
+        };
+
+        Ok(Self {
+            account,
+            account_seed,
+            auth_secret_key,
+            storage_schema,
+        })
     }
 
     fn read_from_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
@@ -94,7 +120,7 @@ impl Deserializable for AccountData {
 
 #[cfg(test)]
 mod tests {
-    use alloc::collections::BTreeMap;
+    use alloc::{collections::BTreeMap, vec::Vec};
 
     use miden_crypto::{
         dsa::rpo_falcon512::SecretKey,
@@ -108,7 +134,8 @@ mod tests {
     use crate::{
         accounts::{
             account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN, storage,
-            Account, AccountCode, AccountId, AuthSecretKey, Felt, Word,
+            Account, AccountCode, AccountId, AuthSecretKey, Felt, SchemaValueType, SlotSchema,
+            SlotSchemaType, StorageSchema, Word,
         },
         assets::AssetVault,
     };
@@ -125,7 +152,13 @@ mod tests {
         let account_seed = Some(Word::default());
         let auth_secret_key = AuthSecretKey::RpoFalcon512(SecretKey::new());
 
+        let storage_schema = StorageSchema::new()
+            .with_slot(0, SlotSchema::new("value", SlotSchemaType::Value(SchemaValueType::Felt)))
+            .unwrap();
+
         AccountData::new(account, account_seed, auth_secret_key)
+            .with_storage_schema(storage_schema)
+            .unwrap()
     }
 
     #[test]
@@ -139,6 +172,23 @@ mod tests {
             deserialized.auth_secret_key.to_bytes(),
             account_data.auth_secret_key.to_bytes()
         );
+        assert_eq!(deserialized.storage_schema, account_data.storage_schema);
+    }
+
+    #[test]
+    fn test_deserialize_without_storage_schema() {
+        let account_data = build_account_data();
+
+        // account data written before storage schemas were introduced has no trailing schema
+        let mut bytes = Vec::new();
+        account_data.account.write_into(&mut bytes);
+        account_data.account_seed.write_into(&mut bytes);
+        account_data.auth_secret_key.write_into(&mut bytes);
+
+        let deserialized = AccountData::read_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.account, account_data.account);
+        assert_eq!(deserialized.account_seed, account_data.account_seed);
+        assert_eq!(deserialized.storage_schema, None);
     }
 
     #[cfg(feature = "std")]
@@ -13,9 +13,15 @@ pub use account_id::{
 pub mod auth;
 pub use auth::AuthSecretKey;
 
+mod builder;
+pub use builder::AccountBuilder;
+
 pub mod code;
 pub use code::{procedure::AccountProcedureInfo, AccountCode};
 
+mod component;
+pub use component::AccountComponent;
+
 pub mod delta;
 pub use delta::{
     AccountDelta, AccountStorageDelta, AccountVaultDelta, FungibleAssetDelta,
@@ -23,10 +29,16 @@ pub use delta::{
 };
 
 mod seed;
-pub use seed::{get_account_seed, get_account_seed_single};
+pub use seed::{
+    get_account_seed, get_account_seed_single, CancellationToken, SeedGrindCheckpoint,
+    SeedGrindOutcome, SeedGrinder, VanityPrefix,
+};
 
 mod storage;
-pub use storage::{AccountStorage, SlotItem, StorageMap, StorageSlot, StorageSlotType};
+pub use storage::{
+    AccountStorage, SchemaValueType, SlotItem, SlotSchema, SlotSchemaType, StorageDisplay,
+    StorageMap, StorageSchema, StorageSlot, StorageSlotType,
+};
 
 mod stub;
 pub use stub::AccountStub;
@@ -1,18 +1,23 @@
-use alloc::vec::Vec;
+use alloc::{format, string::ToString, sync::Arc, vec::Vec};
+use core::sync::atomic::{AtomicBool, Ordering};
 #[cfg(feature = "concurrent")]
-use std::{
-    sync::{
-        mpsc::{self, Sender},
-        Arc, RwLock,
-    },
-    thread::{self, spawn},
-};
+use std::{sync::mpsc, thread};
 
 use super::{
-    account_id::compute_digest, AccountError, AccountId, AccountStorageType, AccountType, Digest,
-    Felt, Word,
+    account_id::{
+        compute_digest, ACCOUNT_STORAGE_MASK_SHIFT, ACCOUNT_TYPE_MASK_SHIFT, OFF_CHAIN, ON_CHAIN,
+    },
+    AccountError, AccountId, AccountStorageType, AccountType, ByteReader, ByteWriter,
+    Deserializable, DeserializationError, Digest, Felt, Hasher, Serializable, Word,
 };
 
+// CONSTANTS
+// ================================================================================================
+
+/// Default number of iterations after which a grinding lane reports its progress and checks
+/// whether grinding should stop.
+const DEFAULT_REPORT_INTERVAL: u64 = 500_000;
+
 // SEED GENERATORS
 // --------------------------------------------------------------------------------------------
 
@@ -26,236 +31,907 @@ pub fn get_account_seed(
     code_commitment: Digest,
     storage_root: Digest,
 ) -> Result<Word, AccountError> {
-    let thread_count = thread::available_parallelism().map_or(1, |v| v.get());
-
-    let (send, recv) = mpsc::channel();
-    let stop = Arc::new(RwLock::new(false));
-
-    for count in 0..thread_count {
-        let send = send.clone();
-        let stop = Arc::clone(&stop);
-        let mut init_seed = init_seed;
-        init_seed[0] = init_seed[0].wrapping_add(count as u8);
-        spawn(move || {
-            get_account_seed_inner(
-                send,
-                stop,
-                init_seed,
-                account_type,
-                storage_type,
-                code_commitment,
-                storage_root,
-            )
-        });
-    }
-
-    #[allow(unused_variables)]
-    let (digest, seed) = recv.recv().unwrap();
+    let num_threads = thread::available_parallelism().map_or(1, |v| v.get());
 
-    // Safety: this is the only writer for this lock, it should never be poisoned
-    *stop.write().unwrap() = true;
+    let seed =
+        SeedGrinder::new(init_seed, account_type, storage_type, code_commitment, storage_root)
+            .with_num_threads(num_threads)
+            .grind_to_completion()?;
 
     #[cfg(feature = "log")]
-    ::log::info!(
-        "Using account seed [pow={}, digest={}, seed={}]",
-        super::account_id::digest_pow(digest),
-        log::digest_hex(digest),
-        log::word_hex(seed),
-    );
+    {
+        let digest = compute_digest(seed, code_commitment, storage_root);
+        ::log::info!(
+            "Using account seed [pow={}, digest={}, seed={}]",
+            super::account_id::digest_pow(digest),
+            log::digest_hex(digest),
+            log::word_hex(seed),
+        );
+    }
 
     Ok(seed)
 }
 
-#[cfg(feature = "concurrent")]
-pub fn get_account_seed_inner(
-    send: Sender<(Digest, Word)>,
-    stop: Arc<RwLock<bool>>,
+#[cfg(not(feature = "concurrent"))]
+pub fn get_account_seed(
     init_seed: [u8; 32],
     account_type: AccountType,
     storage_type: AccountStorageType,
     code_commitment: Digest,
     storage_root: Digest,
-) {
-    let init_seed: Vec<[u8; 8]> =
-        init_seed.chunks(8).map(|chunk| chunk.try_into().unwrap()).collect();
-    let mut current_seed: Word = [
-        Felt::new(u64::from_le_bytes(init_seed[0])),
-        Felt::new(u64::from_le_bytes(init_seed[1])),
-        Felt::new(u64::from_le_bytes(init_seed[2])),
-        Felt::new(u64::from_le_bytes(init_seed[3])),
-    ];
-    let mut current_digest = compute_digest(current_seed, code_commitment, storage_root);
+) -> Result<Word, AccountError> {
+    get_account_seed_single(init_seed, account_type, storage_type, code_commitment, storage_root)
+}
 
-    #[cfg(feature = "log")]
-    let mut log = log::Log::start(current_digest, current_seed, account_type, storage_type);
+/// Finds and returns a seed suitable for creating an account ID for the specified account type
+/// using the provided initial seed as a starting point. Using a single thread.
+pub fn get_account_seed_single(
+    init_seed: [u8; 32],
+    account_type: AccountType,
+    storage_type: AccountStorageType,
+    code_commitment: Digest,
+    storage_root: Digest,
+) -> Result<Word, AccountError> {
+    SeedGrinder::new(init_seed, account_type, storage_type, code_commitment, storage_root)
+        .grind_to_completion()
+}
 
-    // loop until we have a seed that satisfies the specified account type.
-    let mut count = 0;
-    loop {
-        #[cfg(feature = "log")]
-        log.iteration(current_digest, current_seed);
+// CANCELLATION TOKEN
+// ================================================================================================
+
+/// A token which can be used to stop an ongoing [SeedGrinder::grind()] from another thread.
+///
+/// Clones of a token share the same state, i.e., cancelling any clone cancels all of them.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Returns a new token which has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests the cancellation of all grinds using this token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if cancellation was requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
 
-        // regularly check if another thread found a digest
-        count += 1;
-        if count % 500_000 == 0 && *stop.read().unwrap() {
-            return;
+// VANITY PREFIX
+// ================================================================================================
+
+/// A prefix which the hex representation of an account ID must start with, e.g., `0x2bad`.
+///
+/// The prefix is matched against the most significant digits of the ID as rendered by the
+/// `Display` implementation of [AccountId]. Since the first digit of the ID encodes the storage
+/// type and the type of the account, the first digit of the prefix must match the account for which
+/// the seed is ground (see [SeedGrinder::with_vanity_prefix()]).
+///
+/// Every additional digit of the prefix increases the expected number of iterations needed to find
+/// a seed 16-fold.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VanityPrefix {
+    value: u64,
+    num_digits: u8,
+}
+
+impl VanityPrefix {
+    /// The maximum number of hex digits in a prefix.
+    pub const MAX_DIGITS: u8 = 16;
+
+    /// Returns a new [VanityPrefix] parsed from the provided hex string, which may optionally
+    /// start with `0x`. Both lowercase and uppercase digits are accepted.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The prefix contains non-hex characters or more than 16 digits.
+    /// - No valid account ID starts with the prefix, i.e., the first digit of the prefix does not
+    ///   encode a valid storage type, or the IDs starting with the prefix cannot contain at least
+    ///   [AccountId::MIN_ACCOUNT_ONES] ones.
+    pub fn new(prefix: &str) -> Result<Self, AccountError> {
+        let digits = prefix.strip_prefix("0x").unwrap_or(prefix);
+        if digits.len() > Self::MAX_DIGITS as usize {
+            return Err(AccountError::InvalidVanityPrefix(format!(
+                "prefix has {} digits, but at most {} are allowed",
+                digits.len(),
+                Self::MAX_DIGITS
+            )));
         }
 
-        // check if the seed satisfies the specified account type
-        if AccountId::validate_seed_digest(&current_digest).is_ok() {
-            if let Ok(account_id) = AccountId::try_from(current_digest[0]) {
-                if account_id.account_type() == account_type
-                    && account_id.storage_type() == storage_type
-                {
-                    #[cfg(feature = "log")]
-                    log.done(current_digest, current_seed, account_id);
+        let mut value = 0_u64;
+        for digit in digits.chars() {
+            let digit = digit.to_digit(16).ok_or_else(|| {
+                AccountError::InvalidVanityPrefix(format!("'{digit}' is not a hex digit"))
+            })?;
+            value = (value << 4) | digit as u64;
+        }
 
-                    let _ = send.send((current_digest, current_seed));
-                    return;
-                };
-            }
+        let prefix = Self { value, num_digits: digits.len() as u8 };
+        prefix.validate()?;
+
+        Ok(prefix)
+    }
+
+    /// Returns the number of hex digits in this prefix.
+    pub fn num_digits(&self) -> u8 {
+        self.num_digits
+    }
+
+    /// Returns true if the hex representation of the provided account ID starts with this prefix.
+    pub fn matches(&self, account_id: AccountId) -> bool {
+        self.matches_u64(account_id.into())
+    }
+
+    /// Returns the value of the first digit of this prefix, if the prefix is not empty.
+    fn first_digit(&self) -> Option<u64> {
+        (self.num_digits > 0).then(|| self.value >> (4 * (self.num_digits - 1)))
+    }
+
+    /// Returns an error if no valid account ID starts with this prefix.
+    fn validate(&self) -> Result<(), AccountError> {
+        let Some(first_digit) = self.first_digit() else {
+            return Ok(());
+        };
+
+        let storage_bits = first_digit >> (ACCOUNT_STORAGE_MASK_SHIFT - 60);
+        if storage_bits != ON_CHAIN && storage_bits != OFF_CHAIN {
+            return Err(AccountError::InvalidVanityPrefix(format!(
+                "IDs cannot start with {first_digit:x}, as it does not encode a valid storage type"
+            )));
+        }
+
+        // the digits following the prefix can be chosen freely
+        let max_ones = self.value.count_ones() + 64 - 4 * self.num_digits as u32;
+        if max_ones < AccountId::MIN_ACCOUNT_ONES {
+            return Err(AccountError::InvalidVanityPrefix(format!(
+                "IDs with the prefix have at most {max_ones} ones, but {} are required",
+                AccountId::MIN_ACCOUNT_ONES
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn matches_u64(&self, account_id: u64) -> bool {
+        match self.num_digits {
+            0 => true,
+            num_digits => account_id >> (64 - 4 * num_digits as u32) == self.value,
         }
-        current_seed = current_digest.into();
-        current_digest = compute_digest(current_seed, code_commitment, storage_root);
     }
 }
 
-#[cfg(not(feature = "concurrent"))]
-pub fn get_account_seed(
-    init_seed: [u8; 32],
+// SEED GRIND CHECKPOINT
+// ================================================================================================
+
+/// The state of a seed grind from which the grind can be resumed via
+/// [SeedGrinder::from_checkpoint()].
+///
+/// A grind is split into lanes, each of which walks its own sequence of seeds, where the next seed
+/// in a lane is the digest computed from the previous one. The checkpoint records the next seed to
+/// try in each lane, together with the parameters of the account for which the seed is ground.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeedGrindCheckpoint {
     account_type: AccountType,
     storage_type: AccountStorageType,
     code_commitment: Digest,
     storage_root: Digest,
-) -> Result<Word, AccountError> {
-    get_account_seed_single(init_seed, account_type, storage_type, code_commitment, storage_root)
+    vanity_prefix: VanityPrefix,
+    lanes: Vec<Word>,
+    iterations: u64,
 }
 
-/// Finds and returns a seed suitable for creating an account ID for the specified account type
-/// using the provided initial seed as a starting point. Using a single thread.
-pub fn get_account_seed_single(
+impl SeedGrindCheckpoint {
+    /// Returns the type of the account for which the seed is ground.
+    pub fn account_type(&self) -> AccountType {
+        self.account_type
+    }
+
+    /// Returns the storage type of the account for which the seed is ground.
+    pub fn storage_type(&self) -> AccountStorageType {
+        self.storage_type
+    }
+
+    /// Returns the code commitment of the account for which the seed is ground.
+    pub fn code_commitment(&self) -> Digest {
+        self.code_commitment
+    }
+
+    /// Returns the storage root of the account for which the seed is ground.
+    pub fn storage_root(&self) -> Digest {
+        self.storage_root
+    }
+
+    /// Returns the prefix which the account ID must start with.
+    pub fn vanity_prefix(&self) -> VanityPrefix {
+        self.vanity_prefix
+    }
+
+    /// Returns the number of iterations the grind is expected to take in total, see
+    /// [SeedGrinder::expected_iterations()].
+    pub fn expected_iterations(&self) -> u64 {
+        expected_iterations(self.account_type, self.vanity_prefix)
+    }
+
+    /// Returns the next seed to try in each of the grinding lanes.
+    pub fn lanes(&self) -> &[Word] {
+        &self.lanes
+    }
+
+    /// Returns the total number of seeds tried across all lanes so far.
+    pub fn iterations(&self) -> u64 {
+        self.iterations
+    }
+}
+
+// SEED GRIND OUTCOME
+// ================================================================================================
+
+/// The result of a [SeedGrinder::grind()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SeedGrindOutcome {
+    /// A seed satisfying all requirements was found.
+    Found(Word),
+    /// The grind was cancelled before a seed was found; it can be resumed from the checkpoint.
+    Cancelled(SeedGrindCheckpoint),
+}
+
+// SEED GRINDER
+// ================================================================================================
+
+/// Searches for a seed suitable for creating an account ID of the specified account type and
+/// storage type.
+///
+/// Compared to [get_account_seed()], the grinder can be cancelled via a [CancellationToken],
+/// reports its progress and can be resumed from a [SeedGrindCheckpoint], e.g., after the
+/// application performing the grind was restarted.
+///
+/// The search is split into lanes, one per thread. With the `concurrent` feature, each lane is
+/// processed by its own thread; otherwise, the lanes are processed in turns by the calling thread.
+/// A grinder using a single lane tries the same sequence of seeds as [get_account_seed_single()].
+#[derive(Debug, Clone)]
+pub struct SeedGrinder {
     init_seed: [u8; 32],
+    num_threads: usize,
+    report_interval: u64,
+    checkpoint: Option<SeedGrindCheckpoint>,
     account_type: AccountType,
     storage_type: AccountStorageType,
     code_commitment: Digest,
     storage_root: Digest,
-) -> Result<Word, AccountError> {
-    let init_seed: Vec<[u8; 8]> =
-        init_seed.chunks(8).map(|chunk| chunk.try_into().unwrap()).collect();
-    let mut current_seed: Word = [
-        Felt::new(u64::from_le_bytes(init_seed[0])),
-        Felt::new(u64::from_le_bytes(init_seed[1])),
-        Felt::new(u64::from_le_bytes(init_seed[2])),
-        Felt::new(u64::from_le_bytes(init_seed[3])),
-    ];
-    let mut current_digest = compute_digest(current_seed, code_commitment, storage_root);
+    vanity_prefix: VanityPrefix,
+}
 
-    #[cfg(feature = "log")]
-    let mut log = log::Log::start(current_digest, current_seed, account_type, storage_type);
+impl SeedGrinder {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [SeedGrinder] which uses the provided initial seed as a starting point and a
+    /// single thread.
+    pub fn new(
+        init_seed: [u8; 32],
+        account_type: AccountType,
+        storage_type: AccountStorageType,
+        code_commitment: Digest,
+        storage_root: Digest,
+    ) -> Self {
+        Self {
+            init_seed,
+            num_threads: 1,
+            report_interval: DEFAULT_REPORT_INTERVAL,
+            checkpoint: None,
+            account_type,
+            storage_type,
+            code_commitment,
+            storage_root,
+            vanity_prefix: VanityPrefix::default(),
+        }
+    }
+
+    /// Returns a new [SeedGrinder] which resumes the grind recorded in the provided checkpoint.
+    ///
+    /// The grinder uses as many threads as there are lanes in the checkpoint.
+    pub fn from_checkpoint(checkpoint: SeedGrindCheckpoint) -> Self {
+        Self {
+            init_seed: [0; 32],
+            num_threads: checkpoint.lanes.len(),
+            report_interval: DEFAULT_REPORT_INTERVAL,
+            account_type: checkpoint.account_type,
+            storage_type: checkpoint.storage_type,
+            code_commitment: checkpoint.code_commitment,
+            storage_root: checkpoint.storage_root,
+            vanity_prefix: checkpoint.vanity_prefix,
+            checkpoint: Some(checkpoint),
+        }
+    }
+
+    /// Sets the number of threads used for grinding.
+    ///
+    /// This has no effect on grinders resumed from a checkpoint, which always use one thread per
+    /// lane of the checkpoint.
+    ///
+    /// # Panics
+    /// Panics if the number of threads is zero.
+    pub fn with_num_threads(mut self, num_threads: usize) -> Self {
+        assert!(num_threads > 0, "number of threads must be greater than zero");
+        if self.checkpoint.is_none() {
+            self.num_threads = num_threads;
+        }
+        self
+    }
+
+    /// Sets the prefix which the hex representation of the account ID must start with.
+    ///
+    /// This has no effect on grinders resumed from a checkpoint, which always use the prefix of
+    /// the checkpoint.
+    ///
+    /// # Errors
+    /// Returns an error if the first digit of the prefix does not encode the storage type and the
+    /// account type of the account for which the seed is ground.
+    pub fn with_vanity_prefix(mut self, vanity_prefix: VanityPrefix) -> Result<Self, AccountError> {
+        let metadata_digit = ((self.storage_type as u64) << (ACCOUNT_STORAGE_MASK_SHIFT - 60))
+            | ((self.account_type as u64) << (ACCOUNT_TYPE_MASK_SHIFT - 60));
+        if let Some(first_digit) = vanity_prefix.first_digit() {
+            if first_digit != metadata_digit {
+                return Err(AccountError::InvalidVanityPrefix(format!(
+                    "IDs of {:?} {:?} accounts start with {metadata_digit:x}, not {first_digit:x}",
+                    self.storage_type, self.account_type,
+                )));
+            }
+        }
+
+        if self.checkpoint.is_none() {
+            self.vanity_prefix = vanity_prefix;
+        }
+        Ok(self)
+    }
+
+    /// Sets the number of iterations after which each lane reports its progress and checks
+    /// whether the grind was cancelled.
+    ///
+    /// # Panics
+    /// Panics if the interval is zero.
+    pub fn with_report_interval(mut self, report_interval: u64) -> Self {
+        assert!(report_interval > 0, "report interval must be greater than zero");
+        self.report_interval = report_interval;
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of iterations a grind is expected to take in total.
+    ///
+    /// The estimate accounts for the proof-of-work required for the account type, the digit
+    /// encoding the storage type and the account type, and the remaining digits of the vanity
+    /// prefix. The estimate saturates at [u64::MAX].
+    pub fn expected_iterations(&self) -> u64 {
+        expected_iterations(self.account_type, self.vanity_prefix)
+    }
+
+    // GRINDING
+    // --------------------------------------------------------------------------------------------
+
+    /// Searches for a seed until one is found or the grind is cancelled via the provided token.
+    ///
+    /// Every time a lane completes the configured number of iterations, the provided callback is
+    /// invoked with a checkpoint reflecting the current state of the grind. The checkpoint can be
+    /// persisted in order to resume the grind later.
+    pub fn grind(
+        self,
+        cancel: &CancellationToken,
+        mut progress: impl FnMut(&SeedGrindCheckpoint),
+    ) -> SeedGrindOutcome {
+        let checkpoint = self.start_checkpoint();
+
+        #[cfg(feature = "log")]
+        log::start(&checkpoint);
+
+        let outcome = self.grind_lanes(checkpoint, cancel, |checkpoint| {
+            #[cfg(feature = "log")]
+            log::progress(checkpoint);
+
+            progress(checkpoint)
+        });
 
-    // loop until we have a seed that satisfies the specified account type.
-    loop {
         #[cfg(feature = "log")]
-        log.iteration(current_digest, current_seed);
+        if let SeedGrindOutcome::Found(seed) = outcome {
+            log::done(seed, self.code_commitment, self.storage_root);
+        }
+
+        outcome
+    }
+
+    /// Searches for a seed starting from the provided checkpoint.
+    fn grind_lanes(
+        &self,
+        mut checkpoint: SeedGrindCheckpoint,
+        cancel: &CancellationToken,
+        mut progress: impl FnMut(&SeedGrindCheckpoint),
+    ) -> SeedGrindOutcome {
+        #[cfg(feature = "concurrent")]
+        if checkpoint.lanes.len() > 1 {
+            return self.grind_concurrent(checkpoint, cancel, progress);
+        }
+
+        loop {
+            for lane in 0..checkpoint.lanes.len() {
+                let (step, iterations) = get_account_seed_inner(
+                    checkpoint.lanes[lane],
+                    self.report_interval,
+                    self.account_type,
+                    self.storage_type,
+                    self.code_commitment,
+                    self.storage_root,
+                    self.vanity_prefix,
+                );
+                checkpoint.iterations += iterations;
+
+                match step {
+                    LaneStep::Found(seed) => return SeedGrindOutcome::Found(seed),
+                    LaneStep::Continue(seed) => checkpoint.lanes[lane] = seed,
+                }
+
+                progress(&checkpoint);
+                if cancel.is_cancelled() {
+                    return SeedGrindOutcome::Cancelled(checkpoint);
+                }
+            }
+        }
+    }
+
+    /// Searches for a seed using one thread per lane of the provided checkpoint.
+    #[cfg(feature = "concurrent")]
+    fn grind_concurrent(
+        &self,
+        mut checkpoint: SeedGrindCheckpoint,
+        cancel: &CancellationToken,
+        mut progress: impl FnMut(&SeedGrindCheckpoint),
+    ) -> SeedGrindOutcome {
+        let stop = AtomicBool::new(false);
+        let (send, recv) = mpsc::channel();
+
+        thread::scope(|scope| {
+            for (lane, &seed) in checkpoint.lanes.iter().enumerate() {
+                let send = send.clone();
+                let stop = &stop;
+                scope.spawn(move || {
+                    let mut seed = seed;
+                    loop {
+                        let (step, iterations) = get_account_seed_inner(
+                            seed,
+                            self.report_interval,
+                            self.account_type,
+                            self.storage_type,
+                            self.code_commitment,
+                            self.storage_root,
+                            self.vanity_prefix,
+                        );
+                        let is_found = matches!(step, LaneStep::Found(_));
+                        let _ = send.send((lane, step, iterations));
+
+                        // stop when this lane found a seed, another lane found a seed, or the
+                        // grind was cancelled
+                        if is_found || stop.load(Ordering::Relaxed) || cancel.is_cancelled() {
+                            return;
+                        }
+                        if let LaneStep::Continue(next_seed) = step {
+                            seed = next_seed;
+                        }
+                    }
+                });
+            }
+            drop(send);
+
+            // the channel is closed once all lanes stopped
+            let mut found = None;
+            for (lane, step, iterations) in recv {
+                checkpoint.iterations += iterations;
+                match step {
+                    LaneStep::Found(seed) => {
+                        stop.store(true, Ordering::Relaxed);
+                        found.get_or_insert(seed);
+                    },
+                    LaneStep::Continue(seed) => {
+                        checkpoint.lanes[lane] = seed;
+                        if found.is_none() {
+                            progress(&checkpoint);
+                        }
+                    },
+                }
+            }
+
+            match found {
+                Some(seed) => SeedGrindOutcome::Found(seed),
+                None => SeedGrindOutcome::Cancelled(checkpoint),
+            }
+        })
+    }
+
+    /// Searches for a seed until one is found.
+    fn grind_to_completion(self) -> Result<Word, AccountError> {
+        match self.grind(&CancellationToken::new(), |_| ()) {
+            SeedGrindOutcome::Found(seed) => Ok(seed),
+            SeedGrindOutcome::Cancelled(_) => unreachable!("the grind cannot be cancelled"),
+        }
+    }
+
+    /// Returns the checkpoint to start the grind from.
+    fn start_checkpoint(&self) -> SeedGrindCheckpoint {
+        if let Some(checkpoint) = &self.checkpoint {
+            return checkpoint.clone();
+        }
+
+        // the first lane starts from the initial seed, so that a single-lane grind tries the same
+        // seeds as get_account_seed_single(); every other lane starts from the hash of the initial
+        // seed and the index of the lane
+        let init_seed = seed_from_bytes(self.init_seed);
+        let lanes = (0..self.num_threads)
+            .map(|lane| match lane {
+                0 => init_seed,
+                _ => {
+                    let mut elements = init_seed.to_vec();
+                    elements.push(Felt::new(lane as u64));
+                    Hasher::hash_elements(&elements).into()
+                },
+            })
+            .collect();
+
+        SeedGrindCheckpoint {
+            account_type: self.account_type,
+            storage_type: self.storage_type,
+            code_commitment: self.code_commitment,
+            storage_root: self.storage_root,
+            vanity_prefix: self.vanity_prefix,
+            lanes,
+            iterations: 0,
+        }
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// The result of advancing a grinding lane.
+#[derive(Debug, Clone, Copy)]
+enum LaneStep {
+    /// The contained seed satisfies all requirements.
+    Found(Word),
+    /// No seed satisfying the requirements was found; the lane continues with the contained seed.
+    Continue(Word),
+}
+
+/// Tries at most `max_iterations` seeds of the lane starting with the provided seed.
+///
+/// Returns the result of the search together with the number of seeds tried.
+fn get_account_seed_inner(
+    mut current_seed: Word,
+    max_iterations: u64,
+    account_type: AccountType,
+    storage_type: AccountStorageType,
+    code_commitment: Digest,
+    storage_root: Digest,
+    vanity_prefix: VanityPrefix,
+) -> (LaneStep, u64) {
+    let mut current_digest = compute_digest(current_seed, code_commitment, storage_root);
 
+    // loop until we have a seed that satisfies the specified account type.
+    for count in 1..=max_iterations {
         // check if the seed satisfies the specified account type
         if AccountId::validate_seed_digest(&current_digest).is_ok() {
             if let Ok(account_id) = AccountId::try_from(current_digest[0]) {
                 if account_id.account_type() == account_type
                     && account_id.storage_type() == storage_type
+                    && vanity_prefix.matches(account_id)
                 {
-                    #[cfg(feature = "log")]
-                    log.done(current_digest, current_seed, account_id);
-
-                    return Ok(current_seed);
+                    return (LaneStep::Found(current_seed), count);
                 };
             }
         }
         current_seed = current_digest.into();
         current_digest = compute_digest(current_seed, code_commitment, storage_root);
     }
+
+    (LaneStep::Continue(current_seed), max_iterations)
+}
+
+/// Returns the number of iterations needed on average to find a seed for an account of the
+/// provided type with an ID starting with the provided prefix.
+fn expected_iterations(account_type: AccountType, vanity_prefix: VanityPrefix) -> u64 {
+    let pow = match account_type {
+        AccountType::FungibleFaucet | AccountType::NonFungibleFaucet => {
+            AccountId::FAUCET_SEED_DIGEST_MIN_TRAILING_ZEROS
+        },
+        _ => AccountId::REGULAR_ACCOUNT_SEED_DIGEST_MIN_TRAILING_ZEROS,
+    };
+    // the first digit of the ID is fixed by the storage and account types
+    let num_digits = vanity_prefix.num_digits().max(1) as u32;
+
+    1_u64.checked_shl(pow + 4 * num_digits).unwrap_or(u64::MAX)
+}
+
+/// Converts the provided bytes into a seed.
+fn seed_from_bytes(init_seed: [u8; 32]) -> Word {
+    let init_seed: Vec<[u8; 8]> =
+        init_seed.chunks(8).map(|chunk| chunk.try_into().unwrap()).collect();
+    [
+        Felt::new(u64::from_le_bytes(init_seed[0])),
+        Felt::new(u64::from_le_bytes(init_seed[1])),
+        Felt::new(u64::from_le_bytes(init_seed[2])),
+        Felt::new(u64::from_le_bytes(init_seed[3])),
+    ]
 }
 
 #[cfg(feature = "log")]
 mod log {
     use alloc::string::String;
 
-    use assembly::utils::to_hex;
-    use miden_crypto::FieldElement;
-
     use super::{
-        super::{account_id::digest_pow, Digest, Word},
-        AccountId, AccountType,
+        super::account_id::{compute_digest, digest_pow},
+        AccountId, Digest, SeedGrindCheckpoint, Word,
     };
-    use crate::accounts::AccountStorageType;
-
-    /// Keeps track of the best digest found so far and count how many iterations have been done.
-    pub struct Log {
-        digest: Digest,
-        seed: Word,
-        count: usize,
-        pow: u32,
-    }
 
     /// Given a [Digest] returns its hex representation.
     pub fn digest_hex(digest: Digest) -> String {
-        to_hex(&digest.as_bytes()).expect("hex formatting failed")
+        digest.to_hex()
     }
 
     /// Given a [Word] returns its hex representation.
     pub fn word_hex(word: Word) -> String {
-        to_hex(FieldElement::elements_as_bytes(&word)).expect("hex formatting failed")
-    }
-
-    impl Log {
-        pub fn start(
-            digest: Digest,
-            seed: Word,
-            account_type: AccountType,
-            storage_type: AccountStorageType,
-        ) -> Self {
-            log::info!(
-                "Generating new account seed [pow={}, digest={}, seed={} type={:?} onchain={:?}]",
-                digest_pow(digest),
-                digest_hex(digest),
-                word_hex(seed),
-                account_type,
-                storage_type,
-            );
-
-            Self { digest, seed, count: 0, pow: 0 }
-        }
+        Digest::from(word).to_hex()
+    }
 
-        pub fn iteration(&mut self, digest: Digest, seed: Word) {
-            self.count += 1;
+    /// Logs the start of a grind from the provided checkpoint.
+    pub fn start(checkpoint: &SeedGrindCheckpoint) {
+        ::log::info!(
+            "Generating new account seed [lanes={}, iterations={}, type={:?}, storage={:?}]",
+            checkpoint.lanes().len(),
+            checkpoint.iterations(),
+            checkpoint.account_type(),
+            checkpoint.storage_type(),
+        );
+    }
 
-            let pow = digest_pow(digest);
-            if pow >= self.pow {
-                self.digest = digest;
-                self.seed = seed;
-                self.pow = pow;
-            }
+    /// Logs the progress of a grind.
+    pub fn progress(checkpoint: &SeedGrindCheckpoint) {
+        ::log::debug!(
+            "Account seed loop [count={}, expected={}]",
+            checkpoint.iterations(),
+            checkpoint.expected_iterations(),
+        );
+    }
 
-            if self.count % 500_000 == 0 {
-                log::debug!(
-                    "Account seed loop [count={}, pow={}, digest={}, seed={}]",
-                    self.count,
-                    self.pow,
-                    digest_hex(self.digest),
-                    word_hex(self.seed),
-                );
-            }
-        }
+    /// Logs the seed found by a grind.
+    pub fn done(seed: Word, code_commitment: Digest, storage_root: Digest) {
+        let digest = compute_digest(seed, code_commitment, storage_root);
+        let account_id = AccountId::try_from(digest[0]).expect("seed digest must be a valid ID");
+        ::log::info!(
+            "Found account seed [pow={}, digest={}, seed={}, type={:?}, onchain={}]",
+            digest_pow(digest),
+            digest_hex(digest),
+            word_hex(seed),
+            account_id.account_type(),
+            account_id.is_on_chain(),
+        );
+    }
+}
 
-        pub fn done(self, digest: Digest, seed: Word, account_id: AccountId) {
-            log::info!(
-                "Found account seed [pow={}, current_digest={}, current_seed={} type={:?} onchain={}]]",
-                digest_pow(digest),
-                digest_hex(digest),
-                word_hex(seed),
-                account_id.account_type(),
-                account_id.is_on_chain(),
-            );
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for SeedGrindCheckpoint {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.account_type as u8);
+        target.write_u8(self.storage_type as u8);
+        target.write(self.code_commitment);
+        target.write(self.storage_root);
+        target.write_u64(self.vanity_prefix.value);
+        target.write_u8(self.vanity_prefix.num_digits);
+        target.write(&self.lanes);
+        target.write_u64(self.iterations);
+    }
+}
+
+impl Deserializable for SeedGrindCheckpoint {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account_type = match source.read_u8()? as u64 {
+            super::account_id::FUNGIBLE_FAUCET => AccountType::FungibleFaucet,
+            super::account_id::NON_FUNGIBLE_FAUCET => AccountType::NonFungibleFaucet,
+            super::account_id::REGULAR_ACCOUNT_IMMUTABLE_CODE => {
+                AccountType::RegularAccountImmutableCode
+            },
+            super::account_id::REGULAR_ACCOUNT_UPDATABLE_CODE => {
+                AccountType::RegularAccountUpdatableCode
+            },
+            value => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid account type: {value}"
+                )))
+            },
+        };
+        let storage_type = match source.read_u8()? as u64 {
+            super::account_id::ON_CHAIN => AccountStorageType::OnChain,
+            super::account_id::OFF_CHAIN => AccountStorageType::OffChain,
+            value => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid account storage type: {value}"
+                )))
+            },
+        };
+        let code_commitment = source.read()?;
+        let storage_root = source.read()?;
+        let value = source.read_u64()?;
+        let num_digits = source.read_u8()?;
+        if num_digits > VanityPrefix::MAX_DIGITS
+            || (num_digits < VanityPrefix::MAX_DIGITS && value >> (4 * num_digits) != 0)
+        {
+            return Err(DeserializationError::InvalidValue("invalid vanity prefix".to_string()));
+        }
+        let vanity_prefix = VanityPrefix { value, num_digits };
+        vanity_prefix
+            .validate()
+            .map_err(|err| DeserializationError::InvalidValue(format!("{err:?}")))?;
+        let lanes: Vec<Word> = source.read()?;
+        if lanes.is_empty() {
+            return Err(DeserializationError::InvalidValue(
+                "seed grind checkpoint must have at least one lane".to_string(),
+            ));
         }
+        let iterations = source.read_u64()?;
+
+        Ok(Self {
+            account_type,
+            storage_type,
+            code_commitment,
+            storage_root,
+            vanity_prefix,
+            lanes,
+            iterations,
+        })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeSet;
+
+    use super::{
+        get_account_seed_single, seed_from_bytes, CancellationToken, Deserializable,
+        SeedGrindCheckpoint, SeedGrindOutcome, SeedGrinder, Serializable, VanityPrefix,
+    };
+    use crate::{
+        accounts::{AccountError, AccountId, AccountStorageType, AccountType},
+        Digest,
+    };
+
+    const INIT_SEED: [u8; 32] = [3; 32];
+
+    fn grinder() -> SeedGrinder {
+        SeedGrinder::new(
+            INIT_SEED,
+            AccountType::FungibleFaucet,
+            AccountStorageType::OnChain,
+            Digest::default(),
+            Digest::default(),
+        )
+        .with_report_interval(4)
+    }
+
+    #[test]
+    fn test_seed_grinder_matches_single_threaded_search() {
+        let expected = get_account_seed_single(
+            INIT_SEED,
+            AccountType::FungibleFaucet,
+            AccountStorageType::OnChain,
+            Digest::default(),
+            Digest::default(),
+        )
+        .unwrap();
+
+        let mut reports = 0;
+        let outcome = grinder().grind(&CancellationToken::new(), |_| reports += 1);
+        assert_eq!(outcome, SeedGrindOutcome::Found(expected));
+        assert!(reports > 0);
+
+        let id = AccountId::new(expected, Digest::default(), Digest::default()).unwrap();
+        assert_eq!(id.account_type(), AccountType::FungibleFaucet);
+    }
+
+    #[test]
+    fn test_seed_grinder_resumes_from_checkpoint() {
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+
+        // a cancelled grind stops after the first report
+        let checkpoint = match grinder().grind(&cancel, |_| ()) {
+            SeedGrindOutcome::Cancelled(checkpoint) => checkpoint,
+            SeedGrindOutcome::Found(_) => panic!("the grind should have been cancelled"),
+        };
+        assert_eq!(checkpoint.iterations(), 4);
+
+        let checkpoint = SeedGrindCheckpoint::read_from_bytes(&checkpoint.to_bytes()).unwrap();
+
+        // the resumed grind finds the same seed as an uninterrupted one
+        let expected = grinder().grind(&CancellationToken::new(), |_| ());
+        let resumed = SeedGrinder::from_checkpoint(checkpoint)
+            .grind(&CancellationToken::new(), |checkpoint| assert!(checkpoint.iterations() > 4));
+        assert_eq!(resumed, expected);
+    }
+
+    #[test]
+    fn test_seed_grinder_multiple_threads() {
+        let outcome = grinder()
+            .with_num_threads(3)
+            .grind(&CancellationToken::new(), |checkpoint| assert_eq!(checkpoint.lanes().len(), 3));
+
+        let SeedGrindOutcome::Found(seed) = outcome else {
+            panic!("the grind should have found a seed");
+        };
+        let id = AccountId::new(seed, Digest::default(), Digest::default()).unwrap();
+        assert_eq!(id.account_type(), AccountType::FungibleFaucet);
+        assert_eq!(id.storage_type(), AccountStorageType::OnChain);
+    }
+
+    #[test]
+    fn test_seed_grinder_distinct_lanes() {
+        let checkpoint = grinder().with_num_threads(300).start_checkpoint();
+        assert_eq!(checkpoint.lanes()[0], seed_from_bytes(INIT_SEED));
+
+        let lanes: BTreeSet<_> =
+            checkpoint.lanes().iter().map(|&lane| Digest::from(lane)).collect();
+        assert_eq!(lanes.len(), 300);
+    }
+
+    #[test]
+    fn test_vanity_prefix() {
+        let prefix = VanityPrefix::new("0x2Bad").unwrap();
+        assert_eq!(prefix.num_digits(), 4);
+        assert!(prefix.matches_u64(0x2bad_0000_0000_0001));
+        assert!(!prefix.matches_u64(0x2bae_0000_0000_0001));
+        assert!(VanityPrefix::new("").unwrap().matches_u64(0x2bae_0000_0000_0001));
+
+        assert!(matches!(VanityPrefix::new("0x2g"), Err(AccountError::InvalidVanityPrefix(_))));
+        assert!(matches!(
+            VanityPrefix::new("0x20000000000000000"),
+            Err(AccountError::InvalidVanityPrefix(_))
+        ));
+
+        // no valid account ID starts with these prefixes
+        assert!(matches!(
+            VanityPrefix::new("0x2000000000000000"),
+            Err(AccountError::InvalidVanityPrefix(_))
+        ));
+        assert!(matches!(VanityPrefix::new("0x4a"), Err(AccountError::InvalidVanityPrefix(_))));
+        assert!(VanityPrefix::new("0x2000000000000f").is_ok());
+
+        // IDs of on-chain fungible faucets start with 2
+        assert!(matches!(
+            grinder().with_vanity_prefix(VanityPrefix::new("3a").unwrap()),
+            Err(AccountError::InvalidVanityPrefix(_))
+        ));
+    }
+
+    #[test]
+    fn test_seed_grinder_vanity_prefix() {
+        let prefix = VanityPrefix::new("0x2a").unwrap();
+        let grinder = grinder().with_vanity_prefix(prefix).unwrap();
+        assert_eq!(
+            grinder.expected_iterations(),
+            1 << (AccountId::FAUCET_SEED_DIGEST_MIN_TRAILING_ZEROS + 8)
+        );
+
+        let SeedGrindOutcome::Found(seed) = grinder.grind(&CancellationToken::new(), |_| ()) else {
+            panic!("the grind should have found a seed");
+        };
+        let id = AccountId::new(seed, Digest::default(), Digest::default()).unwrap();
+        assert!(prefix.matches(id));
+        assert!(id.to_hex().starts_with("0x2a"));
     }
 }
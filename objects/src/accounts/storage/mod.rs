@@ -12,6 +12,9 @@ pub use slot::StorageSlotType;
 mod map;
 pub use map::StorageMap;
 
+mod schema;
+pub use schema::{SchemaValueType, SlotSchema, SlotSchemaType, StorageDisplay, StorageSchema};
+
 // CONSTANTS
 // ================================================================================================
 
@@ -0,0 +1,521 @@
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use vm_core::prettier::{const_text, indent, nl, text, Document, PrettyPrint};
+
+use super::{
+    AccountError, AccountStorage, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    Digest, Serializable, StorageSlotType, Word,
+};
+use crate::{accounts::AccountId, ZERO};
+
+// SCHEMA VALUE TYPE
+// ================================================================================================
+
+/// Describes how a [Word] stored in account storage should be interpreted.
+///
+/// Apart from [SchemaValueType::Word], all types are expected to be encoded in a single element of
+/// the word, with the remaining elements set to zero. Values are encoded in the first element of
+/// the word, while map keys are encoded in the last element, following the contracts of miden-lib
+/// which store e.g. `[0, 0, 0, account_id]` keys so that every key ends up in a separate leaf of
+/// the storage map.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SchemaValueType {
+    /// An arbitrary word, e.g., a public key or a commitment.
+    Word,
+    /// A single field element.
+    Felt,
+    /// An amount of fungible assets.
+    Amount,
+    /// An account ID.
+    AccountId,
+}
+
+impl SchemaValueType {
+    /// Returns a human-readable representation of the provided value interpreted as a value of
+    /// this type, expecting the value to be encoded in the first element of the word.
+    ///
+    /// If the word is not a valid encoding of a value of this type, the word is rendered as a hex
+    /// string.
+    pub fn render_value(&self, value: &Word) -> String {
+        self.render_element(value, 0)
+    }
+
+    /// Returns a human-readable representation of the provided map key interpreted as a value of
+    /// this type, expecting the value to be encoded in the last element of the word.
+    ///
+    /// If the word is not a valid encoding of a value of this type, the word is rendered as a hex
+    /// string.
+    pub fn render_key(&self, key: &Word) -> String {
+        self.render_element(key, 3)
+    }
+
+    /// Renders the element at the specified position of the provided word, falling back to the hex
+    /// representation of the word if any other element is non-zero or the element is not a valid
+    /// encoding of a value of this type.
+    fn render_element(&self, word: &Word, position: usize) -> String {
+        let raw = || Digest::from(*word).to_hex();
+        let is_padded = word
+            .iter()
+            .enumerate()
+            .all(|(idx, element)| idx == position || *element == ZERO);
+        if *self != Self::Word && !is_padded {
+            return raw();
+        }
+
+        let element = word[position];
+        match self {
+            Self::Word => raw(),
+            Self::Felt | Self::Amount => element.as_int().to_string(),
+            Self::AccountId => {
+                AccountId::try_from(element).map(|id| id.to_string()).unwrap_or_else(|_| raw())
+            },
+        }
+    }
+}
+
+impl fmt::Display for SchemaValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Word => f.write_str("word"),
+            Self::Felt => f.write_str("felt"),
+            Self::Amount => f.write_str("amount"),
+            Self::AccountId => f.write_str("account_id"),
+        }
+    }
+}
+
+// SLOT SCHEMA TYPE
+// ================================================================================================
+
+/// The expected type of a storage slot described by a [SlotSchema].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SlotSchemaType {
+    /// A value slot holding a single value of the specified type.
+    Value(SchemaValueType),
+    /// A map slot holding entries with keys and values of the specified types.
+    Map {
+        key: SchemaValueType,
+        value: SchemaValueType,
+    },
+}
+
+impl SlotSchemaType {
+    /// Returns true if a storage slot of the provided type can hold data of this type.
+    pub fn matches(&self, slot_type: &StorageSlotType) -> bool {
+        matches!(
+            (self, slot_type),
+            (Self::Value(_), StorageSlotType::Value { .. })
+                | (Self::Map { .. }, StorageSlotType::Map { .. })
+        )
+    }
+}
+
+impl fmt::Display for SlotSchemaType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Value(value_type) => write!(f, "{value_type}"),
+            Self::Map { key, value } => write!(f, "map<{key}, {value}>"),
+        }
+    }
+}
+
+// SLOT SCHEMA
+// ================================================================================================
+
+/// Describes a single storage slot of an account: its name, an optional description and the
+/// expected type of the data stored in the slot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlotSchema {
+    name: String,
+    description: String,
+    slot_type: SlotSchemaType,
+}
+
+impl SlotSchema {
+    /// Returns a new [SlotSchema] with the provided name and type, and an empty description.
+    pub fn new(name: impl Into<String>, slot_type: SlotSchemaType) -> Self {
+        Self {
+            name: name.into(),
+            description: String::new(),
+            slot_type,
+        }
+    }
+
+    /// Sets the description of the slot.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Returns the name of the slot.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the description of the slot; the description is empty if it was not provided.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns the expected type of the slot.
+    pub fn slot_type(&self) -> SlotSchemaType {
+        self.slot_type
+    }
+}
+
+// STORAGE SCHEMA
+// ================================================================================================
+
+/// Describes the storage slots of an account, mapping slot indices to [SlotSchema]s.
+///
+/// A schema is purely descriptive: it is not committed to by the account and is not enforced by
+/// the transaction kernel. It is intended to be distributed together with the account (see
+/// [AccountData](crate::accounts::AccountData)) so that tools can render the storage of the
+/// account in a human-readable form via [StorageSchema::display()].
+///
+/// Slots which are not described by the schema are rendered as raw words.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StorageSchema {
+    slots: BTreeMap<u8, SlotSchema>,
+}
+
+impl StorageSchema {
+    /// Returns a new empty [StorageSchema].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Describes the storage slot at the specified index with the provided schema, replacing any
+    /// previous description of the slot.
+    ///
+    /// # Errors
+    /// Returns an error if the index specifies the reserved storage layout commitment slot.
+    pub fn with_slot(mut self, index: u8, slot: SlotSchema) -> Result<Self, AccountError> {
+        if index == AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX {
+            return Err(AccountError::StorageSlotIsReserved(index));
+        }
+
+        self.slots.insert(index, slot);
+        Ok(self)
+    }
+
+    /// Returns the schema of the slot at the specified index, if the slot is described.
+    pub fn get_slot(&self, index: u8) -> Option<&SlotSchema> {
+        self.slots.get(&index)
+    }
+
+    /// Returns an iterator over the described slots and their indices, ordered by index.
+    pub fn slots(&self) -> impl Iterator<Item = (u8, &SlotSchema)> {
+        self.slots.iter().map(|(index, slot)| (*index, slot))
+    }
+
+    /// Checks that the types of the described slots match the layout of the provided storage.
+    ///
+    /// # Errors
+    /// Returns an error if a map is described for a non-map slot or a value is described for a
+    /// non-value slot.
+    pub fn validate(&self, storage: &AccountStorage) -> Result<(), AccountError> {
+        for (&index, slot) in self.slots.iter() {
+            let slot_type = storage.layout()[index as usize];
+            if !slot.slot_type.matches(&slot_type) {
+                return Err(AccountError::StorageSchemaSlotTypeMismatch(index, slot_type));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns a value which renders the provided storage according to this schema when
+    /// displayed.
+    pub fn display<'a>(&'a self, storage: &'a AccountStorage) -> StorageDisplay<'a> {
+        StorageDisplay { schema: self, storage }
+    }
+}
+
+// STORAGE DISPLAY
+// ================================================================================================
+
+/// Renders an [AccountStorage] in a human-readable form according to a [StorageSchema].
+///
+/// Every described slot is rendered with its name, type and decoded value, followed by its
+/// description and, for maps, by the decoded entries of the map. Slots which are not described
+/// are rendered only if they hold a non-default value.
+pub struct StorageDisplay<'a> {
+    schema: &'a StorageSchema,
+    storage: &'a AccountStorage,
+}
+
+impl StorageDisplay<'_> {
+    /// Renders the slot at the specified index; returns None for undescribed slots with default
+    /// values.
+    fn render_slot(&self, index: u8) -> Option<Document> {
+        let value: Word = self.storage.get_item(index).into();
+        let Some(slot) = self.schema.get_slot(index) else {
+            let slot_type = self.storage.layout()[index as usize];
+            if value == slot_type.default_word() {
+                return None;
+            }
+            return Some(text(format!("[{index}] {}", SchemaValueType::Word.render_value(&value))));
+        };
+
+        let header = format!("[{index}] {}: {}", slot.name(), slot.slot_type());
+        let mut body = Vec::new();
+        if !slot.description().is_empty() {
+            body.push(text(format!("# {}", slot.description())));
+        }
+
+        let doc = match slot.slot_type() {
+            SlotSchemaType::Value(value_type) => {
+                text(format!("{header} = {}", value_type.render_value(&value)))
+            },
+            SlotSchemaType::Map { key, value: value_type } => {
+                if let Some(map) = self.storage.maps().get(&index) {
+                    for (map_key, map_value) in map.entries() {
+                        body.push(text(format!(
+                            "{} => {}",
+                            key.render_key(&Word::from(*map_key)),
+                            value_type.render_value(map_value)
+                        )));
+                    }
+                }
+                text(header)
+            },
+        };
+
+        let body = body.into_iter().fold(Document::Empty, |acc, line| acc + nl() + line);
+        Some(doc + indent(4, body))
+    }
+}
+
+impl PrettyPrint for StorageDisplay<'_> {
+    fn render(&self) -> Document {
+        (0..AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX)
+            .filter_map(|index| self.render_slot(index))
+            .reduce(|acc, slot| acc + nl() + slot)
+            .unwrap_or_else(|| const_text("<empty storage>"))
+    }
+}
+
+impl fmt::Display for StorageDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.pretty_print(f)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for SchemaValueType {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(match self {
+            Self::Word => 0,
+            Self::Felt => 1,
+            Self::Amount => 2,
+            Self::AccountId => 3,
+        });
+    }
+}
+
+impl Deserializable for SchemaValueType {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::Word),
+            1 => Ok(Self::Felt),
+            2 => Ok(Self::Amount),
+            3 => Ok(Self::AccountId),
+            tag => Err(DeserializationError::InvalidValue(format!(
+                "invalid schema value type tag: {tag}"
+            ))),
+        }
+    }
+}
+
+impl Serializable for SlotSchemaType {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::Value(value_type) => {
+                target.write_u8(0);
+                value_type.write_into(target);
+            },
+            Self::Map { key, value } => {
+                target.write_u8(1);
+                key.write_into(target);
+                value.write_into(target);
+            },
+        }
+    }
+}
+
+impl Deserializable for SlotSchemaType {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::Value(source.read()?)),
+            1 => Ok(Self::Map {
+                key: source.read()?,
+                value: source.read()?,
+            }),
+            tag => Err(DeserializationError::InvalidValue(format!(
+                "invalid slot schema type tag: {tag}"
+            ))),
+        }
+    }
+}
+
+impl Serializable for SlotSchema {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.name.write_into(target);
+        self.description.write_into(target);
+        self.slot_type.write_into(target);
+    }
+}
+
+impl Deserializable for SlotSchema {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let name = String::read_from(source)?;
+        let description = String::read_from(source)?;
+        let slot_type = SlotSchemaType::read_from(source)?;
+
+        Ok(Self { name, description, slot_type })
+    }
+}
+
+impl Serializable for StorageSchema {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.slots.write_into(target);
+    }
+}
+
+impl Deserializable for StorageSchema {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let slots = <BTreeMap<u8, SlotSchema>>::read_from(source)?;
+        if slots.contains_key(&AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX) {
+            return Err(DeserializationError::InvalidValue(
+                "storage layout commitment slot cannot be described".to_string(),
+            ));
+        }
+
+        Ok(Self { slots })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+
+    use miden_crypto::hash::rpo::RpoDigest;
+
+    use super::{
+        AccountStorage, Deserializable, SchemaValueType, Serializable, SlotSchema, SlotSchemaType,
+        StorageSchema,
+    };
+    use crate::{
+        accounts::{
+            account_id::testing::ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, AccountError, AccountId,
+            SlotItem, StorageMap, StorageSlotType,
+        },
+        Felt, ONE, ZERO,
+    };
+
+    fn schema() -> StorageSchema {
+        StorageSchema::new()
+            .with_slot(
+                0,
+                SlotSchema::new("owner", SlotSchemaType::Value(SchemaValueType::AccountId))
+                    .with_description("Account allowed to update the configuration"),
+            )
+            .unwrap()
+            .with_slot(1, SlotSchema::new("limit", SlotSchemaType::Value(SchemaValueType::Amount)))
+            .unwrap()
+            .with_slot(
+                2,
+                SlotSchema::new(
+                    "balances",
+                    SlotSchemaType::Map {
+                        key: SchemaValueType::AccountId,
+                        value: SchemaValueType::Amount,
+                    },
+                ),
+            )
+            .unwrap()
+    }
+
+    fn storage() -> AccountStorage {
+        let owner = Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN);
+        let map = StorageMap::with_entries([(
+            RpoDigest::new([ZERO, ZERO, ZERO, owner]),
+            [Felt::new(500), ZERO, ZERO, ZERO],
+        )])
+        .unwrap();
+
+        AccountStorage::new(
+            vec![
+                SlotItem::new_value(0, 0, [owner, ZERO, ZERO, ZERO]),
+                SlotItem::new_value(1, 0, [Felt::new(1000), ZERO, ZERO, ZERO]),
+                SlotItem::new_map(2, 0, map.root().into()),
+                SlotItem::new_value(3, 0, [ONE, ONE, ZERO, ZERO]),
+            ],
+            BTreeMap::from([(2, map)]),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_storage_schema_display() {
+        let storage = storage();
+        let schema = schema();
+        schema.validate(&storage).unwrap();
+
+        let owner = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let raw_word = RpoDigest::new([ONE, ONE, ZERO, ZERO]).to_hex();
+        let expected = [
+            format!("[0] owner: account_id = {owner}"),
+            "    # Account allowed to update the configuration".to_string(),
+            "[1] limit: amount = 1000".to_string(),
+            "[2] balances: map<account_id, amount>".to_string(),
+            format!("    {owner} => 500"),
+            format!("[3] {raw_word}"),
+        ];
+
+        let rendered = schema.display(&storage).to_string();
+        assert_eq!(rendered.lines().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_storage_schema_validation() {
+        let schema = StorageSchema::new()
+            .with_slot(
+                2,
+                SlotSchema::new("balance", SlotSchemaType::Value(SchemaValueType::Amount)),
+            )
+            .unwrap();
+        assert_eq!(
+            schema.validate(&storage()),
+            Err(AccountError::StorageSchemaSlotTypeMismatch(
+                2,
+                StorageSlotType::Map { value_arity: 0 }
+            ))
+        );
+
+        let result = StorageSchema::new().with_slot(
+            AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX,
+            SlotSchema::new("layout", SlotSchemaType::Value(SchemaValueType::Word)),
+        );
+        assert_eq!(result, Err(AccountError::StorageSlotIsReserved(255)));
+    }
+
+    #[test]
+    fn test_storage_schema_serde() {
+        let schema = schema();
+        let bytes = schema.to_bytes();
+        assert_eq!(schema, StorageSchema::read_from_bytes(&bytes).unwrap());
+    }
+}
@@ -0,0 +1,347 @@
+use alloc::{string::ToString, vec::Vec};
+
+use miden_crypto::merkle::{LeafIndex, MerkleError, MerklePath, SimpleSmt};
+
+use super::{BlockAccountUpdate, BlockHeader};
+use crate::{
+    accounts::AccountId,
+    errors::BlockError,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Digest, Word, ACCOUNT_TREE_DEPTH, EMPTY_WORD,
+};
+
+// ACCOUNT TREE
+// ================================================================================================
+
+/// Wrapper over [SimpleSmt<ACCOUNT_TREE_DEPTH>] for the account tree.
+///
+/// The tree maps IDs of accounts recorded on chain to the hashes of their current states. The
+/// root of this tree is committed to by [BlockHeader::account_root()]. Accounts which are not in
+/// the tree map to [EMPTY_WORD].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AccountTree(SimpleSmt<ACCOUNT_TREE_DEPTH>);
+
+impl AccountTree {
+    /// Returns a new [AccountTree] instantiated with the provided (account ID, account hash)
+    /// entries.
+    ///
+    /// # Errors
+    /// Returns an error if the provided entries contain multiple hashes for the same account.
+    pub fn with_entries(
+        entries: impl IntoIterator<Item = (AccountId, Digest)>,
+    ) -> Result<Self, MerkleError> {
+        let leaves = entries
+            .into_iter()
+            .map(|(account_id, account_hash)| (u64::from(account_id), account_hash.into()));
+
+        SimpleSmt::with_leaves(leaves).map(Self)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> Digest {
+        self.0.root()
+    }
+
+    /// Returns the number of accounts in the tree.
+    pub fn num_accounts(&self) -> usize {
+        self.0.num_leaves()
+    }
+
+    /// Returns the state hash of the specified account, or `None` if the account is not in the
+    /// tree.
+    pub fn get_account_hash(&self, account_id: AccountId) -> Option<Digest> {
+        match self.0.get_leaf(&LeafIndex::from(account_id)) {
+            account_hash if account_hash == EMPTY_WORD => None,
+            account_hash => Some(account_hash.into()),
+        }
+    }
+
+    /// Returns an iterator over the (account ID, account hash) entries of the tree.
+    pub fn accounts(&self) -> impl Iterator<Item = (AccountId, Digest)> + '_ {
+        self.0.leaves().map(|(account_id, account_hash)| {
+            let account_id = AccountId::try_from(account_id)
+                .expect("account tree should only contain valid account IDs");
+            (account_id, (*account_hash).into())
+        })
+    }
+
+    /// Returns a proof of the current state of the specified account against the root of this
+    /// tree.
+    ///
+    /// If the account is not in the tree, the returned proof attests to its absence.
+    pub fn open(&self, account_id: AccountId) -> AccountInclusionProof {
+        let value_path = self.0.open(&LeafIndex::from(account_id));
+
+        AccountInclusionProof {
+            account_id,
+            account_hash: value_path.value,
+            path: value_path.path,
+        }
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the state hash of the specified account and returns the previous hash, or `None` if
+    /// the account was not in the tree.
+    pub fn insert(&mut self, account_id: AccountId, account_hash: Digest) -> Option<Digest> {
+        match self.0.insert(LeafIndex::from(account_id), account_hash.into()) {
+            old_hash if old_hash == EMPTY_WORD => None,
+            old_hash => Some(old_hash.into()),
+        }
+    }
+
+    /// Sets the state hashes of all accounts updated in a block.
+    pub fn apply_block_updates<'a>(
+        &mut self,
+        updates: impl IntoIterator<Item = &'a BlockAccountUpdate>,
+    ) {
+        for update in updates {
+            self.insert(update.account_id(), update.new_state_hash());
+        }
+    }
+}
+
+impl Default for AccountTree {
+    fn default() -> Self {
+        Self(SimpleSmt::new().expect("Unreachable"))
+    }
+}
+
+// ACCOUNT INCLUSION PROOF
+// ================================================================================================
+
+/// Proof of the state of an account against the root of an [AccountTree].
+///
+/// If the account hash is [EMPTY_WORD], the proof attests to the absence of the account from the
+/// tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AccountInclusionProof {
+    /// ID of the account.
+    account_id: AccountId,
+
+    /// Hash of the account state.
+    account_hash: Digest,
+
+    /// Authentication path from the account leaf to the root of the account tree.
+    path: MerklePath,
+}
+
+impl AccountInclusionProof {
+    /// Returns a new [AccountInclusionProof] instantiated from the provided components.
+    ///
+    /// # Errors
+    /// Returns an error if the depth of the path is not [ACCOUNT_TREE_DEPTH].
+    pub fn new(
+        account_id: AccountId,
+        account_hash: Digest,
+        path: MerklePath,
+    ) -> Result<Self, BlockError> {
+        if path.depth() != ACCOUNT_TREE_DEPTH {
+            return Err(BlockError::InvalidAccountProofDepth(path.depth()));
+        }
+
+        Ok(Self { account_id, account_hash, path })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the account.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the hash of the account state, or `None` if the proof attests to the absence of
+    /// the account.
+    pub fn account_hash(&self) -> Option<Digest> {
+        (self.account_hash != Digest::from(EMPTY_WORD)).then_some(self.account_hash)
+    }
+
+    /// Returns the authentication path of the account.
+    pub fn path(&self) -> &MerklePath {
+        &self.path
+    }
+
+    /// Returns the root of the account tree this proof was opened against.
+    pub fn compute_root(&self) -> Digest {
+        self.path
+            .compute_root(self.account_id.into(), self.account_hash)
+            .expect("path depth was validated on construction")
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies this proof against the provided account tree root.
+    ///
+    /// # Errors
+    /// Returns an error if the root computed from the proof does not match the provided root.
+    pub fn verify(&self, account_root: Digest) -> Result<(), BlockError> {
+        let computed_root = self.compute_root();
+        if computed_root != account_root {
+            return Err(BlockError::AccountProofRootMismatch {
+                account_id: self.account_id,
+                expected: account_root,
+                actual: computed_root,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Verifies this proof against the account root committed to by the provided block header.
+    ///
+    /// # Errors
+    /// Returns an error if the root computed from the proof does not match the account root of
+    /// the block.
+    pub fn verify_block(&self, block_header: &BlockHeader) -> Result<(), BlockError> {
+        self.verify(block_header.account_root())
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AccountTree {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u64(self.0.num_leaves() as u64);
+        target.write_many(self.0.leaves());
+    }
+}
+
+impl Deserializable for AccountTree {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        // the number of leaves is not trusted, so the vector is not preallocated
+        let count = source.read_u64()?;
+        let mut leaves: Vec<(u64, Word)> = Vec::new();
+        for _ in 0..count {
+            let (account_id, account_hash): (u64, Word) = source.read()?;
+            AccountId::try_from(account_id)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+            leaves.push((account_id, account_hash));
+        }
+
+        SimpleSmt::with_leaves(leaves)
+            .map(Self)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+impl Serializable for AccountInclusionProof {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_id.write_into(target);
+        self.account_hash.write_into(target);
+        self.path.write_into(target);
+    }
+}
+
+impl Deserializable for AccountInclusionProof {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account_id = AccountId::read_from(source)?;
+        let account_hash = Digest::read_from(source)?;
+        let path = MerklePath::read_from(source)?;
+
+        Self::new(account_id, account_hash, path)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_crypto::utils::{ByteWriter, Deserializable, Serializable};
+
+    use super::{AccountInclusionProof, AccountTree};
+    use crate::{
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+                ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+                ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+            },
+            AccountId,
+        },
+        alloc::vec::Vec,
+        errors::BlockError,
+        BlockHeader, Digest, Felt, ONE, ZERO,
+    };
+
+    fn block_header(account_root: Digest) -> BlockHeader {
+        BlockHeader::new(
+            0,
+            Digest::default(),
+            1,
+            Digest::default(),
+            account_root,
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            0,
+        )
+    }
+
+    #[test]
+    fn test_account_proof_verification() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let absent_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let account_hash = Digest::from([ONE, ZERO, ONE, Felt::new(5)]);
+        let faucet_hash = Digest::from([ZERO, ONE, ONE, Felt::new(7)]);
+
+        let mut tree =
+            AccountTree::with_entries([(account_id, account_hash), (faucet_id, faucet_hash)])
+                .unwrap();
+        let header = block_header(tree.root());
+
+        let proof = tree.open(account_id);
+        assert_eq!(proof.account_hash(), Some(account_hash));
+        proof.verify_block(&header).unwrap();
+
+        let absence_proof = tree.open(absent_id);
+        assert_eq!(absence_proof.account_hash(), None);
+        absence_proof.verify_block(&header).unwrap();
+
+        // a proof for a stale account state does not verify against the new root
+        assert_eq!(tree.insert(account_id, faucet_hash), Some(account_hash));
+        assert!(matches!(
+            proof.verify(tree.root()),
+            Err(BlockError::AccountProofRootMismatch { .. })
+        ));
+
+        // a proof with a forged account hash does not verify
+        let forged =
+            AccountInclusionProof::new(faucet_id, account_hash, proof.path().clone()).unwrap();
+        assert!(forged.verify_block(&header).is_err());
+    }
+
+    #[test]
+    fn test_serialization() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let tree =
+            AccountTree::with_entries([(account_id, Digest::from([ONE, ZERO, ONE, ONE]))]).unwrap();
+
+        let deserialized_tree = AccountTree::read_from_bytes(&tree.to_bytes()).unwrap();
+        assert_eq!(deserialized_tree, tree);
+
+        let proof = tree.open(account_id);
+        let deserialized_proof = AccountInclusionProof::read_from_bytes(&proof.to_bytes()).unwrap();
+        assert_eq!(deserialized_proof, proof);
+
+        // a tree claiming more leaves than it contains is rejected without allocating for them
+        let mut bytes = Vec::new();
+        bytes.write_u64(u64::MAX);
+        assert!(AccountTree::read_from_bytes(&bytes).is_err());
+    }
+}
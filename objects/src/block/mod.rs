@@ -2,10 +2,14 @@ use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
 
 use super::{Digest, Felt, Hasher, MAX_BATCHES_PER_BLOCK, MAX_NOTES_PER_BATCH, ZERO};
 
+mod account_tree;
+pub use account_tree::{AccountInclusionProof, AccountTree};
 mod header;
 pub use header::BlockHeader;
 mod note_tree;
 pub use note_tree::{BlockNoteIndex, BlockNoteTree};
+mod nullifier_tree;
+pub use nullifier_tree::{NullifierProof, NullifierTree};
 
 use crate::{
     accounts::{delta::AccountUpdateDetails, AccountId},
@@ -0,0 +1,411 @@
+use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
+
+use miden_crypto::merkle::{Smt, SmtProof};
+
+use super::BlockHeader;
+use crate::{
+    errors::BlockError,
+    notes::Nullifier,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Digest, Felt, Word, EMPTY_WORD, ZERO,
+};
+
+// NULLIFIER TREE
+// ================================================================================================
+
+/// Wrapper over [Smt] for the nullifier tree.
+///
+/// The tree maps the nullifiers of all consumed notes to the number of the block in which they
+/// were consumed, encoded as `[block_num, 0, 0, 0]`. Unspent nullifiers map to [EMPTY_WORD]. The
+/// root of this tree is committed to by [BlockHeader::nullifier_root()].
+///
+/// Since the genesis block does not contain any transactions, nullifiers cannot be spent in block
+/// `0`, which keeps the encoding of spent nullifiers distinct from [EMPTY_WORD].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NullifierTree(Smt);
+
+impl NullifierTree {
+    /// Returns a new [NullifierTree] instantiated with the provided (nullifier, block number)
+    /// entries.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The provided entries contain the same nullifier more than once.
+    /// - Any of the block numbers is `0`.
+    pub fn with_entries(
+        entries: impl IntoIterator<Item = (Nullifier, u32)>,
+    ) -> Result<Self, BlockError> {
+        let mut tree = Self::default();
+        for (nullifier, block_num) in entries {
+            tree.insert(nullifier, block_num)?;
+        }
+
+        Ok(tree)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> Digest {
+        self.0.root()
+    }
+
+    /// Returns the number of spent nullifiers in the tree.
+    pub fn num_nullifiers(&self) -> usize {
+        self.0.entries().count()
+    }
+
+    /// Returns the number of the block in which the provided nullifier was spent, or `None` if
+    /// the nullifier has not been spent.
+    pub fn get_block_num(&self, nullifier: &Nullifier) -> Option<u32> {
+        decode_block_num(self.0.get_value(&nullifier.inner()))
+    }
+
+    /// Returns true if the provided nullifier has been spent.
+    pub fn is_spent(&self, nullifier: &Nullifier) -> bool {
+        self.get_block_num(nullifier).is_some()
+    }
+
+    /// Returns an iterator over the (nullifier, block number) entries of the tree.
+    pub fn entries(&self) -> impl Iterator<Item = (Nullifier, u32)> + '_ {
+        self.0.entries().map(|(nullifier, value)| {
+            let block_num = decode_block_num(*value)
+                .expect("nullifier tree should only contain valid block numbers");
+            (Nullifier::from(*nullifier), block_num)
+        })
+    }
+
+    /// Returns a proof of the spent status of the provided nullifier against the root of this
+    /// tree.
+    ///
+    /// If the nullifier has not been spent, the returned proof attests to it being unspent.
+    pub fn open(&self, nullifier: &Nullifier) -> NullifierProof {
+        NullifierProof {
+            nullifier: *nullifier,
+            proof: self.0.open(&nullifier.inner()),
+        }
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Marks the provided nullifier as spent in the specified block.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The nullifier has already been spent.
+    /// - The block number is `0`.
+    pub fn insert(&mut self, nullifier: Nullifier, block_num: u32) -> Result<(), BlockError> {
+        self.validate_insertion(&nullifier, block_num)?;
+        self.0.insert(nullifier.inner(), encode_block_num(block_num));
+
+        Ok(())
+    }
+
+    /// Marks all provided nullifiers as spent in the specified block.
+    ///
+    /// Either all nullifiers are inserted, or the tree is left unchanged.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the nullifiers has already been spent.
+    /// - The provided nullifiers contain the same nullifier more than once.
+    /// - The block number is `0`.
+    pub fn apply_block_nullifiers(
+        &mut self,
+        nullifiers: impl IntoIterator<Item = Nullifier>,
+        block_num: u32,
+    ) -> Result<(), BlockError> {
+        let nullifiers: Vec<Nullifier> = nullifiers.into_iter().collect();
+
+        let mut seen = BTreeSet::new();
+        for nullifier in nullifiers.iter() {
+            self.validate_insertion(nullifier, block_num)?;
+            if !seen.insert(*nullifier) {
+                return Err(BlockError::DuplicateNullifier(*nullifier));
+            }
+        }
+
+        for nullifier in nullifiers {
+            self.0.insert(nullifier.inner(), encode_block_num(block_num));
+        }
+
+        Ok(())
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn validate_insertion(&self, nullifier: &Nullifier, block_num: u32) -> Result<(), BlockError> {
+        if block_num == 0 {
+            return Err(BlockError::NullifierSpentInGenesisBlock(*nullifier));
+        }
+        if let Some(spent_block_num) = self.get_block_num(nullifier) {
+            return Err(BlockError::NullifierAlreadySpent {
+                nullifier: *nullifier,
+                block_num: spent_block_num,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+// NULLIFIER PROOF
+// ================================================================================================
+
+/// Proof of the spent status of a nullifier against the root of a [NullifierTree].
+///
+/// The proof either attests to the nullifier having been spent in a specific block, or to the
+/// nullifier being unspent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NullifierProof {
+    /// The nullifier the proof is for.
+    nullifier: Nullifier,
+
+    /// Proof of the value of the nullifier in the nullifier tree.
+    proof: SmtProof,
+}
+
+impl NullifierProof {
+    /// Returns a new [NullifierProof] instantiated from the provided nullifier and tree proof.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The proof is not for the leaf of the provided nullifier.
+    /// - The value of the nullifier in the proof is not a valid block number encoding.
+    pub fn new(nullifier: Nullifier, proof: SmtProof) -> Result<Self, BlockError> {
+        let value = proof
+            .get(&nullifier.inner())
+            .ok_or(BlockError::NullifierProofKeyMismatch(nullifier))?;
+        if value != EMPTY_WORD && decode_block_num(value).is_none() {
+            return Err(BlockError::NullifierProofInvalidValue(nullifier));
+        }
+
+        Ok(Self { nullifier, proof })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the nullifier this proof is for.
+    pub fn nullifier(&self) -> Nullifier {
+        self.nullifier
+    }
+
+    /// Returns the number of the block in which the nullifier was spent, or `None` if the proof
+    /// attests to the nullifier being unspent.
+    pub fn block_num(&self) -> Option<u32> {
+        self.proof.get(&self.nullifier.inner()).and_then(decode_block_num)
+    }
+
+    /// Returns true if the proof attests to the nullifier having been spent.
+    pub fn is_spent(&self) -> bool {
+        self.block_num().is_some()
+    }
+
+    /// Returns the underlying proof against the nullifier tree.
+    pub fn proof(&self) -> &SmtProof {
+        &self.proof
+    }
+
+    /// Returns the root of the nullifier tree this proof was opened against.
+    pub fn compute_root(&self) -> Digest {
+        self.proof.compute_root()
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies this proof against the provided nullifier tree root.
+    ///
+    /// # Errors
+    /// Returns an error if the root computed from the proof does not match the provided root.
+    pub fn verify(&self, nullifier_root: Digest) -> Result<(), BlockError> {
+        let computed_root = self.compute_root();
+        if computed_root != nullifier_root {
+            return Err(BlockError::NullifierProofRootMismatch {
+                nullifier: self.nullifier,
+                expected: nullifier_root,
+                actual: computed_root,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Verifies this proof against the nullifier root committed to by the provided block header.
+    ///
+    /// # Errors
+    /// Returns an error if the root computed from the proof does not match the nullifier root of
+    /// the block.
+    pub fn verify_block(&self, block_header: &BlockHeader) -> Result<(), BlockError> {
+        self.verify(block_header.nullifier_root())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the value under which a nullifier spent in the specified block is stored in the tree.
+fn encode_block_num(block_num: u32) -> Word {
+    [Felt::from(block_num), ZERO, ZERO, ZERO]
+}
+
+/// Returns the block number encoded in the provided tree value, or `None` if the value does not
+/// encode a non-genesis block number.
+fn decode_block_num(value: Word) -> Option<u32> {
+    if value[1..].iter().any(|element| *element != ZERO) {
+        return None;
+    }
+
+    u32::try_from(value[0].as_int()).ok().filter(|block_num| *block_num != 0)
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NullifierTree {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let entries: Vec<(Nullifier, u32)> = self.entries().collect();
+        target.write_u64(entries.len() as u64);
+        for (nullifier, block_num) in entries {
+            nullifier.write_into(target);
+            target.write_u32(block_num);
+        }
+    }
+}
+
+impl Deserializable for NullifierTree {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let count = source.read_u64()?;
+        let mut tree = Self::default();
+        for _ in 0..count {
+            let nullifier = Nullifier::read_from(source)?;
+            let block_num = source.read_u32()?;
+            tree.insert(nullifier, block_num)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        }
+
+        Ok(tree)
+    }
+}
+
+impl Serializable for NullifierProof {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.nullifier.write_into(target);
+        self.proof.write_into(target);
+    }
+}
+
+impl Deserializable for NullifierProof {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let nullifier = Nullifier::read_from(source)?;
+        let proof = SmtProof::read_from(source)?;
+
+        Self::new(nullifier, proof)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_crypto::utils::{Deserializable, Serializable};
+
+    use super::{NullifierProof, NullifierTree};
+    use crate::{errors::BlockError, notes::Nullifier, BlockHeader, Digest, Felt, ONE, ZERO};
+
+    fn nullifier(value: u64) -> Nullifier {
+        Nullifier::from(Digest::from([Felt::new(value), ONE, ZERO, Felt::new(value)]))
+    }
+
+    fn block_header(nullifier_root: Digest) -> BlockHeader {
+        BlockHeader::new(
+            0,
+            Digest::default(),
+            3,
+            Digest::default(),
+            Digest::default(),
+            nullifier_root,
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            0,
+        )
+    }
+
+    #[test]
+    fn test_nullifier_proofs() {
+        let tree = NullifierTree::with_entries([(nullifier(1), 1), (nullifier(2), 2)]).unwrap();
+        let header = block_header(tree.root());
+
+        let spent_proof = tree.open(&nullifier(2));
+        assert_eq!(spent_proof.block_num(), Some(2));
+        spent_proof.verify_block(&header).unwrap();
+
+        let unspent_proof = tree.open(&nullifier(3));
+        assert!(!unspent_proof.is_spent());
+        unspent_proof.verify_block(&header).unwrap();
+
+        // a proof that a nullifier is unspent does not verify once the nullifier is spent
+        let mut tree = tree;
+        tree.insert(nullifier(3), 3).unwrap();
+        assert!(matches!(
+            unspent_proof.verify(tree.root()),
+            Err(BlockError::NullifierProofRootMismatch { .. })
+        ));
+
+        // a proof cannot be used for a different nullifier
+        assert!(matches!(
+            NullifierProof::new(nullifier(4), spent_proof.proof().clone()),
+            Err(BlockError::NullifierProofKeyMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn test_double_spend_detection() {
+        let mut tree = NullifierTree::with_entries([(nullifier(1), 1)]).unwrap();
+
+        assert!(matches!(
+            tree.insert(nullifier(1), 2),
+            Err(BlockError::NullifierAlreadySpent { block_num: 1, .. })
+        ));
+        assert!(matches!(
+            tree.insert(nullifier(2), 0),
+            Err(BlockError::NullifierSpentInGenesisBlock(_))
+        ));
+
+        // a failed batch leaves the tree unchanged
+        let root = tree.root();
+        assert!(matches!(
+            tree.apply_block_nullifiers([nullifier(2), nullifier(3), nullifier(2)], 2),
+            Err(BlockError::DuplicateNullifier(_))
+        ));
+        assert!(matches!(
+            tree.apply_block_nullifiers([nullifier(2), nullifier(1)], 2),
+            Err(BlockError::NullifierAlreadySpent { .. })
+        ));
+        assert_eq!(tree.root(), root);
+
+        tree.apply_block_nullifiers([nullifier(2), nullifier(3)], 2).unwrap();
+        assert_eq!(tree.num_nullifiers(), 3);
+        assert_eq!(tree.get_block_num(&nullifier(3)), Some(2));
+    }
+
+    #[test]
+    fn test_serialization() {
+        let tree = NullifierTree::with_entries([(nullifier(1), 1), (nullifier(2), 5)]).unwrap();
+
+        let deserialized_tree = NullifierTree::read_from_bytes(&tree.to_bytes()).unwrap();
+        assert_eq!(deserialized_tree, tree);
+
+        let proof = tree.open(&nullifier(2));
+        let deserialized_proof = NullifierProof::read_from_bytes(&proof.to_bytes()).unwrap();
+        assert_eq!(deserialized_proof, proof);
+    }
+}
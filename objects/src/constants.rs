@@ -13,12 +13,21 @@ pub const MAX_ASSETS_PER_NOTE: usize = 256;
 /// evenly divisible by 8.
 pub const MAX_INPUTS_PER_NOTE: usize = 128;
 
+/// The maximum number of inputs that can accompany a single note whose inputs are streamed from
+/// the advice provider rather than loaded into memory all at once.
+///
+/// The value is set so that the number of inputs can be represented using two bytes.
+pub const MAX_STREAMED_INPUTS_PER_NOTE: usize = u16::MAX as usize;
+
 /// The maximum number of notes that can be consumed by a single transaction.
 pub const MAX_INPUT_NOTES_PER_TX: usize = 1023;
 
 /// The maximum number of new notes created by a single transaction.
 pub const MAX_OUTPUT_NOTES_PER_TX: usize = 4096;
 
+/// The maximum number of foreign accounts which can be accessed by a single transaction.
+pub const MAX_FOREIGN_ACCOUNTS_PER_TX: usize = 64;
+
 /// The minimum proof security level used by the Miden prover & verifier.
 pub const MIN_PROOF_SECURITY_LEVEL: u32 = 96;
 
@@ -6,9 +6,9 @@ use vm_processor::DeserializationError;
 use super::{
     accounts::{AccountId, StorageSlotType},
     assets::{Asset, FungibleAsset, NonFungibleAsset},
-    crypto::merkle::MerkleError,
-    notes::NoteId,
-    Digest, Word, MAX_BATCHES_PER_BLOCK, MAX_NOTES_PER_BATCH,
+    crypto::merkle::{MerkleError, MmrError},
+    notes::{NoteId, Nullifier},
+    Digest, Word, ACCOUNT_TREE_DEPTH, MAX_BATCHES_PER_BLOCK, MAX_NOTES_PER_BATCH,
 };
 use crate::{
     accounts::{delta::AccountUpdateDetails, AccountType},
@@ -22,10 +22,16 @@ use crate::{
 pub enum AccountError {
     AccountCodeAssemblyError(String), // TODO: use Report
     AccountCodeDeserializationError(DeserializationError),
+    AccountCodeDuplicateProcedureRoot(Digest),
     AccountCodeNoProcedures,
     AccountCodeTooManyProcedures { max: usize, actual: usize },
     AccountCodeProcedureInvalidStorageOffset,
+    AccountCodeProcedureInvalidStorageSize,
+    AccountCodeProcedureStorageOutOfBounds { storage_offset: u8, storage_size: u8 },
     AccountCodeProcedureInvalidPadding,
+    AccountComponentDuplicateProcedure(Digest),
+    AccountComponentTooManyStorageSlots { max: usize, actual: usize },
+    AccountComponentUnsupportedAccountType(AccountType),
     AccountIdInvalidFieldElement(String),
     AccountIdTooFewOnes(u32, u32),
     AssetVaultUpdateError(AssetVaultError),
@@ -34,14 +40,19 @@ pub enum AccountError {
     FungibleFaucetInvalidMetadata(String),
     HexParseError(String),
     InvalidAccountStorageType,
+    InvalidVanityPrefix(String),
     MapsUpdateToNonMapsSlot(u8, StorageSlotType),
+    NonFungibleFaucetInvalidMetadata(String),
     NonceNotMonotonicallyIncreasing { current: u64, new: u64 },
     SeedDigestTooFewTrailingZeros { expected: u32, actual: u32 },
+    SocialRecoveryInvalidConfig(String),
+    SpendingLimitInvalidConfig(String),
     StorageSlotInvalidValueArity { slot: u8, expected: u8, actual: u8 },
     StorageSlotIsReserved(u8),
     StorageSlotMapOrArrayNotAllowed(u8, StorageSlotType),
     StorageMapNotFound(u8),
     StorageMapTooManyMaps { expected: usize, actual: usize },
+    StorageSchemaSlotTypeMismatch(u8, StorageSlotType),
     StubDataIncorrectLength(usize, usize),
 }
 
@@ -138,24 +149,46 @@ impl std::error::Error for AssetVaultError {}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NoteError {
+    DecryptedNoteIdMismatch {
+        expected: NoteId,
+        actual: NoteId,
+    },
     DuplicateFungibleAsset(AccountId),
     DuplicateNonFungibleAsset(NonFungibleAsset),
+    DuplicateNoteLibraryNamespace(String),
+    InclusionProofBlockMismatch {
+        proof_block_num: u32,
+        block_num: u32,
+    },
+    InclusionProofInvalidPath(MerkleError),
+    InclusionProofNoteRootMismatch {
+        note_id: NoteId,
+        expected: Digest,
+        actual: Digest,
+    },
     InconsistentNoteTag(NoteType, u64),
     InvalidAssetData(AssetError),
     InvalidNoteSender(AccountError),
     InvalidNoteTagUseCase(u16),
     InvalidNoteExecutionHintTag(u8),
+    InvalidNoteFileSignature,
+    InvalidNoteFileText(String),
     InvalidNoteExecutionHintPayload(u8, u32),
     InvalidNoteType(NoteType),
     InvalidNoteTypeValue(u64),
     InvalidLocationIndex(String),
     InvalidStubDataLen(usize),
+    InvalidWellKnownNoteInputs(String),
     NetworkExecutionRequiresOnChainAccount,
     NetworkExecutionRequiresPublicNote(NoteType),
+    NonContributoryEncryptionKey,
+    NoteDecryptionFailed,
     NoteDeserializationError(DeserializationError),
+    NoteFileNotSigned,
     NoteScriptAssemblyError(String), // TODO: use Report
     NoteScriptDeserializationError(DeserializationError),
     PublicUseCaseRequiresPublicNote(NoteType),
+    ReservedNoteLibraryNamespace(String),
     TooManyAssets(usize),
     TooManyInputs(usize),
 }
@@ -197,7 +230,12 @@ impl std::error::Error for NoteError {}
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ChainMmrError {
     BlockNumTooBig { chain_length: usize, block_num: u32 },
+    DeltaLengthMismatch { chain_length: usize, from_length: usize },
     DuplicateBlock { block_num: u32 },
+    InvalidBlockPath { block_num: u32 },
+    InvalidMmrDelta(MmrError),
+    InvalidNoteInclusionProof(NoteError),
+    UnexpectedBlock { chain_length: usize, block_num: u32 },
     UntrackedBlock { block_num: u32 },
 }
 
@@ -206,10 +244,22 @@ impl ChainMmrError {
         Self::BlockNumTooBig { chain_length, block_num }
     }
 
+    pub fn delta_length_mismatch(chain_length: usize, from_length: usize) -> Self {
+        Self::DeltaLengthMismatch { chain_length, from_length }
+    }
+
     pub fn duplicate_block(block_num: u32) -> Self {
         Self::DuplicateBlock { block_num }
     }
 
+    pub fn invalid_block_path(block_num: u32) -> Self {
+        Self::InvalidBlockPath { block_num }
+    }
+
+    pub fn unexpected_block(chain_length: usize, block_num: u32) -> Self {
+        Self::UnexpectedBlock { chain_length, block_num }
+    }
+
     pub fn untracked_block(block_num: u32) -> Self {
         Self::UntrackedBlock { block_num }
     }
@@ -248,13 +298,18 @@ impl std::error::Error for TransactionScriptError {}
 pub enum TransactionInputError {
     AccountSeedNotProvidedForNewAccount,
     AccountSeedProvidedForExistingAccount,
+    DuplicateForeignAccount(AccountId),
     DuplicateInputNote(Digest),
+    ForeignAccountIsNativeAccount(AccountId),
+    ForeignAccountNotInBlock(AccountId, u32),
     InconsistentAccountSeed { expected: AccountId, actual: AccountId },
     InconsistentChainLength { expected: u32, actual: u32 },
     InconsistentChainRoot { expected: Digest, actual: Digest },
     InputNoteBlockNotInChainMmr(NoteId),
     InputNoteNotInBlock(NoteId, u32),
     InvalidAccountSeed(AccountError),
+    InvalidForeignAccountPath(AccountId),
+    TooManyForeignAccounts { max: usize, actual: usize },
     TooManyInputNotes { max: usize, actual: usize },
 }
 
@@ -352,7 +407,26 @@ impl std::error::Error for ProvenTransactionError {}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BlockError {
+    AccountProofRootMismatch {
+        account_id: AccountId,
+        expected: Digest,
+        actual: Digest,
+    },
     DuplicateNoteFound(NoteId),
+    DuplicateNullifier(Nullifier),
+    InvalidAccountProofDepth(u8),
+    NullifierAlreadySpent {
+        nullifier: Nullifier,
+        block_num: u32,
+    },
+    NullifierProofInvalidValue(Nullifier),
+    NullifierProofKeyMismatch(Nullifier),
+    NullifierProofRootMismatch {
+        nullifier: Nullifier,
+        expected: Digest,
+        actual: Digest,
+    },
+    NullifierSpentInGenesisBlock(Nullifier),
     TooManyNotesInBatch(usize),
     TooManyTransactionBatches(usize),
 }
@@ -360,9 +434,42 @@ pub enum BlockError {
 impl fmt::Display for BlockError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            BlockError::AccountProofRootMismatch { account_id, expected, actual } => {
+                write!(
+                    f,
+                    "Proof for account {account_id} computes account root {actual}, but expected {expected}"
+                )
+            },
             BlockError::DuplicateNoteFound(id) => {
                 write!(f, "Duplicate note {id} found in the block")
             },
+            BlockError::DuplicateNullifier(nullifier) => {
+                write!(f, "Duplicate nullifier {nullifier} found in the block")
+            },
+            BlockError::InvalidAccountProofDepth(depth) => {
+                write!(
+                    f,
+                    "Account proof has depth {depth}, but the account tree depth is {ACCOUNT_TREE_DEPTH}"
+                )
+            },
+            BlockError::NullifierAlreadySpent { nullifier, block_num } => {
+                write!(f, "Nullifier {nullifier} was already spent in block {block_num}")
+            },
+            BlockError::NullifierProofInvalidValue(nullifier) => {
+                write!(f, "Proof for nullifier {nullifier} does not encode a valid block number")
+            },
+            BlockError::NullifierProofKeyMismatch(nullifier) => {
+                write!(f, "Proof is not for the leaf of nullifier {nullifier}")
+            },
+            BlockError::NullifierProofRootMismatch { nullifier, expected, actual } => {
+                write!(
+                    f,
+                    "Proof for nullifier {nullifier} computes nullifier root {actual}, but expected {expected}"
+                )
+            },
+            BlockError::NullifierSpentInGenesisBlock(nullifier) => {
+                write!(f, "Nullifier {nullifier} cannot be spent in the genesis block")
+            },
             BlockError::TooManyNotesInBatch(actual) => {
                 write!(f, "Too many notes in a batch. Max: {MAX_NOTES_PER_BATCH}, actual: {actual}")
             },
@@ -34,7 +34,7 @@ pub use vm_core::{Felt, FieldElement, StarkField, Word, EMPTY_WORD, ONE, WORD_SI
 
 pub mod assembly {
     pub use assembly::{
-        mast, Assembler, AssemblyError, DefaultSourceManager, KernelLibrary, Library,
+        ast, mast, Assembler, AssemblyError, Compile, DefaultSourceManager, KernelLibrary, Library,
         LibraryNamespace, LibraryPath, SourceManager, Version,
     };
 }
@@ -47,6 +47,11 @@ pub mod utils {
     pub use miden_crypto::utils::{bytes_to_hex_string, collections, hex_to_bytes, HexParseError};
     pub use vm_core::utils::*;
 
+    #[cfg(feature = "std")]
+    mod fs;
+    #[cfg(feature = "std")]
+    pub use fs::write_atomic;
+
     pub mod serde {
         pub use miden_crypto::utils::{
             ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
@@ -0,0 +1,325 @@
+use alloc::vec::Vec;
+
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use rand::{CryptoRng, RngCore};
+use x25519_dalek::{PublicKey, StaticSecret};
+
+use super::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Note, NoteDetails, NoteError,
+    NoteHeader, NoteId, NoteMetadata, NoteType, Serializable,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Context string for deriving note encryption keys from X25519 shared secrets.
+const KEY_DERIVATION_CONTEXT: &str = "miden-base 2024 note encryption v1";
+
+/// Each encryption uses a fresh ephemeral key, and thus a fresh symmetric key, so a constant nonce
+/// is never reused with the same key.
+const NONCE: [u8; 12] = [0; 12];
+
+// NOTE ENCRYPTION KEYS
+// ================================================================================================
+
+/// A public key to which the details of [NoteType::Encrypted] notes can be encrypted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteEncryptionKey(PublicKey);
+
+impl NoteEncryptionKey {
+    /// Returns the bytes of this key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+}
+
+impl From<[u8; 32]> for NoteEncryptionKey {
+    fn from(bytes: [u8; 32]) -> Self {
+        Self(PublicKey::from(bytes))
+    }
+}
+
+/// A secret key with which the details of [NoteType::Encrypted] notes encrypted to the
+/// corresponding [NoteEncryptionKey] can be decrypted.
+#[derive(Clone)]
+pub struct NoteDecryptionKey(StaticSecret);
+
+impl NoteDecryptionKey {
+    /// Returns a new random [NoteDecryptionKey] generated using the provided RNG.
+    pub fn new<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        Self(StaticSecret::random_from_rng(rng))
+    }
+
+    /// Returns the [NoteEncryptionKey] corresponding to this key.
+    pub fn encryption_key(&self) -> NoteEncryptionKey {
+        NoteEncryptionKey(PublicKey::from(&self.0))
+    }
+
+    /// Returns the bytes of this key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+}
+
+impl From<[u8; 32]> for NoteDecryptionKey {
+    fn from(bytes: [u8; 32]) -> Self {
+        Self(StaticSecret::from(bytes))
+    }
+}
+
+impl core::fmt::Debug for NoteDecryptionKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("NoteDecryptionKey").field(&self.encryption_key()).finish()
+    }
+}
+
+// ENCRYPTED NOTE
+// ================================================================================================
+
+/// A [NoteType::Encrypted] note whose details are encrypted to a recipient.
+///
+/// The details of the note are encrypted with ChaCha20-Poly1305 under a key derived from an X25519
+/// key exchange between a fresh ephemeral key and the [NoteEncryptionKey] of the recipient. The
+/// note header is authenticated as associated data, so the ciphertext cannot be moved to a
+/// different header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncryptedNote {
+    header: NoteHeader,
+    ephemeral_key: [u8; 32],
+    ciphertext: Vec<u8>,
+}
+
+impl EncryptedNote {
+    /// Returns a new [EncryptedNote] with the details of the provided note encrypted to the
+    /// specified recipient key.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The note is not of type [NoteType::Encrypted].
+    /// - The recipient key is a low-order point, i.e., it would not contribute to the shared key.
+    pub fn new<R: RngCore + CryptoRng>(
+        note: &Note,
+        recipient_key: &NoteEncryptionKey,
+        rng: &mut R,
+    ) -> Result<Self, NoteError> {
+        let note_type = note.metadata().note_type();
+        if note_type != NoteType::Encrypted {
+            return Err(NoteError::InvalidNoteType(note_type));
+        }
+
+        let ephemeral_secret = StaticSecret::random_from_rng(rng);
+        let ephemeral_key = PublicKey::from(&ephemeral_secret).to_bytes();
+        let cipher = note_cipher(&ephemeral_secret, &recipient_key.0, ephemeral_key)?;
+
+        let header = *note.header();
+        let plaintext = NoteDetails::from(note).to_bytes();
+        let aad = header.to_bytes();
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&NONCE), Payload { msg: &plaintext, aad: &aad })
+            .expect("note details should fit into a single ciphertext");
+
+        Ok(Self { header, ephemeral_key, ciphertext })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the header of the note.
+    pub fn header(&self) -> &NoteHeader {
+        &self.header
+    }
+
+    /// Returns the ID of the note.
+    pub fn id(&self) -> NoteId {
+        self.header.id()
+    }
+
+    /// Returns the metadata of the note.
+    pub fn metadata(&self) -> &NoteMetadata {
+        self.header.metadata()
+    }
+
+    /// Returns the encrypted note details.
+    pub fn ciphertext(&self) -> &[u8] {
+        &self.ciphertext
+    }
+
+    // DECRYPTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Decrypts the details of this note with the provided key and returns the full note.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The note was not encrypted to the provided key, or the ciphertext was tampered with.
+    /// - The decrypted details do not deserialize, or do not match the ID of the note header.
+    pub fn decrypt(&self, key: &NoteDecryptionKey) -> Result<Note, NoteError> {
+        let ephemeral_key = PublicKey::from(self.ephemeral_key);
+        let cipher = note_cipher(&key.0, &ephemeral_key, self.ephemeral_key)?;
+
+        let aad = self.header.to_bytes();
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(&NONCE), Payload { msg: &self.ciphertext, aad: &aad })
+            .map_err(|_| NoteError::NoteDecryptionFailed)?;
+        let details = NoteDetails::read_from_bytes(&plaintext)
+            .map_err(NoteError::NoteDeserializationError)?;
+
+        if details.id() != self.id() {
+            return Err(NoteError::DecryptedNoteIdMismatch {
+                expected: self.id(),
+                actual: details.id(),
+            });
+        }
+
+        let (assets, recipient) = details.into_parts();
+        Ok(Note::new(assets, *self.metadata(), recipient))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the cipher keyed by the X25519 shared secret of the provided keys.
+///
+/// The encryption key is derived from the shared secret and the ephemeral public key of the
+/// sender, binding the key to the exchange.
+fn note_cipher(
+    secret: &StaticSecret,
+    public_key: &PublicKey,
+    ephemeral_key: [u8; 32],
+) -> Result<ChaCha20Poly1305, NoteError> {
+    let shared_secret = secret.diffie_hellman(public_key);
+    if !shared_secret.was_contributory() {
+        return Err(NoteError::NonContributoryEncryptionKey);
+    }
+
+    let mut hasher = blake3::Hasher::new_derive_key(KEY_DERIVATION_CONTEXT);
+    hasher.update(shared_secret.as_bytes());
+    hasher.update(&ephemeral_key);
+    let key: [u8; 32] = hasher.finalize().into();
+
+    Ok(ChaCha20Poly1305::new(Key::from_slice(&key)))
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NoteEncryptionKey {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(self.0.as_bytes());
+    }
+}
+
+impl Deserializable for NoteEncryptionKey {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self::from(source.read_array::<32>()?))
+    }
+}
+
+impl Serializable for EncryptedNote {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.header.write_into(target);
+        target.write_bytes(&self.ephemeral_key);
+        self.ciphertext.write_into(target);
+    }
+}
+
+impl Deserializable for EncryptedNote {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let header = NoteHeader::read_from(source)?;
+        let ephemeral_key = source.read_array::<32>()?;
+        let ciphertext = Vec::<u8>::read_from(source)?;
+
+        Ok(Self { header, ephemeral_key, ciphertext })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+    use vm_core::utils::{Deserializable, Serializable};
+
+    use super::{EncryptedNote, NoteDecryptionKey};
+    use crate::{
+        accounts::{account_id::testing::ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, AccountId},
+        assets::{Asset, FungibleAsset},
+        notes::{
+            Note, NoteAssets, NoteExecutionHint, NoteHeader, NoteInputs, NoteMetadata,
+            NoteRecipient, NoteScript, NoteTag, NoteType,
+        },
+        Felt, NoteError, ZERO,
+    };
+
+    fn create_note(note_type: NoteType) -> Note {
+        let faucet = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let serial_num = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+        let recipient =
+            NoteRecipient::new(serial_num, NoteScript::mock(), NoteInputs::new(vec![]).unwrap());
+        let asset = Asset::Fungible(FungibleAsset::new(faucet, 100).unwrap());
+        let metadata = NoteMetadata::new(
+            faucet,
+            note_type,
+            NoteTag::for_local_use_case(1, 0).unwrap(),
+            NoteExecutionHint::always(),
+            ZERO,
+        )
+        .unwrap();
+
+        Note::new(NoteAssets::new(vec![asset]).unwrap(), metadata, recipient)
+    }
+
+    #[test]
+    fn test_encrypted_note_roundtrip() {
+        let mut rng = ChaCha20Rng::from_seed([0; 32]);
+        let key = NoteDecryptionKey::new(&mut rng);
+        let note = create_note(NoteType::Encrypted);
+
+        let encrypted = EncryptedNote::new(&note, &key.encryption_key(), &mut rng).unwrap();
+        let encrypted = EncryptedNote::read_from_bytes(&encrypted.to_bytes()).unwrap();
+        assert_eq!(encrypted.id(), note.id());
+        assert_eq!(encrypted.decrypt(&key).unwrap(), note);
+
+        // the note cannot be decrypted with a different key
+        let other_key = NoteDecryptionKey::new(&mut rng);
+        assert_eq!(encrypted.decrypt(&other_key), Err(NoteError::NoteDecryptionFailed));
+
+        // the ciphertext cannot be moved to a different header
+        let mut tampered = encrypted.clone();
+        tampered.header = NoteHeader::new(
+            tampered.id(),
+            NoteMetadata::new(
+                note.metadata().sender(),
+                NoteType::Encrypted,
+                NoteTag::for_local_use_case(2, 0).unwrap(),
+                NoteExecutionHint::always(),
+                ZERO,
+            )
+            .unwrap(),
+        );
+        assert_eq!(tampered.decrypt(&key), Err(NoteError::NoteDecryptionFailed));
+    }
+
+    #[test]
+    fn test_only_encrypted_notes_are_encrypted() {
+        let mut rng = ChaCha20Rng::from_seed([0; 32]);
+        let key = NoteDecryptionKey::new(&mut rng).encryption_key();
+
+        assert_eq!(
+            EncryptedNote::new(&create_note(NoteType::Private), &key, &mut rng),
+            Err(NoteError::InvalidNoteType(NoteType::Private))
+        );
+        assert_eq!(
+            EncryptedNote::new(&create_note(NoteType::Encrypted), &[0; 32].into(), &mut rng),
+            Err(NoteError::NonContributoryEncryptionKey)
+        );
+    }
+}
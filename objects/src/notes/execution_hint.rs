@@ -118,16 +118,17 @@ impl NoteExecutionHint {
                 Some(block_num >= *hint_block_num)
             },
             NoteExecutionHint::OnBlockSlot { epoch_len, slot_len, slot_offset } => {
-                let epoch_len_blocks: u32 = 1 << epoch_len;
-                let slot_len_blocks: u32 = 1 << slot_len;
+                // lengths of 32 and above cover the entire range of block numbers
+                let epoch_len_blocks: u64 = 1 << (*epoch_len).min(32);
+                let slot_len_blocks: u64 = 1 << (*slot_len).min(32);
 
-                let block_epoch_index = block_num / epoch_len_blocks;
+                let block_in_epoch = block_num as u64 % epoch_len_blocks;
 
-                let slot_start_block =
-                    block_epoch_index * epoch_len_blocks + (*slot_offset as u32) * slot_len_blocks;
+                let slot_start_block = (*slot_offset as u64) * slot_len_blocks;
                 let slot_end_block = slot_start_block + slot_len_blocks;
 
-                let can_be_consumed = block_num >= slot_start_block && block_num < slot_end_block;
+                let can_be_consumed =
+                    block_in_epoch >= slot_start_block && block_in_epoch < slot_end_block;
                 Some(can_be_consumed)
             },
         }
@@ -250,6 +251,12 @@ mod tests {
         assert!(on_block_slot.can_be_consumed(2176).unwrap()); // Block 2176 is in the slot 2176..2303
         assert!(!on_block_slot.can_be_consumed(2175).unwrap()); // Block 1279 is in the slot
                                                                 // 2176..2303
+
+        // epochs and slots of 2^32 blocks and above cover all block numbers
+        let on_block_slot = NoteExecutionHint::on_block_slot(40, 32, 0);
+        assert!(on_block_slot.can_be_consumed(u32::MAX).unwrap());
+        let on_block_slot = NoteExecutionHint::on_block_slot(40, 32, 1);
+        assert!(!on_block_slot.can_be_consumed(0).unwrap());
     }
 
     #[test]
@@ -1,12 +1,42 @@
+use alloc::string::{String, ToString};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use miden_crypto::{
+    dsa::rpo_falcon512::{PublicKey, SecretKey, Signature},
+    rand::FeltRng,
+};
 use vm_core::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
 use vm_processor::DeserializationError;
 
-use super::{Note, NoteDetails, NoteId, NoteInclusionProof, NoteTag};
+use super::{
+    Note, NoteAssets, NoteDetails, NoteError, NoteId, NoteInclusionProof, NoteInputs, NoteMetadata,
+    NoteRecipient, NoteScript, NoteTag,
+};
+use crate::{Felt, Hasher, Word};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Magic value at the start of every serialized note file.
+const NOTE_FILE_MAGIC: &str = "note";
+
+/// Marker following the magic value in versioned note files.
+///
+/// Files written before the format was versioned have the variant of the [NoteFile] in this
+/// position instead, which is always smaller than the marker.
+const VERSIONED_FORMAT_MARKER: u8 = 0xff;
+
+/// Prefix of the text encoding of a [NoteFileEnvelope].
+const NOTE_FILE_TEXT_PREFIX: &str = "miden-note:";
 
 // NOTE FILE
 // ================================================================================================
 
 /// A serialized representation of a note.
+///
+/// Note files are serialized with a version, and files written before the format was versioned
+/// can still be read. To exchange note files between users, see [NoteFileEnvelope].
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NoteFile {
     /// The note's details aren't known.
     NoteId(NoteId),
@@ -27,6 +57,14 @@ pub enum NoteFile {
     NoteWithProof(Note, NoteInclusionProof),
 }
 
+impl NoteFile {
+    /// The current version of the note file format.
+    ///
+    /// Version 2 widened the number of note inputs from a `u8` to a `u16`; files of previous
+    /// versions are read with the narrower width.
+    pub const VERSION: u8 = 2;
+}
+
 impl From<NoteDetails> for NoteFile {
     fn from(details: NoteDetails) -> Self {
         NoteFile::NoteDetails { details, after_block_num: 0, tag: None }
@@ -44,7 +82,9 @@ impl From<NoteId> for NoteFile {
 
 impl Serializable for NoteFile {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        target.write_bytes("note".as_bytes());
+        target.write_bytes(NOTE_FILE_MAGIC.as_bytes());
+        target.write_u8(VERSIONED_FORMAT_MARKER);
+        target.write_u8(Self::VERSION);
         match self {
             NoteFile::NoteId(note_id) => {
                 target.write_u8(0);
@@ -68,23 +108,40 @@ impl Serializable for NoteFile {
 impl Deserializable for NoteFile {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let magic_value = source.read_string(4)?;
-        if magic_value != "note" {
+        if magic_value != NOTE_FILE_MAGIC {
             return Err(DeserializationError::InvalidValue(format!(
                 "Invalid note file marker: {magic_value}"
             )));
         }
-        match source.read_u8()? {
+
+        // files written before the format was versioned start with the variant directly, and are
+        // treated as version 0
+        let mut variant = source.read_u8()?;
+        let mut version = 0;
+        if variant == VERSIONED_FORMAT_MARKER {
+            version = source.read_u8()?;
+            if version == 0 || version > Self::VERSION {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "Unsupported note file version {version}, the latest supported version is {}",
+                    Self::VERSION
+                )));
+            }
+            variant = source.read_u8()?;
+        }
+
+        match variant {
             0 => Ok(NoteFile::NoteId(NoteId::read_from(source)?)),
             1 => {
-                let details = NoteDetails::read_from(source)?;
+                let details = read_note_details(source, version)?;
                 let after_block_num = u32::read_from(source)?;
                 let tag = Option::<NoteTag>::read_from(source)?;
                 Ok(NoteFile::NoteDetails { details, after_block_num, tag })
             },
             2 => {
-                let note = Note::read_from(source)?;
+                let metadata = NoteMetadata::read_from(source)?;
+                let (assets, recipient) = read_note_details(source, version)?.into_parts();
                 let proof = NoteInclusionProof::read_from(source)?;
-                Ok(NoteFile::NoteWithProof(note, proof))
+                Ok(NoteFile::NoteWithProof(Note::new(assets, metadata, recipient), proof))
             },
             v => {
                 Err(DeserializationError::InvalidValue(format!("Unknown variant {v} for NoteFile")))
@@ -93,6 +150,205 @@ impl Deserializable for NoteFile {
     }
 }
 
+/// Reads note details serialized in a note file of the specified version.
+///
+/// Before version 2, the number of note inputs was serialized as a `u8`.
+fn read_note_details<R: ByteReader>(
+    source: &mut R,
+    version: u8,
+) -> Result<NoteDetails, DeserializationError> {
+    if version >= 2 {
+        return NoteDetails::read_from(source);
+    }
+
+    let assets = NoteAssets::read_from(source)?;
+    let script = NoteScript::read_from(source)?;
+    let num_values = source.read_u8()? as usize;
+    let values = source.read_many::<Felt>(num_values)?;
+    let inputs = NoteInputs::new(values)
+        .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+    let serial_num = Word::read_from(source)?;
+
+    Ok(NoteDetails::new(assets, NoteRecipient::new(serial_num, script, inputs)))
+}
+
+// NOTE FILE ENVELOPE
+// ================================================================================================
+
+/// A [NoteFile] optionally signed by its sender, for exchanging notes between users out of band.
+///
+/// The signature covers the serialized note file. Envelopes can be encoded as text (see
+/// [NoteFileEnvelope::to_text()]) for sharing via QR codes or chat messages.
+///
+/// Envelopes which are read from bytes or text are returned as an [UnverifiedNoteFileEnvelope],
+/// since anyone can sign an envelope with their own key: the signature proves that the envelope
+/// comes from a given sender only once it is verified against the key expected from that sender.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteFileEnvelope {
+    file: NoteFile,
+    signature: Option<Signature>,
+}
+
+impl NoteFileEnvelope {
+    /// Returns a new unsigned [NoteFileEnvelope] containing the provided note file.
+    pub fn new(file: NoteFile) -> Self {
+        Self { file, signature: None }
+    }
+
+    /// Returns a new [NoteFileEnvelope] containing the provided note file signed with the
+    /// provided key.
+    pub fn new_signed<R: FeltRng>(file: NoteFile, sender_key: &SecretKey, rng: &mut R) -> Self {
+        let signature = sender_key.sign_with_rng(file_commitment(&file), rng);
+        Self { file, signature: Some(signature) }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the note file contained in this envelope.
+    pub fn file(&self) -> &NoteFile {
+        &self.file
+    }
+
+    /// Returns the public key of the sender who signed this envelope, or `None` if the envelope is
+    /// not signed.
+    pub fn sender_key(&self) -> Option<PublicKey> {
+        self.signature
+            .as_ref()
+            .map(|signature| PublicKey::from(signature.pk_poly().clone()))
+    }
+
+    /// Returns the note file contained in this envelope.
+    pub fn into_file(self) -> NoteFile {
+        self.file
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies that this envelope was signed by the expected sender.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The envelope is not signed.
+    /// - The envelope was not signed with the key of the expected sender, or its contents were
+    ///   modified after it was signed.
+    pub fn verify(&self, expected_sender: &PublicKey) -> Result<(), NoteError> {
+        let signature = self.signature.as_ref().ok_or(NoteError::NoteFileNotSigned)?;
+        if !expected_sender.verify(file_commitment(&self.file), signature) {
+            return Err(NoteError::InvalidNoteFileSignature);
+        }
+
+        Ok(())
+    }
+
+    // TEXT ENCODING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the text encoding of this envelope: the serialized envelope encoded as URL-safe
+    /// base64 and prefixed with `miden-note:`.
+    pub fn to_text(&self) -> String {
+        let mut text = NOTE_FILE_TEXT_PREFIX.to_string();
+        text.push_str(&URL_SAFE_NO_PAD.encode(self.to_bytes()));
+        text
+    }
+
+    /// Returns the envelope decoded from the provided text encoding.
+    ///
+    /// Whitespace in the encoding is ignored, so envelopes which were wrapped across multiple
+    /// lines can be decoded. The signature of the decoded envelope is not verified.
+    ///
+    /// # Errors
+    /// Returns an error if the text is not a valid encoding of an envelope.
+    pub fn from_text(text: &str) -> Result<UnverifiedNoteFileEnvelope, NoteError> {
+        let encoded: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+        let encoded = encoded.strip_prefix(NOTE_FILE_TEXT_PREFIX).ok_or_else(|| {
+            NoteError::InvalidNoteFileText(format!("text must start with {NOTE_FILE_TEXT_PREFIX}"))
+        })?;
+        let bytes = URL_SAFE_NO_PAD
+            .decode(encoded)
+            .map_err(|err| NoteError::InvalidNoteFileText(err.to_string()))?;
+
+        UnverifiedNoteFileEnvelope::read_from_bytes(&bytes)
+            .map_err(NoteError::NoteDeserializationError)
+    }
+}
+
+impl From<NoteFile> for NoteFileEnvelope {
+    fn from(file: NoteFile) -> Self {
+        Self::new(file)
+    }
+}
+
+/// Returns the commitment to the provided note file which is signed by the sender.
+fn file_commitment(file: &NoteFile) -> Word {
+    Hasher::hash(&file.to_bytes()).into()
+}
+
+// UNVERIFIED NOTE FILE ENVELOPE
+// ================================================================================================
+
+/// A [NoteFileEnvelope] received from an untrusted source, whose signature was not verified yet.
+///
+/// The note file can be obtained either by verifying the envelope against the key of the expected
+/// sender via [UnverifiedNoteFileEnvelope::verify()], or explicitly without any verification via
+/// [UnverifiedNoteFileEnvelope::into_unverified_file()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnverifiedNoteFileEnvelope(NoteFileEnvelope);
+
+impl UnverifiedNoteFileEnvelope {
+    /// Returns the public key the envelope claims to be signed with, or `None` if the envelope is
+    /// not signed.
+    ///
+    /// The key is not verified, and thus must not be used to identify the sender.
+    pub fn claimed_sender_key(&self) -> Option<PublicKey> {
+        self.0.sender_key()
+    }
+
+    /// Returns the envelope if it was signed by the expected sender.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The envelope is not signed.
+    /// - The envelope was not signed with the key of the expected sender, or its contents were
+    ///   modified after it was signed.
+    pub fn verify(self, expected_sender: &PublicKey) -> Result<NoteFileEnvelope, NoteError> {
+        self.0.verify(expected_sender)?;
+        Ok(self.0)
+    }
+
+    /// Returns the note file contained in the envelope without verifying its signature.
+    ///
+    /// This should only be used for envelopes which are not expected to be signed, or when the
+    /// source of the envelope is trusted.
+    pub fn into_unverified_file(self) -> NoteFile {
+        self.0.file
+    }
+}
+
+impl Serializable for NoteFileEnvelope {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.file.write_into(target);
+        self.signature.write_into(target);
+    }
+}
+
+impl Deserializable for UnverifiedNoteFileEnvelope {
+    /// Reads an envelope, or a bare [NoteFile] which is read as an unsigned envelope.
+    ///
+    /// The signature of the envelope is not verified.
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let file = NoteFile::read_from(source)?;
+        let signature = if source.has_more_bytes() {
+            Option::<Signature>::read_from(source)?
+        } else {
+            None
+        };
+
+        Ok(Self(NoteFileEnvelope { file, signature }))
+    }
+}
+
 // TESTS
 // ================================================================================================
 
@@ -100,8 +356,11 @@ impl Deserializable for NoteFile {
 mod tests {
     use alloc::vec::Vec;
 
+    use miden_crypto::{dsa::rpo_falcon512::SecretKey, rand::RpoRandomCoin};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
     use vm_core::{
-        utils::{Deserializable, Serializable},
+        utils::{ByteWriter, Deserializable, Serializable},
         Felt,
     };
 
@@ -115,9 +374,11 @@ mod tests {
         },
         assets::{Asset, FungibleAsset},
         notes::{
-            Note, NoteAssets, NoteFile, NoteInclusionProof, NoteInputs, NoteMetadata,
-            NoteRecipient, NoteScript, NoteTag, NoteType,
+            Note, NoteAssets, NoteDetails, NoteFile, NoteFileEnvelope, NoteId, NoteInclusionProof,
+            NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType,
+            UnverifiedNoteFileEnvelope,
         },
+        NoteError,
     };
 
     fn create_example_note() -> Note {
@@ -144,6 +405,14 @@ mod tests {
         Note::new(NoteAssets::new(vec![asset]).unwrap(), metadata, recipient)
     }
 
+    /// A note file with a note ID, serialized before the format was versioned.
+    fn legacy_note_id_file(note_id: NoteId) -> Vec<u8> {
+        let mut buffer = b"note".to_vec();
+        buffer.push(0);
+        note_id.write_into(&mut buffer);
+        buffer
+    }
+
     #[test]
     fn serialized_note_magic() {
         let note = create_example_note();
@@ -213,4 +482,116 @@ mod tests {
             _ => panic!("Invalid note file variant"),
         }
     }
+
+    /// A note file with note details, serialized with the specified version (or before the format
+    /// was versioned), where the number of note inputs was serialized as a `u8`.
+    fn narrow_inputs_note_details_file(details: &NoteDetails, version: Option<u8>) -> Vec<u8> {
+        let mut buffer = b"note".to_vec();
+        if let Some(version) = version {
+            buffer.extend([0xff, version]);
+        }
+        buffer.push(1);
+        details.assets().write_into(&mut buffer);
+        details.script().write_into(&mut buffer);
+        buffer.push(details.inputs().num_values() as u8);
+        buffer.write_many(details.inputs().values());
+        details.serial_num().write_into(&mut buffer);
+        456_u32.write_into(&mut buffer);
+        Some(NoteTag::from(123)).write_into(&mut buffer);
+        buffer
+    }
+
+    #[test]
+    fn read_narrow_inputs_files() {
+        let note = create_example_note();
+        let expected = NoteFile::NoteDetails {
+            details: note.details.clone(),
+            after_block_num: 456,
+            tag: Some(NoteTag::from(123)),
+        };
+
+        for version in [None, Some(1)] {
+            let bytes = narrow_inputs_note_details_file(&note.details, version);
+            assert_eq!(NoteFile::read_from_bytes(&bytes).unwrap(), expected);
+        }
+        let bytes = expected.to_bytes();
+        assert_eq!(bytes[5], 2);
+        assert_eq!(NoteFile::read_from_bytes(&bytes).unwrap(), expected);
+    }
+
+    #[test]
+    fn read_legacy_and_future_files() {
+        let note = create_example_note();
+        let legacy = legacy_note_id_file(note.id());
+        assert_eq!(NoteFile::read_from_bytes(&legacy).unwrap(), NoteFile::NoteId(note.id()));
+        assert!(UnverifiedNoteFileEnvelope::read_from_bytes(&legacy)
+            .unwrap()
+            .claimed_sender_key()
+            .is_none());
+
+        let mut future = NoteFile::NoteId(note.id()).to_bytes();
+        future[5] = NoteFile::VERSION + 1;
+        assert!(NoteFile::read_from_bytes(&future).is_err());
+    }
+
+    #[test]
+    fn signed_envelope() {
+        let mut rng = ChaCha20Rng::from_seed([0; 32]);
+        let sender_key = SecretKey::with_rng(&mut rng);
+        let note = create_example_note();
+        let file = NoteFile::NoteDetails {
+            details: note.details.clone(),
+            after_block_num: 456,
+            tag: None,
+        };
+
+        let mut coin = RpoRandomCoin::new(Default::default());
+        let envelope = NoteFileEnvelope::new_signed(file.clone(), &sender_key, &mut coin);
+        assert_eq!(envelope.sender_key(), Some(sender_key.public_key()));
+        envelope.verify(&sender_key.public_key()).unwrap();
+
+        let text = envelope.to_text();
+        assert!(text.starts_with("miden-note:"));
+        let wrapped = text
+            .as_bytes()
+            .chunks(64)
+            .map(|line| core::str::from_utf8(line).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let decoded = NoteFileEnvelope::from_text(&wrapped).unwrap();
+        assert_eq!(decoded.claimed_sender_key(), Some(sender_key.public_key()));
+        assert_eq!(decoded.verify(&sender_key.public_key()).unwrap(), envelope);
+
+        // tampering with the signed contents is detected
+        let mut tampered = envelope.clone();
+        tampered.file = NoteFile::NoteId(note.id());
+        let decoded = UnverifiedNoteFileEnvelope::read_from_bytes(&tampered.to_bytes()).unwrap();
+        assert_eq!(
+            decoded.verify(&sender_key.public_key()),
+            Err(NoteError::InvalidNoteFileSignature)
+        );
+
+        // an envelope re-signed with another key is rejected, even though its signature is valid
+        let other_key = SecretKey::with_rng(&mut rng);
+        let resigned = NoteFileEnvelope::new_signed(file.clone(), &other_key, &mut coin);
+        resigned.verify(&other_key.public_key()).unwrap();
+        let decoded = UnverifiedNoteFileEnvelope::read_from_bytes(&resigned.to_bytes()).unwrap();
+        assert_eq!(decoded.claimed_sender_key(), Some(other_key.public_key()));
+        assert_eq!(
+            decoded.verify(&sender_key.public_key()),
+            Err(NoteError::InvalidNoteFileSignature)
+        );
+
+        // an envelope whose signature was stripped is rejected
+        let stripped = NoteFileEnvelope::new(file.clone()).to_text();
+        let decoded = NoteFileEnvelope::from_text(&stripped).unwrap();
+        assert_eq!(decoded.claimed_sender_key(), None);
+        assert_eq!(decoded.clone().into_unverified_file(), file);
+        assert_eq!(decoded.verify(&sender_key.public_key()), Err(NoteError::NoteFileNotSigned));
+
+        assert!(matches!(
+            NoteFileEnvelope::from_text("note:abc"),
+            Err(NoteError::InvalidNoteFileText(_))
+        ));
+    }
 }
@@ -4,15 +4,17 @@ use super::{
     ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Hasher, NoteError,
     Serializable, WORD_SIZE, ZERO,
 };
-use crate::MAX_INPUTS_PER_NOTE;
+use crate::{MAX_INPUTS_PER_NOTE, MAX_STREAMED_INPUTS_PER_NOTE};
 
 // NOTE INPUTS
 // ================================================================================================
 
 /// A container for note inputs.
 ///
-/// A note can be associated with up to 128 input values. Each value is represented by a single
-/// field element. Thus, note input values can contain up to ~1 KB of data.
+/// A note can be associated with up to 65535 input values. Each value is represented by a single
+/// field element. Notes with up to 128 input values can load all of their inputs into memory at
+/// once via the `miden::note::get_inputs` procedure, while the inputs of larger notes must be
+/// streamed from the advice provider via the `miden::note::start_inputs_stream` procedure.
 ///
 /// All inputs associated with a note can be reduced to a single commitment which is computed by
 /// first padding the inputs with ZEROs to the next multiple of 8, and then by computing a
@@ -24,17 +26,20 @@ pub struct NoteInputs {
 }
 
 impl NoteInputs {
-    /// Maximum number of input values associated with a single note.
+    /// Maximum number of input values which can be loaded into memory at once.
     const MAX_INPUTS_PER_NOTE: usize = MAX_INPUTS_PER_NOTE;
 
+    /// Maximum number of input values associated with a single note.
+    const MAX_STREAMED_INPUTS_PER_NOTE: usize = MAX_STREAMED_INPUTS_PER_NOTE;
+
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Returns [NoteInputs] instantiated from the provided values.
     ///
     /// # Errors
-    /// Returns an error if the number of provided inputs is greater than 128.
+    /// Returns an error if the number of provided inputs is greater than 65535.
     pub fn new(values: Vec<Felt>) -> Result<Self, NoteError> {
-        if values.len() > Self::MAX_INPUTS_PER_NOTE {
+        if values.len() > Self::MAX_STREAMED_INPUTS_PER_NOTE {
             return Err(NoteError::too_many_inputs(values.len()));
         }
 
@@ -51,13 +56,19 @@ impl NoteInputs {
 
     /// Returns the number of input values.
     ///
-    /// The returned value is guaranteed to be smaller than or equal to 128.
-    pub fn num_values(&self) -> u8 {
+    /// The returned value is guaranteed to be smaller than or equal to 65535.
+    pub fn num_values(&self) -> u16 {
         debug_assert!(
-            self.values.len() < 128,
+            self.values.len() <= Self::MAX_STREAMED_INPUTS_PER_NOTE,
             "The constructor should have checked the number of inputs"
         );
-        self.values.len() as u8
+        self.values.len() as u16
+    }
+
+    /// Returns true if there are too many input values to load them into memory at once, and
+    /// thus they must be streamed from the advice provider.
+    pub fn is_streamed(&self) -> bool {
+        self.values.len() > Self::MAX_INPUTS_PER_NOTE
     }
 
     /// Returns a reference to the input values.
@@ -73,7 +84,8 @@ impl NoteInputs {
     /// - INPUTS is the variable inputs for the note
     /// - PADDING is the optional padding to align the data with a 2WORD boundary
     pub fn format_for_advice(&self) -> Vec<Felt> {
-        // NOTE: keep map in sync with the `note::get_inputs` API procedure
+        // NOTE: keep map in sync with the `note::get_inputs` and `note::start_inputs_stream` API
+        // procedures
         let mut padded = pad_inputs(&self.values);
         padded.insert(0, self.num_values().into());
         padded
@@ -143,14 +155,14 @@ fn pad_and_build(values: Vec<Felt>) -> NoteInputs {
 impl Serializable for NoteInputs {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         let NoteInputs { values, hash: _hash } = self;
-        target.write_u8(values.len().try_into().expect("inputs len is not a u8 value"));
+        target.write_u16(values.len().try_into().expect("inputs len is not a u16 value"));
         target.write_many(values);
     }
 }
 
 impl Deserializable for NoteInputs {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let num_values = source.read_u8()? as usize;
+        let num_values = source.read_u16()? as usize;
         let values = source.read_many::<Felt>(num_values)?;
         Self::new(values).map_err(|v| DeserializationError::InvalidValue(format!("{v}")))
     }
@@ -161,9 +173,11 @@ impl Deserializable for NoteInputs {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
+
     use miden_crypto::utils::Deserializable;
 
-    use super::{Felt, NoteInputs, Serializable};
+    use super::{Felt, Hasher, NoteInputs, Serializable};
 
     #[test]
     fn test_input_ordering() {
@@ -185,4 +199,27 @@ mod tests {
         let parsed_note_inputs = NoteInputs::read_from_bytes(&bytes).unwrap();
         assert_eq!(note_inputs, parsed_note_inputs);
     }
+
+    #[test]
+    fn test_streamed_inputs() {
+        let inputs: Vec<Felt> = (0..300).map(Felt::new).collect();
+        let note_inputs = NoteInputs::new(inputs.clone()).unwrap();
+        assert!(note_inputs.is_streamed());
+        assert_eq!(note_inputs.num_values(), 300);
+
+        // the commitment is the same sequential hash of the padded inputs as for smaller notes
+        let mut padded = inputs.clone();
+        padded.resize(304, Felt::new(0));
+        assert_eq!(note_inputs.commitment(), Hasher::hash_elements(&padded));
+
+        let advice = note_inputs.format_for_advice();
+        assert_eq!(advice[0], Felt::new(300));
+        assert_eq!(&advice[1..], &padded);
+
+        let parsed_note_inputs = NoteInputs::read_from_bytes(&note_inputs.to_bytes()).unwrap();
+        assert_eq!(note_inputs, parsed_note_inputs);
+
+        assert!(!NoteInputs::new(inputs[..128].to_vec()).unwrap().is_streamed());
+        assert!(NoteInputs::new(vec![Felt::new(0); u16::MAX as usize + 1]).is_err());
+    }
 }
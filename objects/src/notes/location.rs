@@ -1,7 +1,8 @@
 use super::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, NoteError, Serializable,
+    compute_note_hash, ByteReader, ByteWriter, Deserializable, DeserializationError, NoteError,
+    NoteId, NoteMetadata, Serializable,
 };
-use crate::{crypto::merkle::MerklePath, MAX_BATCHES_PER_BLOCK, MAX_NOTES_PER_BATCH};
+use crate::{crypto::merkle::MerklePath, BlockHeader, MAX_BATCHES_PER_BLOCK, MAX_NOTES_PER_BATCH};
 
 /// Contains information about the location of a note.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -73,6 +74,47 @@ impl NoteInclusionProof {
     pub fn note_path(&self) -> &MerklePath {
         &self.note_path
     }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies that the note with the provided ID and metadata was created in the provided block.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The provided block is not the block the note was created in according to this proof.
+    /// - The note path is not a valid path for the note's index in the block note tree.
+    /// - The root computed from the note path does not match the note root of the block.
+    pub fn verify(
+        &self,
+        note_id: NoteId,
+        metadata: &NoteMetadata,
+        block_header: &BlockHeader,
+    ) -> Result<(), NoteError> {
+        if self.location.block_num != block_header.block_num() {
+            return Err(NoteError::InclusionProofBlockMismatch {
+                proof_block_num: self.location.block_num,
+                block_num: block_header.block_num(),
+            });
+        }
+
+        let note_root = self
+            .note_path
+            .compute_root(
+                self.location.node_index_in_block.into(),
+                compute_note_hash(note_id, metadata),
+            )
+            .map_err(NoteError::InclusionProofInvalidPath)?;
+        if note_root != block_header.note_root() {
+            return Err(NoteError::InclusionProofNoteRootMismatch {
+                note_id,
+                expected: block_header.note_root(),
+                actual: note_root,
+            });
+        }
+
+        Ok(())
+    }
 }
 
 // SERIALIZATION
@@ -26,6 +26,11 @@ pub use inputs::NoteInputs;
 mod metadata;
 pub use metadata::NoteMetadata;
 
+#[cfg(feature = "note-encryption")]
+mod encrypted;
+#[cfg(feature = "note-encryption")]
+pub use encrypted::{EncryptedNote, NoteDecryptionKey, NoteEncryptionKey};
+
 mod execution_hint;
 pub use execution_hint::NoteExecutionHint;
 
@@ -53,8 +58,13 @@ pub use recipient::NoteRecipient;
 mod script;
 pub use script::NoteScript;
 
+#[cfg(feature = "note-encryption")]
+mod stealth;
+#[cfg(feature = "note-encryption")]
+pub use stealth::{OneTimeRecipient, StealthAddress};
+
 mod file;
-pub use file::NoteFile;
+pub use file::{NoteFile, NoteFileEnvelope, UnverifiedNoteFileEnvelope};
 
 // CONSTANTS
 // ================================================================================================
@@ -21,7 +21,8 @@ pub enum NoteType {
     /// Notes with this type have only their hash published to the network.
     Private = PRIVATE,
 
-    /// Notes with type are shared with the network encrypted.
+    /// Notes with this type are shared with the network encrypted, e.g., as an `EncryptedNote`
+    /// (available with the `note-encryption` feature).
     Encrypted = ENCRYPTED,
 
     /// Notes with this type are fully shared with the network.
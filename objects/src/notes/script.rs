@@ -1,7 +1,7 @@
 use alloc::{string::ToString, sync::Arc, vec::Vec};
 use core::fmt::Display;
 
-use assembly::{Assembler, Compile};
+use assembly::{Assembler, Compile, Library};
 use vm_core::{
     mast::{MastForest, MastNodeId},
     prettier::PrettyPrint,
@@ -51,6 +51,29 @@ impl NoteScript {
         Ok(Self::new(program))
     }
 
+    /// Returns a new [NoteScript] compiled from the provided source code using the specified
+    /// assembler linked against the provided libraries.
+    ///
+    /// Procedures of the libraries invoked by the script are not copied into the script's MAST,
+    /// and thus the libraries must be available to the transaction executor consuming the note.
+    ///
+    /// # Errors
+    /// Returns an error if linking any of the libraries or the compilation of the provided source
+    /// code fails.
+    pub fn compile_with_libraries<'a>(
+        source_code: impl Compile,
+        mut assembler: Assembler,
+        libraries: impl IntoIterator<Item = &'a Library>,
+    ) -> Result<Self, NoteError> {
+        for library in libraries {
+            assembler
+                .add_library(library)
+                .map_err(|report| NoteError::NoteScriptAssemblyError(report.to_string()))?;
+        }
+
+        Self::compile(source_code, assembler)
+    }
+
     /// Returns a new [NoteScript] deserialized from the provided bytes.
     ///
     /// # Errors
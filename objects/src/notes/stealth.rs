@@ -0,0 +1,314 @@
+use x25519_dalek::{PublicKey, SharedSecret, StaticSecret};
+
+use super::{
+    AccountId, ByteReader, ByteWriter, Deserializable, DeserializationError, NoteDecryptionKey,
+    NoteEncryptionKey, NoteError, Serializable,
+};
+use crate::{Digest, Felt, Hasher, Word, ZERO};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Context string for deriving the blinding factors of one-time recipients from X25519 shared
+/// secrets.
+const BLINDING_DERIVATION_CONTEXT: &str = "miden-base 2024 stealth recipient v1";
+
+/// Context string for deriving the view tags of one-time recipients from X25519 shared secrets.
+const VIEW_TAG_DERIVATION_CONTEXT: &str = "miden-base 2024 stealth view tag v1";
+
+// STEALTH ADDRESS
+// ================================================================================================
+
+/// The published address of an account which can receive notes without being linked to them.
+///
+/// A note sent to a [StealthAddress] commits to a [OneTimeRecipient] instead of the ID of the
+/// account. Each one-time recipient is derived from the viewing key of the address and a fresh
+/// ephemeral key, so notes sent to the same address cannot be linked to each other or to the
+/// account by anyone who does not hold the corresponding [NoteDecryptionKey].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StealthAddress {
+    account_id: AccountId,
+    viewing_key: NoteEncryptionKey,
+}
+
+impl StealthAddress {
+    /// Returns a new [StealthAddress] of the specified account with the provided viewing key.
+    pub fn new(account_id: AccountId, viewing_key: NoteEncryptionKey) -> Self {
+        Self { account_id, viewing_key }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the account this address belongs to.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the viewing key of this address.
+    pub fn viewing_key(&self) -> &NoteEncryptionKey {
+        &self.viewing_key
+    }
+
+    // RECIPIENT DERIVATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Derives a new [OneTimeRecipient] of this address from the provided ephemeral secret.
+    ///
+    /// The ephemeral secret must be sampled at random for every derived recipient, as reusing it
+    /// results in the same recipient commitment.
+    ///
+    /// # Errors
+    /// Returns an error if the viewing key is a low-order point, i.e., it would not contribute to
+    /// the shared secret.
+    pub fn derive_recipient(
+        &self,
+        ephemeral_secret: [u8; 32],
+    ) -> Result<OneTimeRecipient, NoteError> {
+        let ephemeral_secret = StaticSecret::from(ephemeral_secret);
+        let ephemeral_key = PublicKey::from(&ephemeral_secret).to_bytes();
+        let viewing_key = PublicKey::from(self.viewing_key.to_bytes());
+        let shared_secret = diffie_hellman(&ephemeral_secret, &viewing_key)?;
+
+        Ok(OneTimeRecipient::new(self.account_id, ephemeral_key, &shared_secret))
+    }
+}
+
+// ONE-TIME RECIPIENT
+// ================================================================================================
+
+/// A one-time commitment to the recipient account of a note sent to a [StealthAddress].
+///
+/// The commitment is computed as `hash([account_id, 0, 0, 0], BLINDING)`, where the blinding
+/// factor is derived from the X25519 shared secret of the ephemeral key of the sender and the
+/// viewing key of the recipient. The ephemeral key is published alongside the commitment, which
+/// allows the recipient to recover the blinding factor and prove that the commitment opens to its
+/// account ID.
+///
+/// A one-byte view tag is derived from the same shared secret. Publishing it alongside the
+/// ephemeral key allows the recipient to discard most of the notes which were not sent to it after
+/// computing only the shared secret (see [OneTimeRecipient::recover_view_tag()]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OneTimeRecipient {
+    commitment: Digest,
+    ephemeral_key: [u8; 32],
+    blinding: Word,
+    view_tag: u8,
+}
+
+impl OneTimeRecipient {
+    /// Returns a new [OneTimeRecipient] of the specified account derived from the provided shared
+    /// secret.
+    fn new(account_id: AccountId, ephemeral_key: [u8; 32], shared_secret: &SharedSecret) -> Self {
+        let blinding = derive_blinding(shared_secret, ephemeral_key);
+        Self {
+            commitment: compute_commitment(account_id, blinding),
+            ephemeral_key,
+            blinding,
+            view_tag: derive_view_tag(shared_secret),
+        }
+    }
+
+    /// Recovers the [OneTimeRecipient] derived for the specified account from the provided
+    /// ephemeral key, using the secret counterpart of the viewing key of the account's address.
+    ///
+    /// The returned recipient matches a published commitment only if the commitment was derived
+    /// for the address of the account.
+    ///
+    /// # Errors
+    /// Returns an error if the ephemeral key is a low-order point, i.e., it would not contribute to
+    /// the shared secret.
+    pub fn recover(
+        account_id: AccountId,
+        viewing_key: &NoteDecryptionKey,
+        ephemeral_key: [u8; 32],
+    ) -> Result<Self, NoteError> {
+        let viewing_secret = StaticSecret::from(viewing_key.to_bytes());
+        let shared_secret = diffie_hellman(&viewing_secret, &PublicKey::from(ephemeral_key))?;
+
+        Ok(Self::new(account_id, ephemeral_key, &shared_secret))
+    }
+
+    /// Returns the view tag of the one-time recipient derived from the provided ephemeral key for
+    /// the address with the provided viewing key.
+    ///
+    /// This is cheaper than [OneTimeRecipient::recover()], and a view tag which does not match the
+    /// published one shows that the recipient was not derived for the address.
+    ///
+    /// # Errors
+    /// Returns an error if the ephemeral key is a low-order point, i.e., it would not contribute to
+    /// the shared secret.
+    pub fn recover_view_tag(
+        viewing_key: &NoteDecryptionKey,
+        ephemeral_key: [u8; 32],
+    ) -> Result<u8, NoteError> {
+        let viewing_secret = StaticSecret::from(viewing_key.to_bytes());
+        let shared_secret = diffie_hellman(&viewing_secret, &PublicKey::from(ephemeral_key))?;
+
+        Ok(derive_view_tag(&shared_secret))
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the commitment to the recipient account.
+    pub fn commitment(&self) -> Digest {
+        self.commitment
+    }
+
+    /// Returns the ephemeral key from which the blinding factor of the commitment is derived.
+    pub fn ephemeral_key(&self) -> [u8; 32] {
+        self.ephemeral_key
+    }
+
+    /// Returns the blinding factor of the commitment.
+    ///
+    /// The blinding factor opens the commitment to the recipient account ID and must only be
+    /// revealed when the note is consumed.
+    pub fn blinding(&self) -> Word {
+        self.blinding
+    }
+
+    /// Returns the view tag of this recipient.
+    pub fn view_tag(&self) -> u8 {
+        self.view_tag
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the commitment of the specified account with the provided blinding factor.
+fn compute_commitment(account_id: AccountId, blinding: Word) -> Digest {
+    let account_id = Digest::from([account_id.into(), ZERO, ZERO, ZERO]);
+    Hasher::merge(&[account_id, blinding.into()])
+}
+
+/// Returns the X25519 shared secret of the provided keys.
+///
+/// # Errors
+/// Returns an error if the public key is a low-order point.
+fn diffie_hellman(
+    secret: &StaticSecret,
+    public_key: &PublicKey,
+) -> Result<SharedSecret, NoteError> {
+    let shared_secret = secret.diffie_hellman(public_key);
+    if !shared_secret.was_contributory() {
+        return Err(NoteError::NonContributoryEncryptionKey);
+    }
+
+    Ok(shared_secret)
+}
+
+/// Returns the blinding factor derived from the provided shared secret.
+///
+/// The blinding factor is derived from the shared secret and the ephemeral public key of the
+/// sender, binding it to the exchange.
+fn derive_blinding(shared_secret: &SharedSecret, ephemeral_key: [u8; 32]) -> Word {
+    let mut hasher = blake3::Hasher::new_derive_key(BLINDING_DERIVATION_CONTEXT);
+    hasher.update(shared_secret.as_bytes());
+    hasher.update(&ephemeral_key);
+    let bytes: [u8; 32] = hasher.finalize().into();
+
+    core::array::from_fn(|i| {
+        let limb: [u8; 8] = bytes[i * 8..(i + 1) * 8].try_into().expect("slice has 8 bytes");
+        Felt::new(u64::from_le_bytes(limb))
+    })
+}
+
+/// Returns the view tag derived from the provided shared secret.
+fn derive_view_tag(shared_secret: &SharedSecret) -> u8 {
+    let mut hasher = blake3::Hasher::new_derive_key(VIEW_TAG_DERIVATION_CONTEXT);
+    hasher.update(shared_secret.as_bytes());
+
+    hasher.finalize().as_bytes()[0]
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for StealthAddress {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_id.write_into(target);
+        self.viewing_key.write_into(target);
+    }
+}
+
+impl Deserializable for StealthAddress {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account_id = AccountId::read_from(source)?;
+        let viewing_key = NoteEncryptionKey::read_from(source)?;
+
+        Ok(Self::new(account_id, viewing_key))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+    use vm_core::utils::{Deserializable, Serializable};
+
+    use super::{OneTimeRecipient, StealthAddress};
+    use crate::{
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+                ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+            },
+            AccountId,
+        },
+        notes::NoteDecryptionKey,
+        NoteError,
+    };
+
+    #[test]
+    fn test_one_time_recipient() {
+        let mut rng = ChaCha20Rng::from_seed([0; 32]);
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let other_account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let viewing_key = NoteDecryptionKey::new(&mut rng);
+        let address = StealthAddress::new(account_id, viewing_key.encryption_key());
+        assert_eq!(StealthAddress::read_from_bytes(&address.to_bytes()).unwrap(), address);
+
+        // the recipient recovers the one-time recipient derived by the sender
+        let recipient = address.derive_recipient([1; 32]).unwrap();
+        let recovered =
+            OneTimeRecipient::recover(account_id, &viewing_key, recipient.ephemeral_key()).unwrap();
+        assert_eq!(recovered, recipient);
+        assert_eq!(
+            OneTimeRecipient::recover_view_tag(&viewing_key, recipient.ephemeral_key()).unwrap(),
+            recipient.view_tag()
+        );
+
+        // recipients derived from different ephemeral secrets are unlinkable
+        let other_recipient = address.derive_recipient([2; 32]).unwrap();
+        assert_ne!(other_recipient.commitment(), recipient.commitment());
+        assert_ne!(other_recipient.ephemeral_key(), recipient.ephemeral_key());
+
+        // the commitment does not open to a different account or with a different viewing key
+        let recovered =
+            OneTimeRecipient::recover(other_account_id, &viewing_key, recipient.ephemeral_key())
+                .unwrap();
+        assert_ne!(recovered.commitment(), recipient.commitment());
+        let other_viewing_key = NoteDecryptionKey::new(&mut rng);
+        let recovered =
+            OneTimeRecipient::recover(account_id, &other_viewing_key, recipient.ephemeral_key())
+                .unwrap();
+        assert_ne!(recovered.commitment(), recipient.commitment());
+        assert_ne!(
+            OneTimeRecipient::recover_view_tag(&other_viewing_key, recipient.ephemeral_key())
+                .unwrap(),
+            recipient.view_tag()
+        );
+
+        // low-order keys are rejected
+        let address = StealthAddress::new(account_id, [0; 32].into());
+        assert_eq!(address.derive_recipient([1; 32]), Err(NoteError::NonContributoryEncryptionKey));
+    }
+}
@@ -7,16 +7,16 @@ use crate::accounts::AccountCode;
 const MASTS: [&str; 12] = [
     "0xff06b90f849c4b262cbfbea67042c4ea017ea0e9c558848a951d44b23370bec5",
     "0x8ef0092134469a1330e3c468f57c7f085ce611645d09cc7516c786fefc71d794",
-    "0x86472c62c0f9d2f93369f793abd66127f9cf4a77d4339dcacfb118aba0cf79b6",
-    "0xa5e47b6219605992b497ab85404425da4b88ad58789d86ab09bea9ed0ec12897",
-    "0x56723c7bd5e46ce33f99f256ae1b8f4856600744191f8a18d1c572a925f41ced",
-    "0x6be2be94390361792f5becf18f3e916fa2458c67b809ad144d8ec5fb144ce9c3",
-    "0x0f0447bc4eb9a366d8158274427445fcc169949e4ab9092d45ff55c2a7753e2a",
-    "0x3d77d6c0727fa8c78695123bcd9413e88a5d92e72a60453557fb93dfa575c81a",
-    "0x383067a3ef06a0fad1f11ab7707c67c286db851cc9edece3ea53a76520a014fa",
-    "0x59e26d0f909ce76298f12ba6a6a4120b0cf541622128756eb572fd17dbe8732d",
-    "0xe55e8abaa5a3a8ff89537111b490f22983a7012e65c11ead8478f7a645ba49bd",
-    "0xad0d0d771f4a301c658c61366b4436a4b45b7e317d0f3ae2c76f37e1f8bd63e6",
+    "0x28679b9c63743d635a85cf328c2d2e30f0102171369d33526724f4def262742b",
+    "0x5bfbfddceffcbf8bc54843bec52a460754ce2ab77c810abadb5106b12b21e90b",
+    "0x037132315f5415866b3842808f0aaa161acd82fe997f4a8e2ae90c3b418aafe6",
+    "0x1afa736bb3b9a0c126829e2b48e70f798f6c72e1f33d509ed6521a46b5db11f8",
+    "0x238a311e833b6bf22e1e08c6f485860993f54df90609db532bc8ad8a13525fbe",
+    "0xaa4cae873d99329c60cc2d6dd6f3fa658cde6892780b3ab672058876f04a81b6",
+    "0xc6f3fc3268e94e67afd2acf5a251578489478490986c6fcc735338de1118006f",
+    "0x7e06f380a777173d6b0c21082f6af9a4d93789e99a2982219d3cc2134a067cb2",
+    "0x30ad95a1240a011563870900239eff31e18f292b9f39eb582c83d22196d1520d",
+    "0x4b6e5b8855b00b307beaf61054350057525111a59f1567a3116abaf6c6ec3ea9",
 ];
 
 pub const ACCOUNT_ADD_ASSET_TO_NOTE_MAST_ROOT: &str = MASTS[2];
@@ -46,6 +46,7 @@ pub const CODE: &str = "
 pub const DEFAULT_ACCOUNT_CODE: &str = "
     export.::miden::contracts::wallets::basic::receive_asset
     export.::miden::contracts::wallets::basic::send_asset
+    export.::miden::contracts::auth::basic::rotate_key_rpo_falcon512
     export.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
 ";
 
@@ -1,12 +1,9 @@
-use alloc::vec::Vec;
-
-use miden_crypto::merkle::SimpleSmt;
 use vm_core::Felt;
 use vm_processor::Digest;
 #[cfg(not(target_family = "wasm"))]
 use winter_rand_utils::{rand_array, rand_value};
 
-use crate::{accounts::Account, BlockHeader, ACCOUNT_TREE_DEPTH};
+use crate::{accounts::Account, block::AccountTree, BlockHeader};
 
 impl BlockHeader {
     /// Creates a mock block. The account tree is formed from the provided `accounts`,
@@ -21,18 +18,11 @@ impl BlockHeader {
         note_root: Option<Digest>,
         accounts: &[Account],
     ) -> Self {
-        let acct_db = SimpleSmt::<ACCOUNT_TREE_DEPTH>::with_leaves(
+        let acct_db = AccountTree::with_entries(
             accounts
                 .iter()
-                .flat_map(|acct| {
-                    if acct.is_new() {
-                        None
-                    } else {
-                        let felt_id: Felt = acct.id().into();
-                        Some((felt_id.as_int(), *acct.hash()))
-                    }
-                })
-                .collect::<Vec<_>>(),
+                .filter(|acct| !acct.is_new())
+                .map(|acct| (acct.id(), acct.hash())),
         )
         .expect("failed to create account db");
         let acct_root = acct_db.root();
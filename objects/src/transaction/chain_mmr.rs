@@ -1,8 +1,10 @@
 use alloc::{collections::BTreeMap, vec::Vec};
 
 use crate::{
-    crypto::merkle::{InnerNodeInfo, MmrPeaks, PartialMmr},
-    BlockHeader, ChainMmrError,
+    crypto::merkle::{InnerNodeInfo, MerklePath, Mmr, MmrDelta, MmrPeaks, MmrProof, PartialMmr},
+    notes::{NoteId, NoteInclusionProof, NoteMetadata},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    BlockHeader, ChainMmrError, Digest,
 };
 
 // CHAIN MMR
@@ -84,6 +86,54 @@ impl ChainMmr {
         self.blocks.get(&block_num)
     }
 
+    /// Returns an iterator over the headers of all blocks tracked by this chain MMR.
+    pub fn blocks(&self) -> impl Iterator<Item = &BlockHeader> {
+        self.blocks.values()
+    }
+
+    /// Returns the authentication path of the specified block against the peaks of this MMR, or
+    /// None if the block is not present in this chain MMR.
+    pub fn open(&self, block_num: u32) -> Option<MmrProof> {
+        if !self.contains_block(block_num) {
+            return None;
+        }
+
+        self.mmr.open(block_num as usize).expect("tracked block must be in the chain")
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies that the note with the provided ID and metadata was created in one of the blocks
+    /// of the chain described by this MMR.
+    ///
+    /// The header of the block the note was created in is authenticated against the peaks of this
+    /// MMR, and the note is authenticated against the note root of that block.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The block the note was created in is not tracked by this chain MMR.
+    /// - The tracked header of that block does not match the corresponding leaf of the MMR.
+    /// - The note inclusion proof is invalid for the block header.
+    pub fn verify_note(
+        &self,
+        note_id: NoteId,
+        metadata: &NoteMetadata,
+        proof: &NoteInclusionProof,
+    ) -> Result<(), ChainMmrError> {
+        let block_num = proof.location().block_num();
+        let block_header =
+            self.get_block(block_num).ok_or(ChainMmrError::untracked_block(block_num))?;
+        let block_proof = self.open(block_num).ok_or(ChainMmrError::untracked_block(block_num))?;
+        if !self.peaks().verify(block_header.hash(), block_proof) {
+            return Err(ChainMmrError::invalid_block_path(block_num));
+        }
+
+        proof
+            .verify(note_id, metadata, block_header)
+            .map_err(ChainMmrError::InvalidNoteInclusionProof)
+    }
+
     // DATA MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -91,7 +141,8 @@ impl ChainMmr {
     /// block header is for the next block in the chain.
     ///
     /// If `track` parameter is set to true, the authentication path for the provided block header
-    /// will be added to this chain MMR.
+    /// will be added to this chain MMR, together with the header itself. The header is required to
+    /// keep the block tracked when the chain MMR is rebuilt in [ChainMmr::prune_blocks()].
     ///
     /// # Panics
     /// Panics if the `block_header.block_num` is not equal to the current chain length (i.e., the
@@ -99,6 +150,111 @@ impl ChainMmr {
     pub fn add_block(&mut self, block_header: BlockHeader, track: bool) {
         assert_eq!(block_header.block_num(), self.chain_length() as u32);
         self.mmr.add(block_header.hash(), track);
+        if track {
+            self.blocks.insert(block_header.block_num(), block_header);
+        }
+    }
+
+    /// Appends the provided block headers to this chain MMR, tracking the authentication paths of
+    /// the blocks for which `track` returns true.
+    ///
+    /// The block headers are validated before any of them is appended, so this chain MMR is left
+    /// unchanged if an error is returned.
+    ///
+    /// # Errors
+    /// Returns an error if the provided block headers are not for consecutive blocks starting at
+    /// the current chain length.
+    pub fn add_blocks(
+        &mut self,
+        block_headers: impl IntoIterator<Item = BlockHeader>,
+        mut track: impl FnMut(&BlockHeader) -> bool,
+    ) -> Result<(), ChainMmrError> {
+        let block_headers: Vec<BlockHeader> = block_headers.into_iter().collect();
+        for (chain_length, block_header) in (self.chain_length()..).zip(block_headers.iter()) {
+            if block_header.block_num() as usize != chain_length {
+                return Err(ChainMmrError::unexpected_block(
+                    chain_length,
+                    block_header.block_num(),
+                ));
+            }
+        }
+
+        for block_header in block_headers {
+            let track = track(&block_header);
+            self.add_block(block_header, track);
+        }
+
+        Ok(())
+    }
+
+    /// Stops tracking the authentication paths of all blocks for which `retain` returns false,
+    /// removing their headers from this chain MMR.
+    pub fn prune_blocks(&mut self, mut retain: impl FnMut(&BlockHeader) -> bool) {
+        let num_blocks = self.blocks.len();
+        self.blocks.retain(|_, block_header| retain(block_header));
+        if self.blocks.len() == num_blocks {
+            return;
+        }
+
+        // rebuild the partial MMR from the peaks, as untracking leaves in place may remove nodes
+        // which are still needed to authenticate the retained blocks
+        let mut mmr = PartialMmr::from_peaks(self.mmr.peaks());
+        for (block_num, block_header) in self.blocks.iter() {
+            let proof = self
+                .mmr
+                .open(*block_num as usize)
+                .expect("tracked block must be in the chain")
+                .expect("tracked block must have an authentication path");
+            mmr.track(*block_num as usize, block_header.hash(), &proof.merkle_path)
+                .expect("authentication path must be valid for the current peaks");
+        }
+
+        self.mmr = mmr;
+    }
+
+    /// Applies the provided delta to this chain MMR, advancing it to the chain length of the
+    /// delta and tracking the blocks included in the delta.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The delta does not start at the current chain length of this chain MMR.
+    /// - The delta is inconsistent with the current peaks of this chain MMR.
+    /// - The authentication path of any of the blocks in the delta is invalid, including paths
+    ///   whose depth does not match the height of the MMR tree containing the block.
+    pub fn apply_delta(&mut self, delta: ChainMmrDelta) -> Result<(), ChainMmrError> {
+        if delta.from_length != self.chain_length() {
+            return Err(ChainMmrError::delta_length_mismatch(
+                self.chain_length(),
+                delta.from_length,
+            ));
+        }
+
+        let mut mmr = self.mmr.clone();
+        mmr.apply(MmrDelta {
+            forest: delta.to_length,
+            data: delta.data,
+        })
+        .map_err(ChainMmrError::InvalidMmrDelta)?;
+
+        for (block_header, path) in delta.blocks.iter() {
+            let block_num = block_header.block_num();
+            if block_num as usize >= delta.to_length {
+                return Err(ChainMmrError::block_num_too_big(delta.to_length, block_num));
+            }
+            // the paths are not trusted, and the partial MMR expects a path to the peak of the
+            // tree containing the block
+            if mmr_tree_height(delta.to_length, block_num as usize) != Some(path.depth() as u32) {
+                return Err(ChainMmrError::invalid_block_path(block_num));
+            }
+            mmr.track(block_num as usize, block_header.hash(), path)
+                .map_err(|_| ChainMmrError::invalid_block_path(block_num))?;
+        }
+
+        self.mmr = mmr;
+        self.blocks
+            .extend(delta.blocks.into_iter().map(|(header, _)| (header.block_num(), header)));
+
+        Ok(())
     }
 
     // ITERATORS
@@ -113,16 +269,164 @@ impl ChainMmr {
     }
 }
 
+// CHAIN MMR DELTA
+// ================================================================================================
+
+/// The data needed to advance a [ChainMmr] from one chain length to another.
+///
+/// A delta is produced from the full chain MMR and contains the MMR update data along with the
+/// headers and authentication paths of a set of blocks which the recipient wants to track (e.g.,
+/// the blocks in which the recipient's notes were created).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainMmrDelta {
+    /// The chain length to which this delta can be applied.
+    from_length: usize,
+    /// The chain length after this delta has been applied.
+    to_length: usize,
+    /// MMR update data as produced by [Mmr::get_delta()].
+    data: Vec<Digest>,
+    /// Headers of the blocks to track, with their authentication paths against the MMR at
+    /// `to_length`.
+    blocks: Vec<(BlockHeader, MerklePath)>,
+}
+
+impl ChainMmrDelta {
+    /// Returns a new [ChainMmrDelta] advancing a chain MMR of length `from_length` to `to_length`
+    /// and tracking the provided blocks.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `from_length` is greater than `to_length`, or `to_length` is greater than the length of
+    ///   the provided MMR.
+    /// - block_num for any of the blocks is greater than or equal to `to_length`.
+    /// - The hash of any of the blocks does not match the corresponding leaf of the MMR.
+    pub fn from_mmr(
+        mmr: &Mmr,
+        from_length: usize,
+        to_length: usize,
+        blocks: impl IntoIterator<Item = BlockHeader>,
+    ) -> Result<Self, ChainMmrError> {
+        let delta =
+            mmr.get_delta(from_length, to_length).map_err(ChainMmrError::InvalidMmrDelta)?;
+
+        let mut tracked_blocks = Vec::new();
+        for block_header in blocks {
+            let block_num = block_header.block_num();
+            if block_num as usize >= to_length {
+                return Err(ChainMmrError::block_num_too_big(to_length, block_num));
+            }
+            if mmr.get(block_num as usize) != Ok(block_header.hash()) {
+                return Err(ChainMmrError::invalid_block_path(block_num));
+            }
+
+            let proof = mmr
+                .open(block_num as usize, to_length)
+                .map_err(ChainMmrError::InvalidMmrDelta)?;
+            tracked_blocks.push((block_header, proof.merkle_path));
+        }
+
+        Ok(Self {
+            from_length,
+            to_length,
+            data: delta.data,
+            blocks: tracked_blocks,
+        })
+    }
+
+    /// Returns the chain length to which this delta can be applied.
+    pub fn from_length(&self) -> usize {
+        self.from_length
+    }
+
+    /// Returns the chain length after this delta has been applied.
+    pub fn to_length(&self) -> usize {
+        self.to_length
+    }
+
+    /// Returns an iterator over the headers of the blocks tracked by this delta.
+    pub fn blocks(&self) -> impl Iterator<Item = &BlockHeader> {
+        self.blocks.iter().map(|(block_header, _)| block_header)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ChainMmrDelta {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u64(self.from_length as u64);
+        target.write_u64(self.to_length as u64);
+        self.data.write_into(target);
+        target.write_usize(self.blocks.len());
+        for (block_header, path) in self.blocks.iter() {
+            block_header.write_into(target);
+            path.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for ChainMmrDelta {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let from_length = source.read_u64()? as usize;
+        let to_length = source.read_u64()? as usize;
+        if from_length > to_length {
+            return Err(DeserializationError::InvalidValue(format!(
+                "chain MMR delta from length {from_length} is greater than to length {to_length}"
+            )));
+        }
+        // the numbers of digests and blocks are not trusted, so the vectors are not preallocated
+        let num_digests = source.read_usize()?;
+        let mut data = Vec::new();
+        for _ in 0..num_digests {
+            data.push(Digest::read_from(source)?);
+        }
+        let num_blocks = source.read_usize()?;
+        let mut blocks = Vec::new();
+        for _ in 0..num_blocks {
+            let block_header = BlockHeader::read_from(source)?;
+            let path = MerklePath::read_from(source)?;
+            blocks.push((block_header, path));
+        }
+
+        Ok(Self { from_length, to_length, data, blocks })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the height of the tree containing the leaf at the specified position in an MMR with
+/// the specified forest, or `None` if the MMR does not contain the leaf.
+fn mmr_tree_height(forest: usize, leaf_pos: usize) -> Option<u32> {
+    let mut tree_offset = 0;
+    for height in (0..usize::BITS).rev() {
+        let tree_size = 1 << height;
+        if forest & tree_size == 0 {
+            continue;
+        }
+        if leaf_pos < tree_offset + tree_size {
+            return Some(height);
+        }
+        tree_offset += tree_size;
+    }
+
+    None
+}
+
 // TESTS
 // ================================================================================================
 
 #[cfg(test)]
 mod tests {
-    use super::ChainMmr;
+    use super::{ChainMmr, ChainMmrDelta};
     use crate::{
+        accounts::{account_id::testing::ACCOUNT_ID_SENDER, AccountId},
         alloc::vec::Vec,
-        crypto::merkle::{Mmr, PartialMmr},
-        BlockHeader, Digest,
+        block::{BlockNoteIndex, BlockNoteTree},
+        crypto::merkle::{MerklePath, Mmr, PartialMmr},
+        notes::{NoteExecutionHint, NoteId, NoteInclusionProof, NoteMetadata, NoteTag, NoteType},
+        utils::serde::{ByteWriter, Deserializable, Serializable},
+        BlockHeader, ChainMmrError, Digest, Felt, NoteError, ONE, ZERO,
     };
 
     #[test]
@@ -170,6 +474,191 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_chain_mmr_delta_untrusted_length() {
+        // a delta claiming more blocks than it contains is rejected without allocating for them
+        let mut bytes = Vec::new();
+        bytes.write_u64(3);
+        bytes.write_u64(11);
+        Vec::<Digest>::new().write_into(&mut bytes);
+        bytes.write_usize(usize::MAX);
+
+        assert!(ChainMmrDelta::read_from_bytes(&bytes).is_err());
+
+        // the same holds for the MMR update data
+        let mut bytes = Vec::new();
+        bytes.write_u64(3);
+        bytes.write_u64(11);
+        bytes.write_usize(usize::MAX);
+        bytes.write_usize(0);
+
+        assert!(ChainMmrDelta::read_from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_chain_mmr_delta_invalid_path_depth() {
+        let mut mmr = Mmr::default();
+        for i in 0..11 {
+            mmr.add(int_to_block_header(i).hash());
+        }
+        let partial_mmr: PartialMmr = mmr.peaks(3).unwrap().into();
+        let mut chain_mmr = ChainMmr::new(partial_mmr, Vec::new()).unwrap();
+
+        // block 1 is in the tree of height 3 of the MMR with 11 blocks, a shorter path is rejected
+        let mut delta = ChainMmrDelta::from_mmr(&mmr, 3, 11, [int_to_block_header(1)]).unwrap();
+        let path = &mut delta.blocks[0].1;
+        *path = MerklePath::new(path.iter().take(1).copied().collect());
+
+        assert_eq!(
+            chain_mmr.apply_delta(delta),
+            Err(ChainMmrError::InvalidBlockPath { block_num: 1 })
+        );
+        assert_eq!(chain_mmr.chain_length(), 3);
+    }
+
+    #[test]
+    fn test_chain_mmr_sync() {
+        let mut mmr = Mmr::default();
+        for i in 0..3 {
+            mmr.add(int_to_block_header(i).hash());
+        }
+        let partial_mmr: PartialMmr = mmr.peaks(mmr.forest()).unwrap().into();
+        let mut chain_mmr = ChainMmr::new(partial_mmr, Vec::new()).unwrap();
+
+        // the node advances the chain to 11 blocks, the client wants to track blocks 1 and 7
+        for i in 3..11 {
+            mmr.add(int_to_block_header(i).hash());
+        }
+        let delta =
+            ChainMmrDelta::from_mmr(&mmr, 3, 11, [int_to_block_header(1), int_to_block_header(7)])
+                .unwrap();
+        let delta = ChainMmrDelta::read_from_bytes(&delta.to_bytes()).unwrap();
+        chain_mmr.apply_delta(delta.clone()).unwrap();
+
+        assert_eq!(chain_mmr.chain_length(), 11);
+        assert_eq!(chain_mmr.peaks(), mmr.peaks(11).unwrap());
+        for block_num in [1, 7] {
+            assert_eq!(
+                chain_mmr.open(block_num).unwrap(),
+                mmr.open(block_num as usize, 11).unwrap()
+            );
+        }
+
+        // the same delta cannot be applied twice
+        assert_eq!(
+            chain_mmr.apply_delta(delta),
+            Err(ChainMmrError::DeltaLengthMismatch { chain_length: 11, from_length: 3 })
+        );
+
+        // new blocks can be added incrementally and untracked blocks can be pruned
+        let new_blocks = (11..14).map(int_to_block_header);
+        for block_header in new_blocks.clone() {
+            mmr.add(block_header.hash());
+        }
+        chain_mmr
+            .add_blocks(new_blocks, |block_header| block_header.block_num() == 12)
+            .unwrap();
+        chain_mmr.prune_blocks(|block_header| block_header.block_num() != 7);
+
+        assert!(!chain_mmr.contains_block(7));
+        assert!(chain_mmr.open(7).is_none());
+        assert_eq!(chain_mmr.peaks(), mmr.peaks(14).unwrap());
+        for block_num in [1, 12] {
+            assert_eq!(
+                chain_mmr.open(block_num).unwrap(),
+                mmr.open(block_num as usize, 14).unwrap()
+            );
+        }
+        assert_eq!(
+            chain_mmr.add_blocks([int_to_block_header(15)], |_| true),
+            Err(ChainMmrError::UnexpectedBlock { chain_length: 14, block_num: 15 })
+        );
+
+        // no block is added if any of the blocks is unexpected
+        assert_eq!(
+            chain_mmr.add_blocks([int_to_block_header(14), int_to_block_header(16)], |_| true),
+            Err(ChainMmrError::UnexpectedBlock { chain_length: 15, block_num: 16 })
+        );
+        assert_eq!(chain_mmr.chain_length(), 14);
+    }
+
+    #[test]
+    fn test_chain_mmr_verify_note() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let metadata = NoteMetadata::new(
+            sender,
+            NoteType::Private,
+            NoteTag::for_local_use_case(1, 0).unwrap(),
+            NoteExecutionHint::always(),
+            ZERO,
+        )
+        .unwrap();
+        let note_id = NoteId::from(Digest::from([ONE, ZERO, ONE, Felt::new(3)]));
+        let note_index = BlockNoteIndex::new(1, 2);
+        let note_tree =
+            BlockNoteTree::with_entries([(note_index, note_id.inner(), metadata)]).unwrap();
+
+        // the note is created in block 2 of a chain of 4 blocks
+        let note_block = BlockHeader::new(
+            0,
+            Digest::default(),
+            2,
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            note_tree.root(),
+            Digest::default(),
+            Digest::default(),
+            0,
+        );
+        let mut mmr = Mmr::default();
+        for i in 0..4 {
+            let block_header = if i == 2 { note_block } else { int_to_block_header(i) };
+            mmr.add(block_header.hash());
+        }
+        let partial_mmr: PartialMmr = mmr.peaks(mmr.forest()).unwrap().into();
+        let mut chain_mmr = ChainMmr::new(partial_mmr, Vec::new()).unwrap();
+
+        let proof = NoteInclusionProof::new(
+            2,
+            note_index.to_absolute_index(),
+            note_tree.get_note_path(note_index).unwrap(),
+        )
+        .unwrap();
+
+        // the note block is not tracked yet
+        assert_eq!(
+            chain_mmr.verify_note(note_id, &metadata, &proof),
+            Err(ChainMmrError::UntrackedBlock { block_num: 2 })
+        );
+
+        chain_mmr
+            .apply_delta(ChainMmrDelta::from_mmr(&mmr, 4, 4, [note_block]).unwrap())
+            .unwrap();
+        proof.verify(note_id, &metadata, &note_block).unwrap();
+        chain_mmr.verify_note(note_id, &metadata, &proof).unwrap();
+
+        // proofs for different metadata or a different block are rejected
+        let other_metadata = NoteMetadata::new(
+            sender,
+            NoteType::Private,
+            NoteTag::for_local_use_case(2, 0).unwrap(),
+            NoteExecutionHint::always(),
+            ZERO,
+        )
+        .unwrap();
+        assert!(matches!(
+            chain_mmr.verify_note(note_id, &other_metadata, &proof),
+            Err(ChainMmrError::InvalidNoteInclusionProof(
+                NoteError::InclusionProofNoteRootMismatch { .. }
+            ))
+        ));
+        assert!(matches!(
+            proof.verify(note_id, &metadata, &int_to_block_header(3)),
+            Err(NoteError::InclusionProofBlockMismatch { proof_block_num: 2, block_num: 3 })
+        ));
+    }
+
     fn int_to_block_header(block_num: u32) -> BlockHeader {
         BlockHeader::new(
             0,
@@ -4,9 +4,10 @@ use core::fmt::Debug;
 use super::{BlockHeader, ChainMmr, Digest, Felt, Hasher, Word};
 use crate::{
     accounts::{Account, AccountId},
+    crypto::merkle::MerklePath,
     notes::{Note, NoteId, NoteInclusionProof, NoteLocation, Nullifier},
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
-    TransactionInputError, MAX_INPUT_NOTES_PER_TX,
+    TransactionInputError, MAX_FOREIGN_ACCOUNTS_PER_TX, MAX_INPUT_NOTES_PER_TX,
 };
 
 // TRANSACTION INPUTS
@@ -20,6 +21,7 @@ pub struct TransactionInputs {
     block_header: BlockHeader,
     block_chain: ChainMmr,
     input_notes: InputNotes<InputNote>,
+    foreign_accounts: Vec<ForeignAccountInputs>,
 }
 
 impl TransactionInputs {
@@ -85,9 +87,53 @@ impl TransactionInputs {
             block_header,
             block_chain,
             input_notes,
+            foreign_accounts: Vec::new(),
         })
     }
 
+    /// Returns these [TransactionInputs] extended with the specified foreign accounts, the state of
+    /// which can be read by the transaction.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The total number of foreign accounts is greater than 64.
+    /// - A foreign account is the account against which the transaction is executed.
+    /// - A foreign account is already part of these inputs.
+    /// - The state of a foreign account is not recorded in the account tree of the reference block.
+    pub fn with_foreign_accounts(
+        mut self,
+        foreign_accounts: impl IntoIterator<Item = ForeignAccountInputs>,
+    ) -> Result<Self, TransactionInputError> {
+        for foreign_account in foreign_accounts {
+            let account_id = foreign_account.account().id();
+            if account_id == self.account.id() {
+                return Err(TransactionInputError::ForeignAccountIsNativeAccount(account_id));
+            }
+
+            if self.foreign_account(account_id).is_some() {
+                return Err(TransactionInputError::DuplicateForeignAccount(account_id));
+            }
+
+            if !foreign_account.is_in_block(&self.block_header) {
+                return Err(TransactionInputError::ForeignAccountNotInBlock(
+                    account_id,
+                    self.block_header.block_num(),
+                ));
+            }
+
+            self.foreign_accounts.push(foreign_account);
+        }
+
+        if self.foreign_accounts.len() > MAX_FOREIGN_ACCOUNTS_PER_TX {
+            return Err(TransactionInputError::TooManyForeignAccounts {
+                max: MAX_FOREIGN_ACCOUNTS_PER_TX,
+                actual: self.foreign_accounts.len(),
+            });
+        }
+
+        Ok(self)
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -117,19 +163,40 @@ impl TransactionInputs {
         &self.input_notes
     }
 
+    /// Returns the foreign accounts the state of which can be read by the transaction.
+    pub fn foreign_accounts(&self) -> &[ForeignAccountInputs] {
+        &self.foreign_accounts
+    }
+
+    /// Returns the inputs of the foreign account with the specified ID, if it is part of these
+    /// inputs.
+    pub fn foreign_account(&self, account_id: AccountId) -> Option<&ForeignAccountInputs> {
+        self.foreign_accounts
+            .iter()
+            .find(|foreign_account| foreign_account.account().id() == account_id)
+    }
+
     // CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
     /// Consumes these transaction inputs and returns their underlying components.
     pub fn into_parts(
         self,
-    ) -> (Account, Option<Word>, BlockHeader, ChainMmr, InputNotes<InputNote>) {
+    ) -> (
+        Account,
+        Option<Word>,
+        BlockHeader,
+        ChainMmr,
+        InputNotes<InputNote>,
+        Vec<ForeignAccountInputs>,
+    ) {
         (
             self.account,
             self.account_seed,
             self.block_header,
             self.block_chain,
             self.input_notes,
+            self.foreign_accounts,
         )
     }
 }
@@ -442,6 +509,78 @@ impl Deserializable for InputNote {
     }
 }
 
+// FOREIGN ACCOUNT INPUTS
+// ================================================================================================
+
+/// A foreign account, the state of which can be read by a transaction executed against another
+/// account.
+///
+/// Contains the state of the account as of the reference block of the transaction, together with
+/// the authentication path of the account state in the account tree of that block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForeignAccountInputs {
+    account: Account,
+    account_path: MerklePath,
+}
+
+impl ForeignAccountInputs {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new [ForeignAccountInputs] instantiated from the specified account and the Merkle
+    /// path of its state in the account tree.
+    pub fn new(account: Account, account_path: MerklePath) -> Self {
+        Self { account, account_path }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the foreign account.
+    pub fn account(&self) -> &Account {
+        &self.account
+    }
+
+    /// Returns the Merkle path of the account state in the account tree.
+    pub fn account_path(&self) -> &MerklePath {
+        &self.account_path
+    }
+
+    /// Returns true if the state of the account is recorded in the account tree of the specified
+    /// block.
+    pub fn is_in_block(&self, block_header: &BlockHeader) -> bool {
+        let account_id: Felt = self.account.id().into();
+        self.account_path.verify(
+            account_id.as_int(),
+            self.account.hash(),
+            &block_header.account_root(),
+        )
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes these foreign account inputs and returns their underlying components.
+    pub fn into_parts(self) -> (Account, MerklePath) {
+        (self.account, self.account_path)
+    }
+}
+
+impl Serializable for ForeignAccountInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account.write_into(target);
+        self.account_path.write_into(target);
+    }
+}
+
+impl Deserializable for ForeignAccountInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account = Account::read_from(source)?;
+        let account_path = MerklePath::read_from(source)?;
+        Ok(Self::new(account, account_path))
+    }
+}
+
 /// Validates that the provided seed is valid for this account.
 pub fn validate_account_seed(
     account: &Account,
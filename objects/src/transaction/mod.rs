@@ -14,9 +14,11 @@ mod transaction_id;
 mod tx_args;
 mod tx_witness;
 
-pub use chain_mmr::ChainMmr;
+pub use chain_mmr::{ChainMmr, ChainMmrDelta};
 pub use executed_tx::{ExecutedTransaction, TransactionMeasurements};
-pub use inputs::{InputNote, InputNotes, ToInputNoteCommitments, TransactionInputs};
+pub use inputs::{
+    ForeignAccountInputs, InputNote, InputNotes, ToInputNoteCommitments, TransactionInputs,
+};
 pub use outputs::{OutputNote, OutputNotes, TransactionOutputs};
 pub use proven_tx::{
     InputNoteCommitment, ProvenTransaction, ProvenTransactionBuilder, TxAccountUpdate,
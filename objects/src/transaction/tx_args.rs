@@ -12,6 +12,7 @@ use vm_processor::{AdviceInputs, AdviceMap, DeserializationError};
 
 use super::{Digest, Felt, Word};
 use crate::{
+    accounts::AccountId,
     notes::{NoteDetails, NoteId},
     TransactionScriptError,
 };
@@ -27,11 +28,14 @@ use crate::{
 ///   different from note inputs, as the user executing the transaction can specify arbitrary note
 ///   args.
 /// - Advice inputs: Provides data needed by the runtime, like the details of public output notes.
+/// - Foreign accounts: IDs of the accounts the state of which can be read by the transaction via
+///   foreign procedure invocation.
 #[derive(Clone, Debug, Default)]
 pub struct TransactionArgs {
     tx_script: Option<TransactionScript>,
     note_args: BTreeMap<NoteId, Word>,
     advice_inputs: AdviceInputs,
+    foreign_accounts: Vec<AccountId>,
 }
 
 impl TransactionArgs {
@@ -59,6 +63,7 @@ impl TransactionArgs {
             tx_script,
             note_args: note_args.unwrap_or_default(),
             advice_inputs,
+            foreign_accounts: Vec::new(),
         }
     }
 
@@ -90,6 +95,11 @@ impl TransactionArgs {
         &self.advice_inputs
     }
 
+    /// Returns the IDs of the foreign accounts the state of which can be read by the transaction.
+    pub fn foreign_accounts(&self) -> &[AccountId] {
+        &self.foreign_accounts
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -143,6 +153,16 @@ impl TransactionArgs {
     pub fn extend_merkle_store<I: Iterator<Item = InnerNodeInfo>>(&mut self, iter: I) {
         self.advice_inputs.extend_merkle_store(iter)
     }
+
+    /// Adds the provided accounts to the foreign accounts the state of which can be read by the
+    /// transaction. Accounts which were already added are ignored.
+    pub fn extend_foreign_accounts<T: IntoIterator<Item = AccountId>>(&mut self, account_ids: T) {
+        for account_id in account_ids {
+            if !self.foreign_accounts.contains(&account_id) {
+                self.foreign_accounts.push(account_id);
+            }
+        }
+    }
 }
 
 // TRANSACTION SCRIPT
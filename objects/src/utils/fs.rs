@@ -0,0 +1,25 @@
+use std::{
+    fs, io,
+    path::Path,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// Writes the provided bytes into the file at the specified path atomically, i.e., readers of the
+/// file observe either its previous contents or all of the provided bytes.
+///
+/// The bytes are first written into a temporary file in the same directory, which is then renamed
+/// to the specified path.
+pub fn write_atomic(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    static TEMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let counter = TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed);
+    let mut temp_file_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_file_name.push(format!(".{}.{counter}.tmp", std::process::id()));
+    let temp_path = path.with_file_name(temp_file_name);
+
+    fs::write(&temp_path, bytes)
+        .and_then(|_| fs::rename(&temp_path, path))
+        .inspect_err(|_| {
+            let _ = fs::remove_file(&temp_path);
+        })
+}